@@ -40,6 +40,16 @@ typedef enum HederaError {
   HEDERA_ERROR_CANNOT_PERFORM_TASK_WITHOUT_LEDGER_ID,
   HEDERA_ERROR_NO_EVM_ADDRESS_PRESENT,
   HEDERA_ERROR_WRONG_KEY_TYPE,
+  HEDERA_ERROR_TRANSFERS_NOT_BALANCED,
+  HEDERA_ERROR_NFT_TRANSFER_INCONSISTENT,
+  HEDERA_ERROR_TRANSFER_AMOUNT_OVERFLOW,
+  HEDERA_ERROR_INVALID_TRANSACTION_FIELD,
+  HEDERA_ERROR_ABI_PARSE,
+  HEDERA_ERROR_ABI_ENCODE,
+  HEDERA_ERROR_ABI_DECODE,
+  HEDERA_ERROR_ABI_ITEM_NOT_FOUND,
+  HEDERA_ERROR_CONTRACT_REVERT,
+  HEDERA_ERROR_KEY_ENCRYPT,
 } HederaError;
 
 /**
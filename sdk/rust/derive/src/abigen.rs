@@ -0,0 +1,522 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use proc_macro2::{
+    Span,
+    TokenStream as TokenStream2,
+};
+use quote::{
+    format_ident,
+    quote,
+};
+use serde_json::Value;
+use syn::parse::{
+    Parse,
+    ParseStream,
+};
+use syn::{
+    Ident,
+    LitStr,
+    Token,
+    Visibility,
+};
+
+/// `abigen!(pub Name, "path/to/Contract.json")` (or the ABI JSON itself, instead of a path).
+pub(crate) struct Input {
+    vis: Visibility,
+    name: Ident,
+    source: LitStr,
+}
+
+impl Parse for Input {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let vis = input.parse()?;
+        let name = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let source = input.parse()?;
+
+        // allow a trailing comma.
+        let _ = input.parse::<Option<Token![,]>>()?;
+
+        Ok(Self { vis, name, source })
+    }
+}
+
+/// Methods generated for every contract, that ABI functions can't be named after.
+const RESERVED_METHODS: [&str; 2] = ["new", "contract_id"];
+
+/// The largest tuple that `AbiEncode` and `AbiDecode` are implemented for.
+const MAX_TUPLE_LEN: usize = 8;
+
+struct Function {
+    name: String,
+    inputs: Vec<Param>,
+    outputs: Vec<Param>,
+    is_call: bool,
+}
+
+struct Param {
+    name: String,
+    ty: String,
+}
+
+pub(crate) fn expand(input: &Input) -> syn::Result<TokenStream2> {
+    let error = |message: String| syn::Error::new(input.source.span(), message);
+
+    let source = input.source.value();
+
+    let (json, include) = if source.trim_start().starts_with(['[', '{']) {
+        (source, None)
+    } else {
+        let mut path = PathBuf::from(std::env::var("CARGO_MANIFEST_DIR").unwrap_or_default());
+        path.push(&source);
+
+        let json = std::fs::read_to_string(&path)
+            .map_err(|e| error(format!("failed to read `{}`: {e}", path.display())))?;
+
+        let path = path.to_string_lossy().into_owned();
+
+        // makes cargo rebuild the bindings when the ABI changes.
+        (
+            json,
+            Some(quote!(
+                const _: &str = ::std::include_str!(#path);
+            )),
+        )
+    };
+
+    let functions = parse_functions(&json).map_err(error)?;
+
+    let mut overloads = HashMap::<&str, usize>::new();
+    for function in &functions {
+        *overloads.entry(&function.name).or_default() += 1;
+    }
+
+    let mut seen = HashMap::<&str, usize>::new();
+    let mut generated = HashMap::<String, &str>::new();
+    let mut methods = Vec::with_capacity(functions.len());
+
+    for function in &functions {
+        let mut method_name = snake_case(&function.name);
+
+        if overloads[function.name.as_str()] > 1 {
+            let index = seen.entry(&function.name).or_default();
+            method_name = format!("{method_name}_{index}");
+            *index += 1;
+        }
+
+        // don't shadow the methods every binding has.
+        if RESERVED_METHODS.contains(&method_name.as_str()) {
+            method_name = format!("call_{method_name}");
+        }
+
+        for generated_name in [method_name.clone(), format!("decode_{method_name}")] {
+            if let Some(other) = generated.insert(generated_name.clone(), &function.name) {
+                return Err(error(format!(
+                    "ABI functions `{other}` and `{}` both generate a method named `{generated_name}`",
+                    function.name
+                )));
+            }
+        }
+
+        methods.push(expand_function(function, &method_name).map_err(error)?);
+    }
+
+    let vis = &input.vis;
+    let name = &input.name;
+    let doc = format!("Typed bindings for the `{name}` contract, generated from its ABI.");
+
+    Ok(quote! {
+        #include
+
+        #[doc = #doc]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        #vis struct #name {
+            contract_id: ::hedera::ContractId,
+        }
+
+        impl #name {
+            /// Create bindings for the contract deployed at `contract_id`.
+            #[must_use]
+            pub fn new(contract_id: ::hedera::ContractId) -> Self {
+                Self { contract_id }
+            }
+
+            /// Returns the ID of the contract these bindings call.
+            #[must_use]
+            pub fn contract_id(&self) -> ::hedera::ContractId {
+                self.contract_id
+            }
+
+            #(#methods)*
+        }
+    })
+}
+
+fn expand_function(function: &Function, method_name: &str) -> Result<TokenStream2, String> {
+    let method = ident(method_name);
+    let decode_method = ident(&format!("decode_{method_name}"));
+
+    let input_types: Vec<_> = function.inputs.iter().map(|it| it.ty.as_str()).collect();
+    let output_types: Vec<_> = function.outputs.iter().map(|it| it.ty.as_str()).collect();
+    let signature = format!("{}({})", function.name, input_types.join(","));
+
+    let arg_names: Vec<_> = function
+        .inputs
+        .iter()
+        .enumerate()
+        .map(|(index, param)| match snake_case(&param.name).trim_start_matches('_') {
+            "" => format_ident!("arg{}", index),
+            name => ident(name),
+        })
+        .collect();
+
+    let arg_types = function
+        .inputs
+        .iter()
+        .map(|it| rust_type(&it.ty).map_err(|e| format!("in `{signature}`: {e}")))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let output_rust_types = function
+        .outputs
+        .iter()
+        .map(|it| rust_type(&it.ty).map_err(|e| format!("in `{signature}`: {e}")))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if output_rust_types.len() > MAX_TUPLE_LEN {
+        return Err(format!(
+            "`{signature}` returns {} values, at most {MAX_TUPLE_LEN} are supported",
+            output_rust_types.len()
+        ));
+    }
+
+    let name = &function.name;
+
+    let abi_function = quote! {
+        ::hedera::__private::abigen_function(#name, &[#(#input_types),*], &[#(#output_types),*])
+    };
+
+    let (builder, builder_doc) = if function.is_call {
+        (quote!(::hedera::ContractCallQuery), format!("Returns a query that calls `{signature}`."))
+    } else {
+        (
+            quote!(::hedera::ContractExecuteTransaction),
+            format!("Returns a transaction that calls `{signature}`."),
+        )
+    };
+
+    let (output, decode_output) = match output_rust_types.as_slice() {
+        [] => (quote!(()), quote!(::std::result::Result::Ok(()))),
+        [ty] => (
+            quote!(#ty),
+            quote! {
+                <#ty as ::hedera::AbiDecode>::from_abi_value(values.swap_remove(0))
+            },
+        ),
+        types => (
+            quote!((#(#types),*)),
+            quote! {
+                <(#(#types),*) as ::hedera::AbiDecode>::from_abi_value(::hedera::AbiValue::Tuple(values))
+            },
+        ),
+    };
+
+    let decode_doc = format!("Decode the values returned by `{signature}`.");
+
+    Ok(quote! {
+        #[doc = #builder_doc]
+        ///
+        /// # Errors
+        /// - `Error::AbiEncode` if an argument is out of range for its Solidity type.
+        pub fn #method(&self, #(#arg_names: #arg_types),*) -> ::hedera::Result<#builder> {
+            let data = #abi_function.encode_input(&[
+                #(::hedera::AbiEncode::to_abi_value(&#arg_names)),*
+            ])?;
+
+            let mut builder = #builder::new();
+            builder.contract_id(self.contract_id).function_parameters(data);
+
+            ::std::result::Result::Ok(builder)
+        }
+
+        #[doc = #decode_doc]
+        ///
+        /// # Errors
+        /// - `Error::AbiDecode` if `result` isn't a valid encoding of the returned values.
+        pub fn #decode_method(result: &::hedera::ContractFunctionResult) -> ::hedera::Result<#output> {
+            #[allow(unused_mut, unused_variables)]
+            let mut values = #abi_function.decode_output(&result.bytes)?.into_values();
+
+            #decode_output
+        }
+    })
+}
+
+fn parse_functions(json: &str) -> Result<Vec<Function>, String> {
+    let document: Value =
+        serde_json::from_str(json).map_err(|e| format!("invalid ABI JSON: {e}"))?;
+
+    let items = match &document {
+        Value::Array(items) => items,
+        Value::Object(artifact) => match artifact.get("abi") {
+            Some(Value::Array(items)) => items,
+            _ => return Err("expected an ABI array, or an object with an `abi` array".to_owned()),
+        },
+        _ => return Err("expected an ABI array, or an object with an `abi` array".to_owned()),
+    };
+
+    let mut functions = Vec::new();
+
+    for item in items {
+        // in older ABIs functions may omit their `type`.
+        if item.get("type").and_then(Value::as_str).unwrap_or("function") != "function" {
+            continue;
+        }
+
+        let name = item
+            .get("name")
+            .and_then(Value::as_str)
+            .ok_or_else(|| "function without a `name`".to_owned())?
+            .to_owned();
+
+        let is_call = match item.get("stateMutability").and_then(Value::as_str) {
+            Some(mutability) => matches!(mutability, "view" | "pure"),
+            None => item.get("constant").and_then(Value::as_bool).unwrap_or(false),
+        };
+
+        functions.push(Function {
+            inputs: parse_params(item.get("inputs"))?,
+            outputs: parse_params(item.get("outputs"))?,
+            name,
+            is_call,
+        });
+    }
+
+    Ok(functions)
+}
+
+fn parse_params(params: Option<&Value>) -> Result<Vec<Param>, String> {
+    let params = match params {
+        Some(Value::Array(params)) => params.as_slice(),
+        Some(_) => return Err("expected an array of parameters".to_owned()),
+        None => &[],
+    };
+
+    params
+        .iter()
+        .map(|param| {
+            Ok(Param {
+                name: param.get("name").and_then(Value::as_str).unwrap_or_default().to_owned(),
+                ty: param_type(param)?,
+            })
+        })
+        .collect()
+}
+
+/// Returns the canonical type of `param`, spelling out tuples from their `components`.
+fn param_type(param: &Value) -> Result<String, String> {
+    let ty = param
+        .get("type")
+        .and_then(Value::as_str)
+        .ok_or_else(|| "parameter without a `type`".to_owned())?;
+
+    match ty.strip_prefix("tuple") {
+        Some(suffix) => {
+            let components = parse_params(param.get("components"))?;
+            let types: Vec<_> = components.into_iter().map(|it| it.ty).collect();
+
+            Ok(format!("({}){suffix}", types.join(",")))
+        }
+        None => Ok(ty.to_owned()),
+    }
+}
+
+/// Returns the Rust type that values of the Solidity type `ty` are passed and returned as.
+fn rust_type(ty: &str) -> Result<TokenStream2, String> {
+    if let Some(element) = ty.strip_suffix("[]") {
+        let element = rust_type(element)?;
+        return Ok(quote!(::std::vec::Vec<#element>));
+    }
+
+    if let Some(rest) = ty.strip_suffix(']') {
+        if let Some((element, len)) = rest.rsplit_once('[') {
+            let len: usize = len.parse().map_err(|_| format!("invalid array length in `{ty}`"))?;
+            let element = rust_type(element)?;
+            return Ok(quote!([#element; #len]));
+        }
+    }
+
+    if let Some(inner) = ty.strip_prefix('(').and_then(|it| it.strip_suffix(')')) {
+        let types = split_tuple(inner).into_iter().map(rust_type).collect::<Result<Vec<_>, _>>()?;
+
+        if types.len() > MAX_TUPLE_LEN {
+            return Err(format!(
+                "tuple `{ty}` has {} elements, at most {MAX_TUPLE_LEN} are supported",
+                types.len()
+            ));
+        }
+
+        return Ok(match types.as_slice() {
+            [ty] => quote!((#ty,)),
+            types => quote!((#(#types),*)),
+        });
+    }
+
+    let unsupported = || format!("unsupported type `{ty}`");
+
+    let bits = |digits: &str| -> Result<usize, String> {
+        match digits {
+            "" => Ok(256),
+            digits => match digits.parse() {
+                Ok(bits) if bits % 8 == 0 && (8..=256).contains(&bits) => Ok(bits),
+                _ => Err(unsupported()),
+            },
+        }
+    };
+
+    Ok(match ty {
+        "address" => quote!(::hedera::EvmAddress),
+        "bool" => quote!(bool),
+        "string" => quote!(::std::string::String),
+        "bytes" => quote!(::hedera::AbiBytes),
+        _ if ty.starts_with("bytes") => match ty["bytes".len()..].parse::<usize>() {
+            Ok(len) if (1..=32).contains(&len) => quote!(::hedera::AbiFixedBytes<#len>),
+            _ => return Err(unsupported()),
+        },
+        _ if ty.starts_with("uint") => match bits(&ty["uint".len()..])? {
+            8 => quote!(u8),
+            16 => quote!(u16),
+            9..=32 => quote!(u32),
+            33..=64 => quote!(u64),
+            65..=128 => quote!(u128),
+            _ => quote!(::hedera::__private::num_bigint::BigUint),
+        },
+        _ if ty.starts_with("int") => match bits(&ty["int".len()..])? {
+            8 => quote!(i8),
+            16 => quote!(i16),
+            9..=32 => quote!(i32),
+            33..=64 => quote!(i64),
+            65..=128 => quote!(i128),
+            _ => quote!(::hedera::__private::num_bigint::BigInt),
+        },
+        _ => return Err(unsupported()),
+    })
+}
+
+/// Splits the inside of a tuple type at its top level commas.
+fn split_tuple(inner: &str) -> Vec<&str> {
+    if inner.is_empty() {
+        return Vec::new();
+    }
+
+    let mut parts = Vec::new();
+    let mut depth = 0_usize;
+    let mut start = 0;
+
+    for (index, ch) in inner.char_indices() {
+        match ch {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                parts.push(&inner[start..index]);
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+
+    parts.push(&inner[start..]);
+
+    parts
+}
+
+/// Converts a Solidity `camelCase` name into `snake_case`.
+fn snake_case(name: &str) -> String {
+    let chars: Vec<_> = name.chars().collect();
+    let mut out = String::with_capacity(name.len() + 4);
+
+    for (index, &ch) in chars.iter().enumerate() {
+        if ch.is_ascii_uppercase() && index > 0 {
+            let prev = chars[index - 1];
+            let next_is_lower = chars.get(index + 1).map_or(false, char::is_ascii_lowercase);
+
+            if prev.is_ascii_lowercase()
+                || prev.is_ascii_digit()
+                || (prev.is_ascii_uppercase() && next_is_lower)
+            {
+                out.push('_');
+            }
+        }
+
+        out.push(ch.to_ascii_lowercase());
+    }
+
+    out
+}
+
+/// Makes an identifier, using a raw identifier for Rust keywords (`type` becomes `r#type`).
+fn ident(name: &str) -> Ident {
+    syn::parse_str(name).unwrap_or_else(|_| Ident::new_raw(name, Span::call_site()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        rust_type,
+        snake_case,
+        split_tuple,
+    };
+
+    #[test]
+    fn snake_case_names() {
+        assert_eq!(snake_case("balanceOf"), "balance_of");
+        assert_eq!(snake_case("getURI"), "get_uri");
+        assert_eq!(snake_case("ERC20Name"), "erc20_name");
+        assert_eq!(snake_case("transfer"), "transfer");
+    }
+
+    #[test]
+    fn tuple_split() {
+        assert_eq!(
+            split_tuple("uint256,(bool,string)[],bytes"),
+            ["uint256", "(bool,string)[]", "bytes"]
+        );
+        assert!(split_tuple("").is_empty());
+    }
+
+    #[test]
+    fn rust_types() {
+        let ty = |it| rust_type(it).unwrap().to_string();
+
+        assert_eq!(ty("uint8"), "u8");
+        assert_eq!(ty("uint24"), "u32");
+        assert_eq!(ty("int"), ":: hedera :: __private :: num_bigint :: BigInt");
+        assert_eq!(ty("bool[2]"), "[bool ; 2usize]");
+        assert_eq!(ty("(address)"), "(:: hedera :: EvmAddress ,)");
+
+        assert!(rust_type("uint7").is_err());
+        assert!(rust_type("bytes33").is_err());
+        assert!(rust_type("function").is_err());
+        assert!(rust_type("(bool,bool,bool,bool,bool,bool,bool,bool,bool)").is_err());
+    }
+}
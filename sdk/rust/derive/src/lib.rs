@@ -0,0 +1,269 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+//! Derive macros for `hedera`, use them through the re-exports in that crate.
+
+mod abigen;
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::spanned::Spanned;
+use syn::{
+    parse_macro_input,
+    parse_quote,
+    Data,
+    DeriveInput,
+    Fields,
+    Generics,
+};
+
+/// Derive `AbiEncode` for a struct (a Solidity `struct`) or a field-less enum (a Solidity `enum`).
+#[proc_macro_derive(AbiEncode)]
+pub fn derive_abi_encode(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand_encode(&input).unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+/// Derive `AbiDecode` for a struct (a Solidity `struct`) or a field-less enum (a Solidity `enum`).
+#[proc_macro_derive(AbiDecode)]
+pub fn derive_abi_decode(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand_decode(&input).unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+/// Generate typed bindings for a contract from its ABI JSON.
+///
+/// ```ignore
+/// hedera::abigen!(pub Token, "contracts/Token.json");
+///
+/// let token = Token::new(contract_id);
+///
+/// let result = token.balance_of(owner)?.gas(30_000).execute(&client).await?;
+/// let balance = Token::decode_balance_of(&result)?;
+/// ```
+///
+/// The second argument is a path relative to the crate's `Cargo.toml`, or the ABI JSON itself.
+/// Either a bare ABI array or a compiler artifact with an `abi` key is accepted.
+///
+/// Every contract function `fooBar` becomes two methods:
+/// - `foo_bar(&self, ...)`, which returns a `ContractCallQuery` for `view` and `pure` functions,
+///   or a `ContractExecuteTransaction` otherwise, with the contract ID and call data already set.
+/// - `decode_foo_bar(&ContractFunctionResult)`, which decodes the returned values
+///   (`()` for none, the value itself for one, and a tuple for more).
+///
+/// Overloaded functions are numbered in ABI order (`foo_0`, `foo_1`),
+/// and functions named `new` or `contractId` are prefixed with `call_` (`call_new`, `decode_call_new`),
+/// to not shadow the constructor and `contract_id` getter.
+///
+/// Solidity types map to Rust types as follows:
+/// - `address`: `EvmAddress`
+/// - `bool`: `bool`
+/// - `string`: `String`
+/// - `bytes`: `AbiBytes`, and `bytes<N>`: `AbiFixedBytes<N>`
+/// - `uint<M>`/`int<M>`: the smallest of `u8`..`u128` (`i8`..`i128`) that fits, or `BigUint` (`BigInt`) above 128 bits
+/// - `T[]`: `Vec<T>`, `T[k]`: `[T; k]`, and tuples: Rust tuples
+///
+/// `AbiEncode` and `AbiDecode` are only implemented for tuples of up to 8 elements,
+/// so larger tuples (and functions returning more than 8 values) are a compile error.
+#[proc_macro]
+pub fn abigen(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as abigen::Input);
+
+    abigen::expand(&input).unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+fn expand_encode(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let generics = add_bounds(&input.generics, quote!(::hedera::AbiEncode));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let (abi_type, to_abi_value) = match &input.data {
+        Data::Struct(data) => {
+            let types = data.fields.iter().map(|field| &field.ty);
+            let members = data.fields.iter().enumerate().map(|(index, field)| match &field.ident {
+                Some(ident) => quote!(#ident),
+                None => {
+                    let index = syn::Index::from(index);
+                    quote!(#index)
+                }
+            });
+
+            (
+                quote! {
+                    ::hedera::AbiType::Tuple(::std::vec![
+                        #(<#types as ::hedera::AbiEncode>::abi_type()),*
+                    ])
+                },
+                quote! {
+                    ::hedera::AbiValue::Tuple(::std::vec![
+                        #(::hedera::AbiEncode::to_abi_value(&self.#members)),*
+                    ])
+                },
+            )
+        }
+
+        Data::Enum(_) => {
+            let variants = enum_variants(input)?;
+            let discriminants = (0..variants.len()).map(|it| it as u8);
+
+            (
+                quote!(<u8 as ::hedera::AbiEncode>::abi_type()),
+                quote! {
+                    let discriminant: u8 = match self {
+                        #(Self::#variants => #discriminants),*
+                    };
+
+                    ::hedera::AbiEncode::to_abi_value(&discriminant)
+                },
+            )
+        }
+
+        Data::Union(_) => {
+            return Err(syn::Error::new(input.span(), "`AbiEncode` can't be derived for unions"))
+        }
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::hedera::AbiEncode for #name #ty_generics #where_clause {
+            fn abi_type() -> ::hedera::AbiType {
+                #abi_type
+            }
+
+            fn to_abi_value(&self) -> ::hedera::AbiValue {
+                #to_abi_value
+            }
+        }
+    })
+}
+
+fn expand_decode(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let generics = add_bounds(&input.generics, quote!(::hedera::AbiDecode));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let (abi_type, from_abi_value) = match &input.data {
+        Data::Struct(data) => {
+            let types: Vec<_> = data.fields.iter().map(|field| &field.ty).collect();
+            let len = types.len();
+
+            // `tuple_elements` checks that there are exactly `len` values.
+            let fields = types.iter().map(
+                |ty| quote!(<#ty as ::hedera::AbiDecode>::from_abi_value(values.next().unwrap())?),
+            );
+
+            let construct = match &data.fields {
+                Fields::Named(named) => {
+                    let idents = named.named.iter().map(|field| &field.ident);
+                    quote!(Self { #(#idents: #fields),* })
+                }
+                Fields::Unnamed(_) => quote!(Self(#(#fields),*)),
+                Fields::Unit => quote!(Self),
+            };
+
+            (
+                quote! {
+                    ::hedera::AbiType::Tuple(::std::vec![
+                        #(<#types as ::hedera::AbiDecode>::abi_type()),*
+                    ])
+                },
+                quote! {
+                    #[allow(unused_mut, unused_variables)]
+                    let mut values = ::hedera::__private::tuple_elements::<Self>(value, #len)?;
+
+                    ::std::result::Result::Ok(#construct)
+                },
+            )
+        }
+
+        Data::Enum(_) => {
+            let variants = enum_variants(input)?;
+            let discriminants = (0..variants.len()).map(|it| it as u8);
+
+            (
+                quote!(<u8 as ::hedera::AbiDecode>::abi_type()),
+                quote! {
+                    match <u8 as ::hedera::AbiDecode>::from_abi_value(::std::clone::Clone::clone(&value))? {
+                        #(#discriminants => ::std::result::Result::Ok(Self::#variants),)*
+                        _ => ::std::result::Result::Err(::hedera::__private::mismatch::<Self>(&value)),
+                    }
+                },
+            )
+        }
+
+        Data::Union(_) => {
+            return Err(syn::Error::new(input.span(), "`AbiDecode` can't be derived for unions"))
+        }
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::hedera::AbiDecode for #name #ty_generics #where_clause {
+            fn abi_type() -> ::hedera::AbiType {
+                #abi_type
+            }
+
+            fn from_abi_value(value: ::hedera::AbiValue) -> ::hedera::Result<Self> {
+                #from_abi_value
+            }
+        }
+    })
+}
+
+/// Returns the variants of a Solidity-style enum, which have no fields and fit in a `uint8`.
+fn enum_variants(input: &DeriveInput) -> syn::Result<Vec<&syn::Ident>> {
+    let data = match &input.data {
+        Data::Enum(data) => data,
+        _ => unreachable!(),
+    };
+
+    if data.variants.len() > 256 {
+        return Err(syn::Error::new(
+            input.span(),
+            "enums with more than 256 variants aren't supported",
+        ));
+    }
+
+    data.variants
+        .iter()
+        .map(|variant| match (&variant.fields, &variant.discriminant) {
+            (Fields::Unit, None) => Ok(&variant.ident),
+            (Fields::Unit, Some(_)) => Err(syn::Error::new(
+                variant.span(),
+                "explicit discriminants aren't supported, Solidity enums are numbered in order",
+            )),
+            _ => Err(syn::Error::new(
+                variant.span(),
+                "only enums without fields (Solidity `enum`s) are supported",
+            )),
+        })
+        .collect()
+}
+
+fn add_bounds(generics: &Generics, bound: TokenStream2) -> Generics {
+    let mut generics = generics.clone();
+
+    for param in generics.type_params_mut() {
+        param.bounds.push(parse_quote!(#bound));
+    }
+
+    generics
+}
@@ -0,0 +1,43 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+/// A Solidity `bytes` value, for use with [`AbiEncode`](crate::AbiEncode) and [`AbiDecode`](crate::AbiDecode).
+///
+/// (A `Vec<u8>` is a `uint8[]`.)
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct AbiBytes(pub Vec<u8>);
+
+/// A Solidity `bytes<N>` value, for use with [`AbiEncode`](crate::AbiEncode) and [`AbiDecode`](crate::AbiDecode).
+///
+/// (A `[u8; N]` is a `uint8[N]`.)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AbiFixedBytes<const N: usize>(pub [u8; N]);
+
+impl From<Vec<u8>> for AbiBytes {
+    fn from(value: Vec<u8>) -> Self {
+        Self(value)
+    }
+}
+
+impl<const N: usize> From<[u8; N]> for AbiFixedBytes<N> {
+    fn from(value: [u8; N]) -> Self {
+        Self(value)
+    }
+}
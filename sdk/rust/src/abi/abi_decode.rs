@@ -0,0 +1,325 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use num_bigint::{
+    BigInt,
+    BigUint,
+};
+
+use super::{
+    AbiBytes,
+    AbiFixedBytes,
+    AbiType,
+    AbiValue,
+};
+use crate::{
+    Error,
+    EvmAddress,
+};
+
+/// A Rust type that can be decoded from a value of a Solidity ABI type.
+///
+/// Structs map to tuples (Solidity `struct`s) and field-less enums to `uint8` (Solidity `enum`s),
+/// this can be derived for them with `#[derive(AbiDecode)]`.
+pub trait AbiDecode: Sized {
+    /// Returns the Solidity ABI type values of this type are decoded from.
+    fn abi_type() -> AbiType;
+
+    /// Converts `value`, of type [`abi_type`](Self::abi_type), into `Self`.
+    ///
+    /// # Errors
+    /// - [`Error::AbiDecode`] if `value` isn't of type [`abi_type`](Self::abi_type), or is out of range for `Self`.
+    fn from_abi_value(value: AbiValue) -> crate::Result<Self>;
+}
+
+/// The error for a `value` that can't be decoded as `T`.
+#[doc(hidden)]
+#[must_use]
+pub fn mismatch<T: AbiDecode>(value: &AbiValue) -> Error {
+    Error::abi_decode(format!("can't decode `{value:?}` as `{}`", T::abi_type()))
+}
+
+/// Takes the elements of a tuple of `len` values, for use by `#[derive(AbiDecode)]`.
+///
+/// # Errors
+/// - [`Error::AbiDecode`] if `value` isn't a tuple of `len` values.
+#[doc(hidden)]
+pub fn tuple_elements<T: AbiDecode>(
+    value: AbiValue,
+    len: usize,
+) -> crate::Result<std::vec::IntoIter<AbiValue>> {
+    match value {
+        AbiValue::Tuple(values) if values.len() == len => Ok(values.into_iter()),
+        _ => Err(mismatch::<T>(&value)),
+    }
+}
+
+impl AbiDecode for bool {
+    fn abi_type() -> AbiType {
+        AbiType::Bool
+    }
+
+    fn from_abi_value(value: AbiValue) -> crate::Result<Self> {
+        value.as_bool().ok_or_else(|| mismatch::<Self>(&value))
+    }
+}
+
+impl AbiDecode for EvmAddress {
+    fn abi_type() -> AbiType {
+        AbiType::Address
+    }
+
+    fn from_abi_value(value: AbiValue) -> crate::Result<Self> {
+        value.as_address().ok_or_else(|| mismatch::<Self>(&value))
+    }
+}
+
+impl AbiDecode for String {
+    fn abi_type() -> AbiType {
+        AbiType::String
+    }
+
+    fn from_abi_value(value: AbiValue) -> crate::Result<Self> {
+        match value {
+            AbiValue::String(it) => Ok(it),
+            _ => Err(mismatch::<Self>(&value)),
+        }
+    }
+}
+
+impl AbiDecode for BigUint {
+    fn abi_type() -> AbiType {
+        AbiType::Uint(256)
+    }
+
+    fn from_abi_value(value: AbiValue) -> crate::Result<Self> {
+        match value {
+            AbiValue::Uint(it) => Ok(it),
+            _ => Err(mismatch::<Self>(&value)),
+        }
+    }
+}
+
+impl AbiDecode for BigInt {
+    fn abi_type() -> AbiType {
+        AbiType::Int(256)
+    }
+
+    fn from_abi_value(value: AbiValue) -> crate::Result<Self> {
+        match value {
+            AbiValue::Int(it) => Ok(it),
+            _ => Err(mismatch::<Self>(&value)),
+        }
+    }
+}
+
+impl AbiDecode for AbiBytes {
+    fn abi_type() -> AbiType {
+        AbiType::Bytes
+    }
+
+    fn from_abi_value(value: AbiValue) -> crate::Result<Self> {
+        match value {
+            AbiValue::Bytes(it) => Ok(Self(it)),
+            _ => Err(mismatch::<Self>(&value)),
+        }
+    }
+}
+
+impl<const N: usize> AbiDecode for AbiFixedBytes<N> {
+    fn abi_type() -> AbiType {
+        AbiType::FixedBytes(N)
+    }
+
+    fn from_abi_value(value: AbiValue) -> crate::Result<Self> {
+        match value {
+            AbiValue::FixedBytes(it) if it.len() == N => Ok(Self(it.try_into().unwrap())),
+            _ => Err(mismatch::<Self>(&value)),
+        }
+    }
+}
+
+impl<T: AbiDecode> AbiDecode for Vec<T> {
+    fn abi_type() -> AbiType {
+        AbiType::Array(Box::new(T::abi_type()))
+    }
+
+    fn from_abi_value(value: AbiValue) -> crate::Result<Self> {
+        match value {
+            AbiValue::Array(values) => values.into_iter().map(T::from_abi_value).collect(),
+            _ => Err(mismatch::<Self>(&value)),
+        }
+    }
+}
+
+impl<T: AbiDecode, const N: usize> AbiDecode for [T; N] {
+    fn abi_type() -> AbiType {
+        AbiType::FixedArray(Box::new(T::abi_type()), N)
+    }
+
+    fn from_abi_value(value: AbiValue) -> crate::Result<Self> {
+        match value {
+            AbiValue::FixedArray(values) if values.len() == N => {
+                let values =
+                    values.into_iter().map(T::from_abi_value).collect::<crate::Result<Vec<_>>>()?;
+
+                // the length was checked above.
+                Ok(values.try_into().unwrap_or_else(|_| unreachable!()))
+            }
+            _ => Err(mismatch::<Self>(&value)),
+        }
+    }
+}
+
+macro_rules! impl_decode_int {
+    ($variant:ident: $($ty:ty),*) => {
+        $(
+            impl AbiDecode for $ty {
+                fn abi_type() -> AbiType {
+                    AbiType::$variant(<$ty>::BITS as usize)
+                }
+
+                fn from_abi_value(value: AbiValue) -> crate::Result<Self> {
+                    match &value {
+                        AbiValue::$variant(it) => <$ty>::try_from(it).map_err(|_| mismatch::<Self>(&value)),
+                        _ => Err(mismatch::<Self>(&value)),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_decode_int!(Uint: u8, u16, u32, u64, u128);
+impl_decode_int!(Int: i8, i16, i32, i64, i128);
+
+macro_rules! impl_decode_tuple {
+    ($len:literal: $($name:ident),+) => {
+        impl<$($name: AbiDecode),+> AbiDecode for ($($name,)+) {
+            fn abi_type() -> AbiType {
+                AbiType::Tuple(vec![$($name::abi_type()),+])
+            }
+
+            fn from_abi_value(value: AbiValue) -> crate::Result<Self> {
+                let mut values = tuple_elements::<Self>(value, $len)?;
+
+                // `tuple_elements` checked that there are exactly enough values.
+                Ok(($($name::from_abi_value(values.next().unwrap())?,)+))
+            }
+        }
+    };
+}
+
+impl_decode_tuple!(1: A);
+impl_decode_tuple!(2: A, B);
+impl_decode_tuple!(3: A, B, C);
+impl_decode_tuple!(4: A, B, C, D);
+impl_decode_tuple!(5: A, B, C, D, E);
+impl_decode_tuple!(6: A, B, C, D, E, F);
+impl_decode_tuple!(7: A, B, C, D, E, F, G);
+impl_decode_tuple!(8: A, B, C, D, E, F, G, H);
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+    use num_bigint::BigUint;
+
+    use crate::abi::codec;
+    use crate::{
+        AbiBytes,
+        AbiDecode,
+        AbiEncode,
+        AbiValue,
+        Error,
+        EvmAddress,
+    };
+
+    #[derive(Debug, PartialEq, AbiEncode, AbiDecode)]
+    struct Order {
+        id: u64,
+        owner: EvmAddress,
+        items: Vec<Item>,
+        status: Status,
+    }
+
+    #[derive(Debug, PartialEq, AbiEncode, AbiDecode)]
+    struct Item(String, BigUint, AbiBytes);
+
+    #[derive(Debug, PartialEq, AbiEncode, AbiDecode)]
+    enum Status {
+        Pending,
+        Shipped,
+    }
+
+    fn order() -> Order {
+        Order {
+            id: 7,
+            owner: EvmAddress::from([0x11; 20]),
+            items: vec![
+                Item("apple".to_owned(), BigUint::from(3_u8), AbiBytes(vec![1, 2])),
+                Item("pear".to_owned(), BigUint::from(5_u8), AbiBytes(Vec::new())),
+            ],
+            status: Status::Shipped,
+        }
+    }
+
+    #[test]
+    fn derived_abi_type() {
+        assert_eq!(
+            <Order as AbiDecode>::abi_type().to_string(),
+            "(uint64,address,(string,uint256,bytes)[],uint8)"
+        );
+
+        assert_eq!(<Order as AbiEncode>::abi_type(), <Order as AbiDecode>::abi_type());
+    }
+
+    #[test]
+    fn derived_round_trip() -> crate::Result<()> {
+        let ty = <Order as AbiEncode>::abi_type();
+        let bytes = codec::encode(std::slice::from_ref(&ty), &[order().to_abi_value()])?;
+
+        let value = codec::decode(std::slice::from_ref(&ty), &bytes)?.remove(0);
+
+        assert_eq!(Order::from_abi_value(value)?, order());
+
+        Ok(())
+    }
+
+    #[test]
+    fn enum_discriminants() -> crate::Result<()> {
+        assert_eq!(Status::Pending.to_abi_value(), AbiValue::from(0_u8));
+        assert_eq!(Status::from_abi_value(AbiValue::from(1_u8))?, Status::Shipped);
+
+        assert_matches!(Status::from_abi_value(AbiValue::from(2_u8)), Err(Error::AbiDecode(_)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn wrong_shape() {
+        assert_matches!(
+            Item::from_abi_value(AbiValue::Tuple(vec!["apple".into()])),
+            Err(Error::AbiDecode(_))
+        );
+
+        assert_matches!(u8::from_abi_value(AbiValue::from(256_u16)), Err(Error::AbiDecode(_)));
+        assert_matches!(bool::from_abi_value(AbiValue::from(1_u8)), Err(Error::AbiDecode(_)));
+    }
+}
@@ -0,0 +1,208 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use num_bigint::{
+    BigInt,
+    BigUint,
+};
+
+use super::{
+    AbiBytes,
+    AbiFixedBytes,
+    AbiType,
+    AbiValue,
+};
+use crate::EvmAddress;
+
+/// A Rust type that can be encoded as a value of a Solidity ABI type.
+///
+/// Structs map to tuples (Solidity `struct`s) and field-less enums to `uint8` (Solidity `enum`s),
+/// this can be derived for them with `#[derive(AbiEncode)]`.
+pub trait AbiEncode {
+    /// Returns the Solidity ABI type values of this type are encoded as.
+    fn abi_type() -> AbiType;
+
+    /// Converts `self` into an [`AbiValue`] of type [`abi_type`](Self::abi_type).
+    fn to_abi_value(&self) -> AbiValue;
+}
+
+impl<T: AbiEncode + ?Sized> AbiEncode for &T {
+    fn abi_type() -> AbiType {
+        T::abi_type()
+    }
+
+    fn to_abi_value(&self) -> AbiValue {
+        (**self).to_abi_value()
+    }
+}
+
+impl AbiEncode for bool {
+    fn abi_type() -> AbiType {
+        AbiType::Bool
+    }
+
+    fn to_abi_value(&self) -> AbiValue {
+        AbiValue::Bool(*self)
+    }
+}
+
+impl AbiEncode for EvmAddress {
+    fn abi_type() -> AbiType {
+        AbiType::Address
+    }
+
+    fn to_abi_value(&self) -> AbiValue {
+        AbiValue::Address(*self)
+    }
+}
+
+impl AbiEncode for str {
+    fn abi_type() -> AbiType {
+        AbiType::String
+    }
+
+    fn to_abi_value(&self) -> AbiValue {
+        AbiValue::String(self.to_owned())
+    }
+}
+
+impl AbiEncode for String {
+    fn abi_type() -> AbiType {
+        AbiType::String
+    }
+
+    fn to_abi_value(&self) -> AbiValue {
+        AbiValue::String(self.clone())
+    }
+}
+
+impl AbiEncode for BigUint {
+    fn abi_type() -> AbiType {
+        AbiType::Uint(256)
+    }
+
+    fn to_abi_value(&self) -> AbiValue {
+        AbiValue::Uint(self.clone())
+    }
+}
+
+impl AbiEncode for BigInt {
+    fn abi_type() -> AbiType {
+        AbiType::Int(256)
+    }
+
+    fn to_abi_value(&self) -> AbiValue {
+        AbiValue::Int(self.clone())
+    }
+}
+
+impl AbiEncode for AbiBytes {
+    fn abi_type() -> AbiType {
+        AbiType::Bytes
+    }
+
+    fn to_abi_value(&self) -> AbiValue {
+        AbiValue::Bytes(self.0.clone())
+    }
+}
+
+impl<const N: usize> AbiEncode for AbiFixedBytes<N> {
+    fn abi_type() -> AbiType {
+        AbiType::FixedBytes(N)
+    }
+
+    fn to_abi_value(&self) -> AbiValue {
+        AbiValue::FixedBytes(self.0.to_vec())
+    }
+}
+
+impl<T: AbiEncode> AbiEncode for [T] {
+    fn abi_type() -> AbiType {
+        AbiType::Array(Box::new(T::abi_type()))
+    }
+
+    fn to_abi_value(&self) -> AbiValue {
+        AbiValue::Array(self.iter().map(T::to_abi_value).collect())
+    }
+}
+
+impl<T: AbiEncode> AbiEncode for Vec<T> {
+    fn abi_type() -> AbiType {
+        <[T]>::abi_type()
+    }
+
+    fn to_abi_value(&self) -> AbiValue {
+        self.as_slice().to_abi_value()
+    }
+}
+
+impl<T: AbiEncode, const N: usize> AbiEncode for [T; N] {
+    fn abi_type() -> AbiType {
+        AbiType::FixedArray(Box::new(T::abi_type()), N)
+    }
+
+    fn to_abi_value(&self) -> AbiValue {
+        AbiValue::FixedArray(self.iter().map(T::to_abi_value).collect())
+    }
+}
+
+macro_rules! impl_encode_int {
+    ($variant:ident: $($ty:ty),*) => {
+        $(
+            impl AbiEncode for $ty {
+                fn abi_type() -> AbiType {
+                    AbiType::$variant(<$ty>::BITS as usize)
+                }
+
+                fn to_abi_value(&self) -> AbiValue {
+                    AbiValue::$variant((*self).into())
+                }
+            }
+        )*
+    };
+}
+
+impl_encode_int!(Uint: u8, u16, u32, u64, u128);
+impl_encode_int!(Int: i8, i16, i32, i64, i128);
+
+macro_rules! impl_encode_tuple {
+    ($($name:ident),+) => {
+        impl<$($name: AbiEncode),+> AbiEncode for ($($name,)+) {
+            fn abi_type() -> AbiType {
+                AbiType::Tuple(vec![$($name::abi_type()),+])
+            }
+
+            #[allow(non_snake_case)]
+            fn to_abi_value(&self) -> AbiValue {
+                let ($($name,)+) = self;
+                AbiValue::Tuple(vec![$($name.to_abi_value()),+])
+            }
+        }
+    };
+}
+
+impl_encode_tuple!(A);
+impl_encode_tuple!(A, B);
+impl_encode_tuple!(A, B, C);
+impl_encode_tuple!(A, B, C, D);
+impl_encode_tuple!(A, B, C, D, E);
+impl_encode_tuple!(A, B, C, D, E, F);
+impl_encode_tuple!(A, B, C, D, E, F, G);
+impl_encode_tuple!(A, B, C, D, E, F, G, H);
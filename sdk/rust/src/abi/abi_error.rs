@@ -0,0 +1,74 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use super::abi_function::decode_params;
+use super::{
+    AbiParam,
+    AbiValues,
+};
+use crate::contract::ContractFunctionSelector;
+use crate::Error;
+
+/// A custom error in the Solidity contract ABI, declared with `error Name(...)` and raised with `revert Name(...)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AbiError {
+    /// The name of the error.
+    pub name: String,
+
+    /// The parameters of the error.
+    pub inputs: Vec<AbiParam>,
+}
+
+impl AbiError {
+    /// Returns the canonical signature of this error, for example `InsufficientBalance(uint256,uint256)`.
+    #[must_use]
+    pub fn signature(&self) -> String {
+        let types: Vec<_> = self.inputs.iter().map(|it| it.ty.to_string()).collect();
+
+        format!("{}({})", self.name, types.join(","))
+    }
+
+    /// Returns the 4 byte selector that identifies this error in revert data.
+    #[must_use]
+    pub fn selector(&self) -> [u8; 4] {
+        let mut selector = ContractFunctionSelector::new(&self.name);
+
+        for input in &self.inputs {
+            selector.add_param_type(&input.ty.to_string());
+        }
+
+        selector.finish()
+    }
+
+    /// Decode the parameters of this error from revert `data` (including the selector).
+    ///
+    /// # Errors
+    /// - [`Error::AbiDecode`] if `data` isn't a valid encoding of this error.
+    pub fn decode(&self, data: &[u8]) -> crate::Result<AbiValues> {
+        if data.get(..4) != Some(&self.selector()[..]) {
+            return Err(Error::abi_decode(format!(
+                "data isn't an encoding of error `{}`",
+                self.name
+            )));
+        }
+
+        decode_params(&self.inputs, &data[4..])
+    }
+}
@@ -0,0 +1,148 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use sha3::{
+    Digest,
+    Keccak256,
+};
+
+use super::{
+    codec,
+    AbiType,
+    AbiValue,
+    AbiValues,
+};
+use crate::{
+    ContractId,
+    ContractLogInfo,
+    Error,
+};
+
+/// A parameter of an event in the Solidity contract ABI.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AbiEventParam {
+    /// The name of the parameter, empty if the parameter is unnamed.
+    pub name: String,
+
+    /// The type of the parameter.
+    pub ty: AbiType,
+
+    /// Whether the parameter is stored in the log's topics rather than its data.
+    pub indexed: bool,
+}
+
+/// An event in the Solidity contract ABI.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AbiEvent {
+    /// The name of the event.
+    pub name: String,
+
+    /// The parameters of the event.
+    pub inputs: Vec<AbiEventParam>,
+
+    /// Whether the event was declared `anonymous`, that is, it doesn't have its topic as the first log topic.
+    pub anonymous: bool,
+}
+
+impl AbiEvent {
+    /// Returns the canonical signature of this event, for example `Transfer(address,address,uint256)`.
+    #[must_use]
+    pub fn signature(&self) -> String {
+        let types: Vec<_> = self.inputs.iter().map(|it| it.ty.to_string()).collect();
+
+        format!("{}({})", self.name, types.join(","))
+    }
+
+    /// Returns the topic that identifies this event, the Keccak-256 hash of its signature.
+    #[must_use]
+    pub fn topic(&self) -> [u8; 32] {
+        Keccak256::digest(self.signature().as_bytes()).into()
+    }
+
+    /// Decode the parameters of this event from `log`.
+    ///
+    /// Indexed parameters of reference types (like `string` or arrays) are only stored as a hash,
+    /// so they're decoded as a 32 byte [`AbiValue::FixedBytes`].
+    ///
+    /// # Errors
+    /// - [`Error::AbiDecode`] if `log` wasn't emitted by this event, or isn't a valid encoding of it.
+    ///
+    /// # Panics
+    /// Never, the number of topics and data values are checked against the event's parameters first.
+    pub fn decode_log(&self, log: &ContractLogInfo) -> crate::Result<AbiValues> {
+        let mut topics = log.topics.iter();
+
+        if !self.anonymous && topics.next().map(Vec::as_slice) != Some(&self.topic()[..]) {
+            return Err(Error::abi_decode(format!("log wasn't emitted by event `{}`", self.name)));
+        }
+
+        let (indexed, non_indexed): (Vec<_>, Vec<_>) =
+            self.inputs.iter().partition(|it| it.indexed);
+
+        if topics.len() != indexed.len() {
+            return Err(Error::abi_decode(format!(
+                "expected {} indexed topics for event `{}`, found {}",
+                indexed.len(),
+                self.name,
+                topics.len()
+            )));
+        }
+
+        let data_types: Vec<_> = non_indexed.iter().map(|it| it.ty.clone()).collect();
+        let mut data_values = codec::decode(&data_types, &log.data)?.into_iter();
+
+        let mut names = Vec::with_capacity(self.inputs.len());
+        let mut values = Vec::with_capacity(self.inputs.len());
+
+        for input in &self.inputs {
+            let value = if input.indexed {
+                // the iterator has as many topics as there are indexed parameters.
+                let topic = topics.next().unwrap();
+
+                if input.ty.is_value_type() {
+                    codec::decode(std::slice::from_ref(&input.ty), topic)?.remove(0)
+                } else {
+                    AbiValue::FixedBytes(topic.clone())
+                }
+            } else {
+                // as many values were decoded as there are non-indexed parameters.
+                data_values.next().unwrap()
+            };
+
+            names.push(input.name.clone());
+            values.push(value);
+        }
+
+        Ok(AbiValues::new(names, values))
+    }
+}
+
+/// A contract log decoded with a Solidity contract ABI.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AbiEventLog {
+    /// The contract that emitted the log.
+    pub contract_id: ContractId,
+
+    /// The name of the event that was emitted.
+    pub name: String,
+
+    /// The parameters of the event.
+    pub values: AbiValues,
+}
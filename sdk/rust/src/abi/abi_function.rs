@@ -0,0 +1,211 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use super::{
+    codec,
+    AbiType,
+    AbiValue,
+    AbiValues,
+};
+use crate::contract::ContractFunctionSelector;
+use crate::Error;
+
+/// A named, typed parameter of a function in the Solidity contract ABI.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AbiParam {
+    /// The name of the parameter, empty if the parameter is unnamed.
+    pub name: String,
+
+    /// The type of the parameter.
+    pub ty: AbiType,
+}
+
+/// How a function interacts with the state of the blockchain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StateMutability {
+    /// The function doesn't read or modify state.
+    Pure,
+
+    /// The function reads but doesn't modify state.
+    View,
+
+    /// The function may modify state, but doesn't accept hbar.
+    #[default]
+    NonPayable,
+
+    /// The function may modify state, and accepts hbar.
+    Payable,
+}
+
+/// A function in the Solidity contract ABI.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AbiFunction {
+    /// The name of the function.
+    pub name: String,
+
+    /// The parameters of the function.
+    pub inputs: Vec<AbiParam>,
+
+    /// The values returned by the function.
+    pub outputs: Vec<AbiParam>,
+
+    /// How the function interacts with the state of the blockchain.
+    pub state_mutability: StateMutability,
+}
+
+impl AbiFunction {
+    /// Create a function from its signature, for example `transfer(address,uint256)`.
+    ///
+    /// The parameters of the function are unnamed and it has no outputs,
+    /// which is enough to encode and decode calls to it.
+    ///
+    /// # Errors
+    /// - [`Error::AbiParse`] if `signature` isn't a valid function signature.
+    pub fn from_signature(signature: &str) -> crate::Result<Self> {
+        let invalid = || Error::abi_parse(format!("invalid function signature `{signature}`"));
+
+        let (name, params) = match signature.find('(') {
+            Some(index) if index > 0 => signature.split_at(index),
+            _ => return Err(invalid()),
+        };
+
+        if !name.chars().all(|it| it.is_ascii_alphanumeric() || it == '_' || it == '$') {
+            return Err(invalid());
+        }
+
+        let inputs = match params.parse::<AbiType>().map_err(|_| invalid())? {
+            AbiType::Tuple(types) => {
+                types.into_iter().map(|ty| AbiParam { name: String::new(), ty }).collect()
+            }
+            _ => return Err(invalid()),
+        };
+
+        Ok(Self {
+            name: name.to_owned(),
+            inputs,
+            outputs: Vec::new(),
+            state_mutability: StateMutability::default(),
+        })
+    }
+
+    /// Returns the canonical signature of this function, for example `transfer(address,uint256)`.
+    #[must_use]
+    pub fn signature(&self) -> String {
+        let types: Vec<_> = self.inputs.iter().map(|it| it.ty.to_string()).collect();
+
+        format!("{}({})", self.name, types.join(","))
+    }
+
+    /// Returns the 4 byte selector that identifies this function in call data.
+    #[must_use]
+    pub fn selector(&self) -> [u8; 4] {
+        let mut selector = ContractFunctionSelector::new(&self.name);
+
+        for input in &self.inputs {
+            selector.add_param_type(&input.ty.to_string());
+        }
+
+        selector.finish()
+    }
+
+    /// Encode a call to this function with `args`, including the function selector.
+    ///
+    /// The result can be passed directly to
+    /// [`ContractExecuteTransaction::function_parameters`](crate::ContractExecuteTransaction::function_parameters)
+    /// or [`ContractCallQuery::function_parameters`](crate::ContractCallQuery::function_parameters).
+    ///
+    /// # Errors
+    /// - [`Error::AbiEncode`](crate::Error::AbiEncode) if `args` don't match the function's inputs.
+    pub fn encode_input(&self, args: &[AbiValue]) -> crate::Result<Vec<u8>> {
+        let encoded = codec::encode(&param_types(&self.inputs), args)?;
+
+        Ok([&self.selector()[..], &encoded].concat())
+    }
+
+    /// Decode the arguments of a call to this function from call `data` (including the function selector).
+    ///
+    /// This is the inverse of [`encode_input`](Self::encode_input) and
+    /// [`ContractFunctionParameters::to_bytes`](crate::ContractFunctionParameters::to_bytes),
+    /// for example, to show the arguments of a [`ContractExecuteTransaction`](crate::ContractExecuteTransaction).
+    ///
+    /// # Errors
+    /// - [`Error::AbiDecode`] if `data` isn't a call to this function, or isn't a valid encoding of its inputs.
+    pub fn decode_input(&self, data: &[u8]) -> crate::Result<AbiValues> {
+        if data.get(..4) != Some(&self.selector()[..]) {
+            return Err(Error::abi_decode(format!(
+                "data isn't a call to function `{}`",
+                self.signature()
+            )));
+        }
+
+        decode_params(&self.inputs, &data[4..])
+    }
+
+    /// Decode the values returned by this function.
+    ///
+    /// # Errors
+    /// - [`Error::AbiDecode`](crate::Error::AbiDecode) if `data` isn't a valid encoding of the function's outputs.
+    pub fn decode_output(&self, data: &[u8]) -> crate::Result<AbiValues> {
+        decode_params(&self.outputs, data)
+    }
+}
+
+/// The constructor of a contract in the Solidity contract ABI.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AbiConstructor {
+    /// The parameters of the constructor.
+    pub inputs: Vec<AbiParam>,
+
+    /// Whether the constructor accepts hbar.
+    pub state_mutability: StateMutability,
+}
+
+impl AbiConstructor {
+    /// Encode the arguments to this constructor.
+    ///
+    /// The result can be passed directly to
+    /// [`ContractCreateTransaction::constructor_parameters`](crate::ContractCreateTransaction::constructor_parameters).
+    ///
+    /// # Errors
+    /// - [`Error::AbiEncode`](crate::Error::AbiEncode) if `args` don't match the constructor's inputs.
+    pub fn encode_input(&self, args: &[AbiValue]) -> crate::Result<Vec<u8>> {
+        codec::encode(&param_types(&self.inputs), args)
+    }
+
+    /// Decode the arguments to this constructor, the inverse of [`encode_input`](Self::encode_input).
+    ///
+    /// # Errors
+    /// - [`Error::AbiDecode`] if `data` isn't a valid encoding of the constructor's inputs.
+    pub fn decode_input(&self, data: &[u8]) -> crate::Result<AbiValues> {
+        decode_params(&self.inputs, data)
+    }
+}
+
+pub(super) fn param_types(params: &[AbiParam]) -> Vec<AbiType> {
+    params.iter().map(|it| it.ty.clone()).collect()
+}
+
+pub(super) fn decode_params(params: &[AbiParam], data: &[u8]) -> crate::Result<AbiValues> {
+    let values = codec::decode(&param_types(params), data)?;
+    let names = params.iter().map(|it| it.name.clone()).collect();
+
+    Ok(AbiValues::new(names, values))
+}
@@ -0,0 +1,308 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::Error;
+
+/// The type of a value in the Solidity contract ABI.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AbiType {
+    /// `address`, a 20 byte EVM address.
+    Address,
+
+    /// `bool`.
+    Bool,
+
+    /// `int<M>`, a signed integer of `M` bits.
+    Int(usize),
+
+    /// `uint<M>`, an unsigned integer of `M` bits.
+    Uint(usize),
+
+    /// `bytes<M>`, a byte array of exactly `M` bytes.
+    FixedBytes(usize),
+
+    /// `bytes`, a dynamically sized byte array.
+    Bytes,
+
+    /// `string`, a dynamically sized UTF-8 string.
+    String,
+
+    /// `function`, an address followed by a function selector.
+    Function,
+
+    /// `T[]`, a dynamically sized array of `T`.
+    Array(Box<AbiType>),
+
+    /// `T[k]`, an array of exactly `k` elements of `T`.
+    FixedArray(Box<AbiType>, usize),
+
+    /// `(T1,T2,...,Tn)`, a tuple (or Solidity `struct`) made of values of types `T1` through `Tn`.
+    Tuple(Vec<AbiType>),
+}
+
+impl AbiType {
+    /// Returns `true` if values of this type are encoded out of line, in the "tail" of the encoding.
+    #[must_use]
+    pub fn is_dynamic(&self) -> bool {
+        match self {
+            Self::Bytes | Self::String | Self::Array(_) => true,
+            Self::FixedArray(element, _) => element.is_dynamic(),
+            Self::Tuple(elements) => elements.iter().any(Self::is_dynamic),
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if this is one of Solidity's value types, rather than a reference type (like arrays).
+    ///
+    /// Indexed event parameters of reference types are only stored as a hash.
+    pub(crate) fn is_value_type(&self) -> bool {
+        matches!(
+            self,
+            Self::Address
+                | Self::Bool
+                | Self::Int(_)
+                | Self::Uint(_)
+                | Self::FixedBytes(_)
+                | Self::Function
+        )
+    }
+
+    /// Returns `false` if this is an integer or fixed bytes type of a size the ABI doesn't allow, like `uint7` or `bytes40`.
+    ///
+    /// Only this type is checked, not the types nested in it.
+    pub(crate) fn has_valid_size(&self) -> bool {
+        match self {
+            Self::Int(bits) | Self::Uint(bits) => is_valid_int_bits(*bits),
+            Self::FixedBytes(len) => is_valid_fixed_bytes_len(*len),
+            _ => true,
+        }
+    }
+
+    /// The number of bytes a value of this type takes in the "head" of an encoding.
+    ///
+    /// Dynamic types only store an offset in the head, static types are stored inline.
+    ///
+    /// Returns `None` if the length overflows a `usize`.
+    pub(crate) fn head_len(&self) -> Option<usize> {
+        match self {
+            _ if self.is_dynamic() => Some(32),
+            Self::FixedArray(element, len) => element.head_len()?.checked_mul(*len),
+            Self::Tuple(elements) => Self::tuple_head_len(elements),
+            _ => Some(32),
+        }
+    }
+
+    /// The number of bytes the values of `types` take in the "head" of an encoding of them as a tuple.
+    ///
+    /// Returns `None` if the length overflows a `usize`.
+    pub(crate) fn tuple_head_len(types: &[Self]) -> Option<usize> {
+        types.iter().try_fold(0_usize, |len, it| len.checked_add(it.head_len()?))
+    }
+}
+
+impl fmt::Display for AbiType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Address => f.write_str("address"),
+            Self::Bool => f.write_str("bool"),
+            Self::Int(bits) => write!(f, "int{bits}"),
+            Self::Uint(bits) => write!(f, "uint{bits}"),
+            Self::FixedBytes(len) => write!(f, "bytes{len}"),
+            Self::Bytes => f.write_str("bytes"),
+            Self::String => f.write_str("string"),
+            Self::Function => f.write_str("function"),
+            Self::Array(element) => write!(f, "{element}[]"),
+            Self::FixedArray(element, len) => write!(f, "{element}[{len}]"),
+            Self::Tuple(elements) => {
+                f.write_str("(")?;
+
+                for (index, element) in elements.iter().enumerate() {
+                    if index != 0 {
+                        f.write_str(",")?;
+                    }
+
+                    write!(f, "{element}")?;
+                }
+
+                f.write_str(")")
+            }
+        }
+    }
+}
+
+impl FromStr for AbiType {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(element) = s.strip_suffix("[]") {
+            return Ok(Self::Array(Box::new(element.parse()?)));
+        }
+
+        if let Some(rest) = s.strip_suffix(']') {
+            let (element, len) = rest
+                .rsplit_once('[')
+                .ok_or_else(|| Error::abi_parse(format!("invalid ABI type `{s}`")))?;
+
+            let len = len.parse().ok().filter(|it| *it > 0 && !len.starts_with('0')).ok_or_else(
+                || Error::abi_parse(format!("invalid array length in ABI type `{s}`")),
+            )?;
+
+            return Ok(Self::FixedArray(Box::new(element.parse()?), len));
+        }
+
+        if let Some(elements) = s.strip_prefix('(').and_then(|it| it.strip_suffix(')')) {
+            return split_tuple(elements)
+                .ok_or_else(|| Error::abi_parse(format!("invalid ABI type `{s}`")))?
+                .into_iter()
+                .map(str::parse)
+                .collect::<crate::Result<_>>()
+                .map(Self::Tuple);
+        }
+
+        match s {
+            "address" => return Ok(Self::Address),
+            "bool" => return Ok(Self::Bool),
+            "bytes" => return Ok(Self::Bytes),
+            "string" => return Ok(Self::String),
+            "function" => return Ok(Self::Function),
+            "int" => return Ok(Self::Int(256)),
+            "uint" => return Ok(Self::Uint(256)),
+            _ => {}
+        }
+
+        let parse_size = |size: &str, valid: fn(usize) -> bool| {
+            size.parse()
+                .ok()
+                .filter(|it| !size.starts_with('0') && valid(*it))
+                .ok_or_else(|| Error::abi_parse(format!("invalid ABI type `{s}`")))
+        };
+
+        if let Some(bits) = s.strip_prefix("uint") {
+            parse_size(bits, is_valid_int_bits).map(Self::Uint)
+        } else if let Some(bits) = s.strip_prefix("int") {
+            parse_size(bits, is_valid_int_bits).map(Self::Int)
+        } else if let Some(len) = s.strip_prefix("bytes") {
+            parse_size(len, is_valid_fixed_bytes_len).map(Self::FixedBytes)
+        } else {
+            Err(Error::abi_parse(format!("unknown ABI type `{s}`")))
+        }
+    }
+}
+
+fn is_valid_int_bits(bits: usize) -> bool {
+    bits.is_multiple_of(8) && (8..=256).contains(&bits)
+}
+
+fn is_valid_fixed_bytes_len(len: usize) -> bool {
+    (1..=32).contains(&len)
+}
+
+/// Splits the inside of a tuple type at its top-level commas.
+///
+/// Returns `None` if the parentheses are unbalanced.
+fn split_tuple(s: &str) -> Option<Vec<&str>> {
+    if s.is_empty() {
+        return Some(Vec::new());
+    }
+
+    let mut elements = Vec::new();
+    let mut depth = 0_usize;
+    let mut start = 0;
+
+    for (index, c) in s.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.checked_sub(1)?,
+            ',' if depth == 0 => {
+                elements.push(&s[start..index]);
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+
+    if depth != 0 {
+        return None;
+    }
+
+    elements.push(&s[start..]);
+
+    Some(elements)
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+
+    use super::AbiType;
+    use crate::Error;
+
+    #[test]
+    fn parse() -> crate::Result<()> {
+        assert_eq!("uint".parse::<AbiType>()?, AbiType::Uint(256));
+        assert_eq!("int24".parse::<AbiType>()?, AbiType::Int(24));
+        assert_eq!("bytes10".parse::<AbiType>()?, AbiType::FixedBytes(10));
+        assert_eq!(
+            "bool[3][]".parse::<AbiType>()?,
+            AbiType::Array(Box::new(AbiType::FixedArray(Box::new(AbiType::Bool), 3)))
+        );
+        assert_eq!(
+            "string[][]".parse::<AbiType>()?,
+            AbiType::Array(Box::new(AbiType::Array(Box::new(AbiType::String))))
+        );
+
+        assert_eq!(
+            "(uint,(bool,string)[])[2]".parse::<AbiType>()?,
+            AbiType::FixedArray(
+                Box::new(AbiType::Tuple(vec![
+                    AbiType::Uint(256),
+                    AbiType::Array(Box::new(AbiType::Tuple(vec![AbiType::Bool, AbiType::String]))),
+                ])),
+                2
+            )
+        );
+        assert_eq!("()".parse::<AbiType>()?, AbiType::Tuple(Vec::new()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn display_is_canonical() -> crate::Result<()> {
+        assert_eq!("uint".parse::<AbiType>()?.to_string(), "uint256");
+        assert_eq!("address[]".parse::<AbiType>()?.to_string(), "address[]");
+        assert_eq!("(uint,bytes32[3])[]".parse::<AbiType>()?.to_string(), "(uint256,bytes32[3])[]");
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_invalid() {
+        for s in [
+            "uint7", "uint264", "uint08", "int0", "bytes0", "bytes33", "float", "uint256]",
+            "bool[0]", "bool[01]", "(bool", "(bool))", "(bool,)",
+        ] {
+            assert_matches!(s.parse::<AbiType>(), Err(Error::AbiParse(_)), "{s}");
+        }
+    }
+}
@@ -0,0 +1,216 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use num_bigint::{
+    BigInt,
+    BigUint,
+};
+
+use crate::EvmAddress;
+
+/// A value that can be encoded with (or was decoded from) the Solidity contract ABI.
+///
+/// Which [`AbiType`](crate::AbiType) a value can be encoded as depends on its variant,
+/// for example, an [`AbiValue::Uint`] can be encoded as any `uint<M>` it fits in.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum AbiValue {
+    /// An `address`.
+    Address(EvmAddress),
+
+    /// A `bool`.
+    Bool(bool),
+
+    /// An `int<M>`.
+    Int(BigInt),
+
+    /// A `uint<M>`.
+    Uint(BigUint),
+
+    /// A `bytes<M>`.
+    FixedBytes(Vec<u8>),
+
+    /// A `bytes`.
+    Bytes(Vec<u8>),
+
+    /// A `string`.
+    String(String),
+
+    /// A `function`: a 20 byte address followed by a 4 byte function selector.
+    Function([u8; 24]),
+
+    /// A `T[]`.
+    Array(Vec<AbiValue>),
+
+    /// A `T[k]`.
+    FixedArray(Vec<AbiValue>),
+
+    /// A `(T1,T2,...,Tn)`, or a Solidity `struct`.
+    Tuple(Vec<AbiValue>),
+}
+
+impl AbiValue {
+    /// Returns the value if this is an `address`.
+    #[must_use]
+    pub fn as_address(&self) -> Option<EvmAddress> {
+        match self {
+            Self::Address(it) => Some(*it),
+            _ => None,
+        }
+    }
+
+    /// Returns the value if this is a `bool`.
+    #[must_use]
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Self::Bool(it) => Some(*it),
+            _ => None,
+        }
+    }
+
+    /// Returns the value if this is an `int<M>`.
+    #[must_use]
+    pub fn as_int(&self) -> Option<&BigInt> {
+        match self {
+            Self::Int(it) => Some(it),
+            _ => None,
+        }
+    }
+
+    /// Returns the value if this is a `uint<M>`.
+    #[must_use]
+    pub fn as_uint(&self) -> Option<&BigUint> {
+        match self {
+            Self::Uint(it) => Some(it),
+            _ => None,
+        }
+    }
+
+    /// Returns the value if this is a `bytes` or `bytes<M>`.
+    #[must_use]
+    pub fn as_bytes(&self) -> Option<&[u8]> {
+        match self {
+            Self::Bytes(it) | Self::FixedBytes(it) => Some(it),
+            _ => None,
+        }
+    }
+
+    /// Returns the value if this is a `string`.
+    #[must_use]
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(it) => Some(it),
+            _ => None,
+        }
+    }
+
+    /// Returns the elements if this is a `T[]` or `T[k]`.
+    #[must_use]
+    pub fn as_array(&self) -> Option<&[AbiValue]> {
+        match self {
+            Self::Array(it) | Self::FixedArray(it) => Some(it),
+            _ => None,
+        }
+    }
+
+    /// Returns the elements if this is a tuple.
+    #[must_use]
+    pub fn as_tuple(&self) -> Option<&[AbiValue]> {
+        match self {
+            Self::Tuple(it) => Some(it),
+            _ => None,
+        }
+    }
+}
+
+impl From<EvmAddress> for AbiValue {
+    fn from(value: EvmAddress) -> Self {
+        Self::Address(value)
+    }
+}
+
+impl From<bool> for AbiValue {
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
+
+impl From<BigInt> for AbiValue {
+    fn from(value: BigInt) -> Self {
+        Self::Int(value)
+    }
+}
+
+impl From<BigUint> for AbiValue {
+    fn from(value: BigUint) -> Self {
+        Self::Uint(value)
+    }
+}
+
+impl From<Vec<u8>> for AbiValue {
+    fn from(value: Vec<u8>) -> Self {
+        Self::Bytes(value)
+    }
+}
+
+impl From<&[u8]> for AbiValue {
+    fn from(value: &[u8]) -> Self {
+        Self::Bytes(value.to_vec())
+    }
+}
+
+impl<const N: usize> From<[u8; N]> for AbiValue {
+    fn from(value: [u8; N]) -> Self {
+        Self::FixedBytes(value.to_vec())
+    }
+}
+
+impl From<String> for AbiValue {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+impl From<&str> for AbiValue {
+    fn from(value: &str) -> Self {
+        Self::String(value.to_owned())
+    }
+}
+
+impl From<Vec<AbiValue>> for AbiValue {
+    fn from(value: Vec<AbiValue>) -> Self {
+        Self::Array(value)
+    }
+}
+
+macro_rules! impl_from_int {
+    ($variant:ident: $($ty:ty),*) => {
+        $(
+            impl From<$ty> for AbiValue {
+                fn from(value: $ty) -> Self {
+                    Self::$variant(value.into())
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(Uint: u8, u16, u32, u64, u128);
+impl_from_int!(Int: i8, i16, i32, i64, i128);
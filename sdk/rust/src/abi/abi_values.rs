@@ -0,0 +1,92 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::ops::Index;
+
+use super::AbiValue;
+
+/// A list of values decoded with the Solidity contract ABI, along with their names.
+///
+/// Values can be looked up either by their position or by their name,
+/// unnamed values (an empty name in the ABI) are only accessible by position.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AbiValues {
+    names: Vec<String>,
+    values: Vec<AbiValue>,
+}
+
+impl AbiValues {
+    pub(crate) fn new(names: Vec<String>, values: Vec<AbiValue>) -> Self {
+        debug_assert_eq!(names.len(), values.len());
+
+        Self { names, values }
+    }
+
+    /// Returns the number of values.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns `true` if there are no values.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Returns the value at `index`.
+    #[must_use]
+    pub fn get(&self, index: usize) -> Option<&AbiValue> {
+        self.values.get(index)
+    }
+
+    /// Returns the first value named `name`.
+    #[must_use]
+    pub fn get_by_name(&self, name: &str) -> Option<&AbiValue> {
+        self.names.iter().position(|it| it == name).map(|index| &self.values[index])
+    }
+
+    /// Returns an iterator over the names and values, in order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &AbiValue)> {
+        self.names.iter().map(String::as_str).zip(&self.values)
+    }
+
+    /// Returns the values, discarding their names.
+    #[must_use]
+    pub fn into_values(self) -> Vec<AbiValue> {
+        self.values
+    }
+}
+
+impl Index<usize> for AbiValues {
+    type Output = AbiValue;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.values[index]
+    }
+}
+
+impl Index<&str> for AbiValues {
+    type Output = AbiValue;
+
+    fn index(&self, name: &str) -> &Self::Output {
+        self.get_by_name(name).unwrap_or_else(|| panic!("no value named `{name}`"))
+    }
+}
@@ -0,0 +1,212 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use super::{
+    AbiFunction,
+    AbiParam,
+    StateMutability,
+};
+
+/// Builds the function the code generated by `abigen!` calls, from the types it already validated.
+#[doc(hidden)]
+#[must_use]
+pub fn abigen_function(name: &str, inputs: &[&str], outputs: &[&str]) -> AbiFunction {
+    let params = |types: &[&str]| {
+        types
+            .iter()
+            .map(|ty| AbiParam {
+                name: String::new(),
+                ty: ty
+                    .parse()
+                    .unwrap_or_else(|_| panic!("`abigen!` produced an invalid type `{ty}`")),
+            })
+            .collect()
+    };
+
+    AbiFunction {
+        name: name.to_owned(),
+        inputs: params(inputs),
+        outputs: params(outputs),
+        state_mutability: StateMutability::default(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hedera_proto::services;
+    use hex_literal::hex;
+    use num_bigint::BigUint;
+
+    use self::shop::Shop;
+    use crate::abi::codec;
+    use crate::protobuf::{
+        FromProtobuf,
+        ToProtobuf,
+    };
+    use crate::{
+        AbiType,
+        AbiValue,
+        ContractFunctionResult,
+        ContractId,
+        EvmAddress,
+    };
+
+    // not every generated method is used.
+    #[allow(dead_code)]
+    mod shop {
+        crate::abigen!(
+            pub Shop,
+            r#"[
+            {
+                "type": "function",
+                "name": "balanceOf",
+                "stateMutability": "view",
+                "inputs": [{ "name": "owner", "type": "address" }],
+                "outputs": [{ "name": "", "type": "uint256" }]
+            },
+            {
+                "type": "function",
+                "name": "transfer",
+                "stateMutability": "nonpayable",
+                "inputs": [
+                    { "name": "to", "type": "address" },
+                    { "name": "amount", "type": "uint256" }
+                ],
+                "outputs": [{ "name": "", "type": "bool" }]
+            },
+            {
+                "type": "function",
+                "name": "getOrder",
+                "stateMutability": "view",
+                "inputs": [{ "name": "_id", "type": "uint64" }],
+                "outputs": [
+                    { "name": "owner", "type": "address" },
+                    {
+                        "name": "items",
+                        "type": "tuple[]",
+                        "components": [
+                            { "name": "name", "type": "string" },
+                            { "name": "count", "type": "uint24" }
+                        ]
+                    }
+                ]
+            },
+            {
+                "type": "function",
+                "name": "new",
+                "stateMutability": "nonpayable",
+                "inputs": [],
+                "outputs": []
+            },
+            {
+                "type": "function",
+                "name": "contractId",
+                "stateMutability": "view",
+                "inputs": [],
+                "outputs": [{ "name": "", "type": "uint64" }]
+            },
+            {
+                "type": "event",
+                "name": "Transfer",
+                "inputs": [],
+                "anonymous": false
+            }
+        ]"#
+        );
+    }
+
+    #[test]
+    fn transaction_builder() -> crate::Result<()> {
+        let shop = Shop::new(ContractId::from(5));
+
+        let transaction = shop.transfer(EvmAddress::from([0x11; 20]), BigUint::from(10_u8))?;
+
+        assert_eq!(transaction.get_contract_id(), Some(ContractId::from(5)));
+        assert_eq!(
+            transaction.get_function_parameters(),
+            hex!(
+                "a9059cbb"
+                "0000000000000000000000001111111111111111111111111111111111111111"
+                "000000000000000000000000000000000000000000000000000000000000000a"
+            )
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn query_builder() -> crate::Result<()> {
+        let shop = Shop::new(ContractId::from(5));
+
+        let query = shop.balance_of(EvmAddress::from([0x11; 20]))?;
+
+        assert_eq!(query.get_contract_id(), Some(ContractId::from(5)));
+        assert_eq!(&query.get_contract_parameters()[..4], hex!("70a08231"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn reserved_names() -> crate::Result<()> {
+        let shop = Shop::new(ContractId::from(5));
+
+        assert_eq!(shop.contract_id(), ContractId::from(5));
+
+        let transaction = shop.call_new()?;
+        assert_eq!(
+            transaction.get_function_parameters(),
+            super::abigen_function("new", &[], &[]).selector()
+        );
+
+        let query = shop.call_contract_id()?;
+        assert_eq!(
+            query.get_contract_parameters(),
+            super::abigen_function("contractId", &[], &[]).selector()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn typed_outputs() -> crate::Result<()> {
+        let types: Vec<AbiType> = vec!["address".parse()?, "(string,uint24)[]".parse()?];
+
+        let bytes = codec::encode(
+            &types,
+            &[
+                EvmAddress::from([0x22; 20]).into(),
+                AbiValue::Array(vec![AbiValue::Tuple(vec!["apple".into(), 3_u8.into()])]),
+            ],
+        )?;
+
+        let result = ContractFunctionResult::from_protobuf(services::ContractFunctionResult {
+            contract_id: Some(ContractId::from(5).to_protobuf()),
+            contract_call_result: bytes,
+            ..Default::default()
+        })?;
+
+        let (owner, items) = Shop::decode_get_order(&result)?;
+
+        assert_eq!(owner, EvmAddress::from([0x22; 20]));
+        assert_eq!(items, [("apple".to_owned(), 3_u32)]);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,525 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+//! Encoding and decoding as described by the [Solidity contract ABI specification](https://docs.soliditylang.org/en/latest/abi-spec.html).
+
+use num_bigint::{
+    BigInt,
+    BigUint,
+    Sign,
+};
+
+use super::{
+    AbiType,
+    AbiValue,
+};
+use crate::{
+    Error,
+    EvmAddress,
+};
+
+const WORD: usize = 32;
+
+/// Encodes `values` as a tuple of `types`.
+pub(crate) fn encode(types: &[AbiType], values: &[AbiValue]) -> crate::Result<Vec<u8>> {
+    let mut out = Vec::new();
+    encode_tuple(types, values, &mut out)?;
+    Ok(out)
+}
+
+/// Encodes a single `value` of type `ty`, without the offset a dynamic value would have in the head of a tuple.
+pub(crate) fn encode_single(ty: &AbiType, value: &AbiValue) -> crate::Result<Vec<u8>> {
+    let mut out = Vec::new();
+    encode_value(ty, value, &mut out)?;
+    Ok(out)
+}
+
+/// Decodes `data` as a tuple of `types`.
+pub(crate) fn decode(types: &[AbiType], data: &[u8]) -> crate::Result<Vec<AbiValue>> {
+    decode_tuple(types, data)
+}
+
+fn encode_tuple(types: &[AbiType], values: &[AbiValue], out: &mut Vec<u8>) -> crate::Result<()> {
+    if types.len() != values.len() {
+        return Err(Error::abi_encode(format!(
+            "expected {} values, found {}",
+            types.len(),
+            values.len()
+        )));
+    }
+
+    let head_len = AbiType::tuple_head_len(types)
+        .ok_or_else(|| Error::abi_encode("types are too large to encode"))?;
+
+    let mut head = Vec::with_capacity(head_len);
+    let mut tail = Vec::new();
+
+    for (ty, value) in types.iter().zip(values) {
+        if ty.is_dynamic() {
+            head.extend_from_slice(&usize_word(head_len + tail.len()));
+            encode_value(ty, value, &mut tail)?;
+        } else {
+            encode_value(ty, value, &mut head)?;
+        }
+    }
+
+    out.append(&mut head);
+    out.append(&mut tail);
+
+    Ok(())
+}
+
+fn encode_value(ty: &AbiType, value: &AbiValue, out: &mut Vec<u8>) -> crate::Result<()> {
+    if !ty.has_valid_size() {
+        return Err(Error::abi_encode(format!("invalid ABI type `{ty}`")));
+    }
+
+    match (ty, value) {
+        (AbiType::Address, AbiValue::Address(address)) => {
+            out.extend_from_slice(&left_pad(&address.to_bytes(), 0));
+        }
+
+        (AbiType::Bool, AbiValue::Bool(value)) => {
+            out.extend_from_slice(&usize_word(usize::from(*value)));
+        }
+
+        (AbiType::Uint(bits), AbiValue::Uint(value)) if value.bits() <= *bits as u64 => {
+            out.extend_from_slice(&left_pad(&value.to_bytes_be(), 0));
+        }
+
+        (AbiType::Int(bits), AbiValue::Int(value)) if int_fits(value, *bits) => {
+            let fill = if value.sign() == Sign::Minus { 0xff } else { 0 };
+            out.extend_from_slice(&left_pad(&value.to_signed_bytes_be(), fill));
+        }
+
+        (AbiType::FixedBytes(len), AbiValue::FixedBytes(bytes)) if bytes.len() == *len => {
+            out.extend_from_slice(&right_pad(bytes));
+        }
+
+        (AbiType::Bytes, AbiValue::Bytes(bytes)) => encode_bytes(bytes, out),
+
+        (AbiType::String, AbiValue::String(s)) => encode_bytes(s.as_bytes(), out),
+
+        (AbiType::Function, AbiValue::Function(function)) => {
+            out.extend_from_slice(&right_pad(function));
+        }
+
+        (AbiType::Array(element), AbiValue::Array(values)) => {
+            out.extend_from_slice(&usize_word(values.len()));
+            encode_tuple(&vec![(**element).clone(); values.len()], values, out)?;
+        }
+
+        (AbiType::FixedArray(element, len), AbiValue::FixedArray(values))
+            if values.len() == *len =>
+        {
+            encode_tuple(&vec![(**element).clone(); *len], values, out)?;
+        }
+
+        (AbiType::Tuple(types), AbiValue::Tuple(values)) => encode_tuple(types, values, out)?,
+
+        _ => {
+            return Err(Error::abi_encode(format!("value `{value:?}` can't be encoded as `{ty}`")))
+        }
+    }
+
+    Ok(())
+}
+
+fn encode_bytes(bytes: &[u8], out: &mut Vec<u8>) {
+    out.extend_from_slice(&usize_word(bytes.len()));
+
+    for chunk in bytes.chunks(WORD) {
+        out.extend_from_slice(&right_pad(chunk));
+    }
+}
+
+fn int_fits(value: &BigInt, bits: usize) -> bool {
+    let limit = BigInt::from(1) << (bits - 1);
+
+    -&limit <= *value && *value < limit
+}
+
+fn left_pad(bytes: &[u8], fill: u8) -> [u8; WORD] {
+    let mut word = [fill; WORD];
+    word[(WORD - bytes.len())..].copy_from_slice(bytes);
+    word
+}
+
+fn right_pad(bytes: &[u8]) -> [u8; WORD] {
+    let mut word = [0; WORD];
+    word[..bytes.len()].copy_from_slice(bytes);
+    word
+}
+
+fn usize_word(value: usize) -> [u8; WORD] {
+    left_pad(&(value as u64).to_be_bytes(), 0)
+}
+
+/// Decodes the value of type `ty` whose head is at `offset` in the tuple encoded in `data`.
+pub(crate) fn decode_at(ty: &AbiType, data: &[u8], offset: usize) -> crate::Result<AbiValue> {
+    let offset = if ty.is_dynamic() { read_usize(data, offset)? } else { offset };
+
+    decode_value(ty, data.get(offset..).ok_or_else(out_of_bounds)?)
+}
+
+fn decode_tuple(types: &[AbiType], data: &[u8]) -> crate::Result<Vec<AbiValue>> {
+    let mut offset = 0;
+    let mut values = Vec::with_capacity(types.len());
+
+    for ty in types {
+        values.push(decode_at(ty, data, offset)?);
+        offset = ty
+            .head_len()
+            .and_then(|len| offset.checked_add(len))
+            .ok_or_else(|| Error::abi_decode(format!("type `{ty}` is too large to decode")))?;
+    }
+
+    Ok(values)
+}
+
+fn decode_value(ty: &AbiType, data: &[u8]) -> crate::Result<AbiValue> {
+    if !ty.has_valid_size() {
+        return Err(Error::abi_decode(format!("invalid ABI type `{ty}`")));
+    }
+
+    let value = match ty {
+        AbiType::Address => {
+            let word = read_word(data, 0)?;
+            AbiValue::Address(EvmAddress::from(<[u8; 20]>::try_from(&word[12..]).unwrap()))
+        }
+
+        AbiType::Bool => match read_usize(data, 0)? {
+            0 => AbiValue::Bool(false),
+            1 => AbiValue::Bool(true),
+            _ => return Err(Error::abi_decode("invalid `bool`")),
+        },
+
+        AbiType::Uint(bits) => {
+            let value = BigUint::from_bytes_be(read_word(data, 0)?);
+
+            if value.bits() > *bits as u64 {
+                return Err(Error::abi_decode(format!("value out of range for `{ty}`")));
+            }
+
+            AbiValue::Uint(value)
+        }
+
+        AbiType::Int(bits) => {
+            let value = BigInt::from_signed_bytes_be(read_word(data, 0)?);
+
+            if !int_fits(&value, *bits) {
+                return Err(Error::abi_decode(format!("value out of range for `{ty}`")));
+            }
+
+            AbiValue::Int(value)
+        }
+
+        AbiType::FixedBytes(len) => AbiValue::FixedBytes(read_word(data, 0)?[..*len].to_vec()),
+
+        AbiType::Bytes => AbiValue::Bytes(decode_bytes(data)?.to_vec()),
+
+        AbiType::String => AbiValue::String(
+            String::from_utf8(decode_bytes(data)?.to_vec()).map_err(Error::abi_decode)?,
+        ),
+
+        AbiType::Function => AbiValue::Function(read_word(data, 0)?[..24].try_into().unwrap()),
+
+        AbiType::Array(element) => {
+            let len = read_usize(data, 0)?;
+            let data = &data[WORD..];
+
+            // every element takes at least one word, this stops us from allocating absurd amounts of memory.
+            if len > data.len() / WORD {
+                return Err(out_of_bounds());
+            }
+
+            AbiValue::Array(decode_tuple(&vec![(**element).clone(); len], data)?)
+        }
+
+        AbiType::FixedArray(element, len) => {
+            if *len > data.len() / WORD {
+                return Err(out_of_bounds());
+            }
+
+            AbiValue::FixedArray(decode_tuple(&vec![(**element).clone(); *len], data)?)
+        }
+
+        AbiType::Tuple(types) => AbiValue::Tuple(decode_tuple(types, data)?),
+    };
+
+    Ok(value)
+}
+
+fn decode_bytes(data: &[u8]) -> crate::Result<&[u8]> {
+    let len = read_usize(data, 0)?;
+
+    data.get(WORD..).and_then(|it| it.get(..len)).ok_or_else(out_of_bounds)
+}
+
+fn read_word(data: &[u8], offset: usize) -> crate::Result<&[u8; WORD]> {
+    data.get(offset..)
+        .and_then(|it| it.get(..WORD))
+        .map(|it| it.try_into().unwrap())
+        .ok_or_else(out_of_bounds)
+}
+
+fn read_usize(data: &[u8], offset: usize) -> crate::Result<usize> {
+    let word = read_word(data, offset)?;
+    let (high, low) = word.split_at(WORD - 8);
+
+    if high.iter().any(|it| *it != 0) {
+        return Err(Error::abi_decode("offset or length too large"));
+    }
+
+    usize::try_from(u64::from_be_bytes(low.try_into().unwrap()))
+        .map_err(|_| Error::abi_decode("offset or length too large"))
+}
+
+fn out_of_bounds() -> Error {
+    Error::abi_decode("data too short")
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+    use hex_literal::hex;
+    use num_bigint::BigInt;
+
+    use super::{
+        decode,
+        encode,
+    };
+    use crate::{
+        AbiType,
+        AbiValue,
+        Error,
+        EvmAddress,
+    };
+
+    #[test]
+    fn static_values() -> crate::Result<()> {
+        let types = [AbiType::Address, AbiType::Bool, AbiType::Int(8), AbiType::FixedBytes(3)];
+        let values = [
+            AbiValue::Address(EvmAddress::from([0x11; 20])),
+            AbiValue::Bool(true),
+            AbiValue::from(-2_i8),
+            AbiValue::from(*b"abc"),
+        ];
+
+        let bytes = encode(&types, &values)?;
+
+        assert_eq!(
+            bytes,
+            hex!(
+                "0000000000000000000000001111111111111111111111111111111111111111"
+                "0000000000000000000000000000000000000000000000000000000000000001"
+                "fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffe"
+                "6162630000000000000000000000000000000000000000000000000000000000"
+            )
+        );
+
+        assert_eq!(decode(&types, &bytes)?, values);
+
+        Ok(())
+    }
+
+    #[test]
+    fn nested_dynamic_arrays() -> crate::Result<()> {
+        let types = [
+            AbiType::Array(Box::new(AbiType::Array(Box::new(AbiType::Uint(256))))),
+            AbiType::Array(Box::new(AbiType::String)),
+        ];
+        let values = [
+            AbiValue::Array(vec![
+                AbiValue::Array(vec![1_u8.into(), 2_u8.into()]),
+                AbiValue::Array(vec![3_u8.into()]),
+            ]),
+            AbiValue::Array(vec!["one".into(), "two".into(), "three".into()]),
+        ];
+
+        let bytes = encode(&types, &values)?;
+
+        // the example from the solidity ABI specification.
+        assert_eq!(
+            bytes,
+            hex!(
+                "0000000000000000000000000000000000000000000000000000000000000040"
+                "0000000000000000000000000000000000000000000000000000000000000140"
+                "0000000000000000000000000000000000000000000000000000000000000002"
+                "0000000000000000000000000000000000000000000000000000000000000040"
+                "00000000000000000000000000000000000000000000000000000000000000a0"
+                "0000000000000000000000000000000000000000000000000000000000000002"
+                "0000000000000000000000000000000000000000000000000000000000000001"
+                "0000000000000000000000000000000000000000000000000000000000000002"
+                "0000000000000000000000000000000000000000000000000000000000000001"
+                "0000000000000000000000000000000000000000000000000000000000000003"
+                "0000000000000000000000000000000000000000000000000000000000000003"
+                "0000000000000000000000000000000000000000000000000000000000000060"
+                "00000000000000000000000000000000000000000000000000000000000000a0"
+                "00000000000000000000000000000000000000000000000000000000000000e0"
+                "0000000000000000000000000000000000000000000000000000000000000003"
+                "6f6e650000000000000000000000000000000000000000000000000000000000"
+                "0000000000000000000000000000000000000000000000000000000000000003"
+                "74776f0000000000000000000000000000000000000000000000000000000000"
+                "0000000000000000000000000000000000000000000000000000000000000005"
+                "7468726565000000000000000000000000000000000000000000000000000000"
+            )
+        );
+
+        assert_eq!(decode(&types, &bytes)?, values);
+
+        Ok(())
+    }
+
+    #[test]
+    fn tuples() -> crate::Result<()> {
+        let types = [
+            AbiType::Tuple(vec![AbiType::Uint(256), AbiType::Bool]),
+            AbiType::Tuple(vec![
+                AbiType::String,
+                AbiType::FixedArray(Box::new(AbiType::Uint(8)), 2),
+            ]),
+        ];
+        let values = [
+            AbiValue::Tuple(vec![1_u8.into(), true.into()]),
+            AbiValue::Tuple(vec![
+                "hi".into(),
+                AbiValue::FixedArray(vec![3_u8.into(), 4_u8.into()]),
+            ]),
+        ];
+
+        let bytes = encode(&types, &values)?;
+
+        assert_eq!(
+            bytes,
+            hex!(
+                // (1, true) is static, and so is encoded in place.
+                "0000000000000000000000000000000000000000000000000000000000000001"
+                "0000000000000000000000000000000000000000000000000000000000000001"
+                // ("hi", [3, 4]) is dynamic, so only its offset is in the head.
+                "0000000000000000000000000000000000000000000000000000000000000060"
+                "0000000000000000000000000000000000000000000000000000000000000060"
+                "0000000000000000000000000000000000000000000000000000000000000003"
+                "0000000000000000000000000000000000000000000000000000000000000004"
+                "0000000000000000000000000000000000000000000000000000000000000002"
+                "6869000000000000000000000000000000000000000000000000000000000000"
+            )
+        );
+
+        assert_eq!(decode(&types, &bytes)?, values);
+
+        Ok(())
+    }
+
+    #[test]
+    fn array_of_tuples() -> crate::Result<()> {
+        let types = [AbiType::Array(Box::new(AbiType::Tuple(vec![
+            AbiType::Address,
+            AbiType::Array(Box::new(AbiType::Bytes)),
+        ])))];
+        let values = [AbiValue::Array(vec![
+            AbiValue::Tuple(vec![
+                EvmAddress::from([1; 20]).into(),
+                AbiValue::Array(vec![b"a".as_slice().into(), b"bc".as_slice().into()]),
+            ]),
+            AbiValue::Tuple(vec![EvmAddress::from([2; 20]).into(), AbiValue::Array(Vec::new())]),
+        ])];
+
+        let bytes = encode(&types, &values)?;
+
+        assert_eq!(decode(&types, &bytes)?, values);
+
+        Ok(())
+    }
+
+    #[test]
+    fn fixed_array_length_mismatch() {
+        assert_matches!(
+            encode(
+                &[AbiType::FixedArray(Box::new(AbiType::Bool), 2)],
+                &[AbiValue::FixedArray(vec![true.into()])]
+            ),
+            Err(Error::AbiEncode(_))
+        );
+    }
+
+    #[test]
+    fn value_out_of_range() {
+        assert_matches!(encode(&[AbiType::Uint(8)], &[256_u16.into()]), Err(Error::AbiEncode(_)));
+        assert_matches!(
+            encode(&[AbiType::Int(8)], &[AbiValue::Int(BigInt::from(-129))]),
+            Err(Error::AbiEncode(_))
+        );
+        assert_matches!(
+            encode(&[AbiType::FixedBytes(4)], &[AbiValue::from(*b"abc")]),
+            Err(Error::AbiEncode(_))
+        );
+    }
+
+    #[test]
+    fn type_mismatch() {
+        assert_matches!(encode(&[AbiType::String], &[true.into()]), Err(Error::AbiEncode(_)));
+        assert_matches!(encode(&[AbiType::String], &[]), Err(Error::AbiEncode(_)));
+    }
+
+    #[test]
+    fn invalid_size() {
+        for ty in [AbiType::Int(0), AbiType::Uint(300), AbiType::Uint(12), AbiType::FixedBytes(40)]
+        {
+            let value = match ty {
+                AbiType::FixedBytes(len) => AbiValue::FixedBytes(vec![0; len]),
+                AbiType::Int(_) => AbiValue::Int(BigInt::from(0)),
+                _ => 0_u8.into(),
+            };
+
+            assert_matches!(encode(std::slice::from_ref(&ty), &[value]), Err(Error::AbiEncode(_)));
+            assert_matches!(decode(&[ty], &[0; 64]), Err(Error::AbiDecode(_)));
+        }
+    }
+
+    #[test]
+    fn decode_truncated() {
+        assert_matches!(decode(&[AbiType::Uint(256)], &[0; 31]), Err(Error::AbiDecode(_)));
+        assert_matches!(
+            decode(
+                &[AbiType::Bytes],
+                &hex!(
+                    "0000000000000000000000000000000000000000000000000000000000000020"
+                    "00000000000000000000000000000000000000000000000000000000000000ff"
+                )
+            ),
+            Err(Error::AbiDecode(_))
+        );
+    }
+
+    #[test]
+    fn type_too_large() -> crate::Result<()> {
+        let ty: AbiType = "uint256[1000000000000][1000000000000]".parse()?;
+
+        assert_eq!(ty.head_len(), None);
+        assert_matches!(
+            encode(std::slice::from_ref(&ty), &[AbiValue::FixedArray(Vec::new())]),
+            Err(Error::AbiEncode(_))
+        );
+        assert_matches!(decode(&[ty], &[0; 64]), Err(Error::AbiDecode(_)));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,829 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+mod abi_bytes;
+mod abi_decode;
+mod abi_encode;
+mod abi_error;
+mod abi_event;
+mod abi_function;
+mod abi_type;
+mod abi_value;
+mod abi_values;
+mod abigen;
+pub(crate) mod codec;
+
+use std::str::FromStr;
+
+pub use abi_bytes::{
+    AbiBytes,
+    AbiFixedBytes,
+};
+pub use abi_decode::AbiDecode;
+#[doc(hidden)]
+pub use abi_decode::{
+    mismatch,
+    tuple_elements,
+};
+pub use abi_encode::AbiEncode;
+pub use abi_error::AbiError;
+pub use abi_event::{
+    AbiEvent,
+    AbiEventLog,
+    AbiEventParam,
+};
+pub use abi_function::{
+    AbiConstructor,
+    AbiFunction,
+    AbiParam,
+    StateMutability,
+};
+pub use abi_type::AbiType;
+pub use abi_value::AbiValue;
+pub use abi_values::AbiValues;
+#[doc(hidden)]
+pub use abigen::abigen_function;
+
+use crate::{
+    ContractLogInfo,
+    Error,
+};
+
+/// A Solidity contract ABI, as produced by `solc --abi` (or tools like Hardhat and Foundry).
+///
+/// Use it to encode calls to, and decode results from, a contract by function name.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Abi {
+    constructor: Option<AbiConstructor>,
+    functions: Vec<AbiFunction>,
+    events: Vec<AbiEvent>,
+    errors: Vec<AbiError>,
+}
+
+impl Abi {
+    /// Parse an ABI from its JSON representation.
+    ///
+    /// Both a bare ABI array and a compiler artifact with an `abi` field are accepted.
+    ///
+    /// # Errors
+    /// - [`Error::AbiParse`] if `json` isn't a valid ABI.
+    pub fn from_json(json: &str) -> crate::Result<Self> {
+        let items = match serde_json::from_str(json).map_err(Error::abi_parse)? {
+            json::Document::Abi(items) | json::Document::Artifact { abi: items } => items,
+        };
+
+        let mut abi = Self::default();
+
+        for item in items {
+            match item {
+                json::Item::Function { name, inputs, outputs, state_mutability } => {
+                    abi.functions.push(AbiFunction {
+                        name,
+                        inputs: json::params(inputs)?,
+                        outputs: json::params(outputs)?,
+                        state_mutability,
+                    });
+                }
+
+                json::Item::Constructor { inputs, state_mutability } => {
+                    abi.constructor =
+                        Some(AbiConstructor { inputs: json::params(inputs)?, state_mutability });
+                }
+
+                json::Item::Event { name, inputs, anonymous } => {
+                    abi.events.push(AbiEvent {
+                        name,
+                        inputs: json::event_params(inputs)?,
+                        anonymous,
+                    });
+                }
+
+                json::Item::Error { name, inputs } => {
+                    abi.errors.push(AbiError { name, inputs: json::params(inputs)? });
+                }
+
+                json::Item::Other => {}
+            }
+        }
+
+        Ok(abi)
+    }
+
+    /// Returns the constructor of the contract, if it declares one.
+    #[must_use]
+    pub fn constructor(&self) -> Option<&AbiConstructor> {
+        self.constructor.as_ref()
+    }
+
+    /// Returns all the functions of the contract, in declaration order.
+    #[must_use]
+    pub fn functions(&self) -> &[AbiFunction] {
+        &self.functions
+    }
+
+    /// Returns the function identified by `name`.
+    ///
+    /// `name` is either the plain name of the function (`transfer`),
+    /// or, to pick between overloads, its signature (`transfer(address,uint256)`).
+    ///
+    /// # Errors
+    /// - [`Error::AbiItemNotFound`] if there is no such function, or `name` matches more than one overload.
+    pub fn function(&self, name: &str) -> crate::Result<&AbiFunction> {
+        find_item("function", &self.functions, name, |it| it.name.as_str(), AbiFunction::signature)
+    }
+
+    /// Returns all the events of the contract, in declaration order.
+    #[must_use]
+    pub fn events(&self) -> &[AbiEvent] {
+        &self.events
+    }
+
+    /// Returns the event identified by `name`.
+    ///
+    /// `name` is either the plain name of the event (`Transfer`),
+    /// or, to pick between overloads, its signature (`Transfer(address,address,uint256)`).
+    ///
+    /// # Errors
+    /// - [`Error::AbiItemNotFound`] if there is no such event, or `name` matches more than one overload.
+    pub fn event(&self, name: &str) -> crate::Result<&AbiEvent> {
+        find_item("event", &self.events, name, |it| it.name.as_str(), AbiEvent::signature)
+    }
+
+    /// Returns all the custom errors of the contract, in declaration order.
+    #[must_use]
+    pub fn errors(&self) -> &[AbiError] {
+        &self.errors
+    }
+
+    /// Returns the custom error identified by `name`.
+    ///
+    /// `name` is either the plain name of the error (`InsufficientBalance`),
+    /// or, to pick between overloads, its signature (`InsufficientBalance(uint256,uint256)`).
+    ///
+    /// # Errors
+    /// - [`Error::AbiItemNotFound`] if there is no such error, or `name` matches more than one overload.
+    pub fn error(&self, name: &str) -> crate::Result<&AbiError> {
+        find_item("error", &self.errors, name, |it| it.name.as_str(), AbiError::signature)
+    }
+
+    /// Decode call `data` with the function whose selector matches the first 4 bytes of `data`.
+    ///
+    /// Returns `None` if no function in this ABI matches.
+    ///
+    /// # Errors
+    /// - [`Error::AbiDecode`] if `data` matches a function, but isn't a valid encoding of its inputs.
+    pub fn decode_call(&self, data: &[u8]) -> crate::Result<Option<(&AbiFunction, AbiValues)>> {
+        let selector = match data.get(..4) {
+            Some(selector) => selector,
+            None => return Ok(None),
+        };
+
+        let function = match self.functions.iter().find(|it| it.selector() == selector) {
+            Some(function) => function,
+            None => return Ok(None),
+        };
+
+        Ok(Some((function, function.decode_input(data)?)))
+    }
+
+    /// Decode `log` with the event whose topic matches the log's first topic,
+    /// and that has an indexed parameter for each of the log's other topics.
+    ///
+    /// Events with the same signature can differ in which parameters are indexed
+    /// (like the ERC-20 and ERC-721 `Transfer` events), so the topic alone isn't enough to pick one.
+    ///
+    /// Returns `None` if no (non-anonymous) event in this ABI matches,
+    /// for example, because the log was emitted by another contract.
+    ///
+    /// # Errors
+    /// - [`Error::AbiDecode`] if the log matches an event, but isn't a valid encoding of it.
+    pub fn decode_log(&self, log: &ContractLogInfo) -> crate::Result<Option<AbiEventLog>> {
+        let topic = match log.topics.first() {
+            Some(topic) => topic,
+            None => return Ok(None),
+        };
+
+        let indexed_count = log.topics.len() - 1;
+
+        let event = match self.events.iter().find(|it| {
+            !it.anonymous
+                && it.topic().as_slice() == topic.as_slice()
+                && it.inputs.iter().filter(|it| it.indexed).count() == indexed_count
+        }) {
+            Some(event) => event,
+            None => return Ok(None),
+        };
+
+        Ok(Some(AbiEventLog {
+            contract_id: log.contract_id,
+            name: event.name.clone(),
+            values: event.decode_log(log)?,
+        }))
+    }
+
+    /// Decode every log in `logs` that matches an event in this ABI, skipping the rest.
+    ///
+    /// See [`decode_log`](Self::decode_log).
+    ///
+    /// # Errors
+    /// - [`Error::AbiDecode`] if a log matches an event, but isn't a valid encoding of it.
+    pub fn decode_logs(&self, logs: &[ContractLogInfo]) -> crate::Result<Vec<AbiEventLog>> {
+        logs.iter().filter_map(|it| self.decode_log(it).transpose()).collect()
+    }
+
+    /// Encode a call to the function identified by `name` (see [`function`](Self::function)) with `args`.
+    ///
+    /// # Errors
+    /// - [`Error::AbiItemNotFound`] if the function can't be found.
+    /// - [`Error::AbiEncode`] if `args` don't match the function's inputs.
+    pub fn encode_call(&self, name: &str, args: &[AbiValue]) -> crate::Result<Vec<u8>> {
+        self.function(name)?.encode_input(args)
+    }
+
+    /// Decode the values returned by the function identified by `name` (see [`function`](Self::function)).
+    ///
+    /// # Errors
+    /// - [`Error::AbiItemNotFound`] if the function can't be found.
+    /// - [`Error::AbiDecode`] if `data` isn't a valid encoding of the function's outputs.
+    pub fn decode_output(&self, name: &str, data: &[u8]) -> crate::Result<AbiValues> {
+        self.function(name)?.decode_output(data)
+    }
+
+    /// Encode the arguments to the contract's constructor.
+    ///
+    /// A contract without an explicit constructor takes no arguments.
+    ///
+    /// # Errors
+    /// - [`Error::AbiEncode`] if `args` don't match the constructor's inputs.
+    pub fn encode_constructor(&self, args: &[AbiValue]) -> crate::Result<Vec<u8>> {
+        match &self.constructor {
+            Some(constructor) => constructor.encode_input(args),
+            None => AbiConstructor::default().encode_input(args),
+        }
+    }
+}
+
+/// Finds the item identified by `name`, either a plain name that must be unique, or a full signature.
+fn find_item<'a, T>(
+    kind: &'static str,
+    items: &'a [T],
+    name: &str,
+    item_name: impl Fn(&T) -> &str,
+    signature: impl Fn(&T) -> String,
+) -> crate::Result<&'a T> {
+    let not_found = || Error::AbiItemNotFound { kind, name: name.to_owned() };
+
+    if name.contains('(') {
+        return items.iter().find(|it| signature(it) == name).ok_or_else(not_found);
+    }
+
+    let mut matches = items.iter().filter(|it| item_name(it) == name);
+
+    match (matches.next(), matches.next()) {
+        (Some(item), None) => Ok(item),
+        _ => Err(not_found()),
+    }
+}
+
+impl FromStr for Abi {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_json(s)
+    }
+}
+
+/// The JSON representation of an ABI.
+mod json {
+    use super::{
+        AbiEventParam,
+        AbiParam,
+        AbiType,
+        StateMutability,
+    };
+
+    #[derive(serde::Deserialize)]
+    #[serde(untagged)]
+    pub(super) enum Document {
+        Abi(Vec<Item>),
+        Artifact { abi: Vec<Item> },
+    }
+
+    #[derive(serde::Deserialize)]
+    #[serde(tag = "type", rename_all = "lowercase")]
+    pub(super) enum Item {
+        #[serde(rename_all = "camelCase")]
+        Function {
+            name: String,
+            #[serde(default)]
+            inputs: Vec<Param>,
+            #[serde(default)]
+            outputs: Vec<Param>,
+            #[serde(default)]
+            state_mutability: StateMutability,
+        },
+
+        #[serde(rename_all = "camelCase")]
+        Constructor {
+            #[serde(default)]
+            inputs: Vec<Param>,
+            #[serde(default)]
+            state_mutability: StateMutability,
+        },
+
+        Event {
+            name: String,
+            #[serde(default)]
+            inputs: Vec<Param>,
+            #[serde(default)]
+            anonymous: bool,
+        },
+
+        Error {
+            name: String,
+            #[serde(default)]
+            inputs: Vec<Param>,
+        },
+
+        #[serde(other)]
+        Other,
+    }
+
+    #[derive(serde::Deserialize)]
+    pub(super) struct Param {
+        #[serde(default)]
+        name: String,
+        #[serde(rename = "type")]
+        ty: String,
+        #[serde(default)]
+        indexed: bool,
+        #[serde(default)]
+        components: Vec<Param>,
+    }
+
+    impl Param {
+        fn ty(&self) -> crate::Result<AbiType> {
+            // `tuple`, `tuple[]`, `tuple[2][]`, etc, where the tuple's elements are given by `components`.
+            match self.ty.strip_prefix("tuple") {
+                Some(suffix) => {
+                    let components =
+                        self.components.iter().map(Self::ty).collect::<crate::Result<_>>()?;
+
+                    format!("{}{suffix}", AbiType::Tuple(components)).parse()
+                }
+
+                None => self.ty.parse(),
+            }
+        }
+    }
+
+    pub(super) fn params(params: Vec<Param>) -> crate::Result<Vec<AbiParam>> {
+        params.into_iter().map(|it| Ok(AbiParam { ty: it.ty()?, name: it.name })).collect()
+    }
+
+    pub(super) fn event_params(params: Vec<Param>) -> crate::Result<Vec<AbiEventParam>> {
+        params
+            .into_iter()
+            .map(|it| Ok(AbiEventParam { ty: it.ty()?, name: it.name, indexed: it.indexed }))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+    use hex_literal::hex;
+    use num_bigint::BigUint;
+
+    use crate::{
+        Abi,
+        AbiValue,
+        ContractId,
+        ContractLogInfo,
+        Error,
+        EvmAddress,
+        StateMutability,
+    };
+
+    const ABI: &str = r#"[
+        {
+            "type": "constructor",
+            "inputs": [{ "name": "supply", "type": "uint256", "internalType": "uint256" }],
+            "stateMutability": "nonpayable"
+        },
+        {
+            "type": "function",
+            "name": "transfer",
+            "inputs": [
+                { "name": "to", "type": "address", "internalType": "address" },
+                { "name": "amount", "type": "uint256", "internalType": "uint256" }
+            ],
+            "outputs": [{ "name": "", "type": "bool", "internalType": "bool" }],
+            "stateMutability": "nonpayable"
+        },
+        {
+            "type": "function",
+            "name": "f",
+            "inputs": [
+                { "name": "", "type": "uint" },
+                { "name": "", "type": "uint32[]" },
+                { "name": "", "type": "bytes10" },
+                { "name": "", "type": "bytes" }
+            ],
+            "outputs": [
+                { "name": "name", "type": "string" },
+                { "name": "count", "type": "uint64" }
+            ],
+            "stateMutability": "view"
+        },
+        {
+            "type": "event",
+            "name": "Transfer",
+            "inputs": [
+                { "name": "from", "type": "address", "indexed": true },
+                { "name": "to", "type": "address", "indexed": true },
+                { "name": "value", "type": "uint256", "indexed": false }
+            ],
+            "anonymous": false
+        },
+        {
+            "type": "event",
+            "name": "Named",
+            "inputs": [
+                { "name": "id", "type": "uint8", "indexed": false },
+                { "name": "name", "type": "string", "indexed": true },
+                { "name": "note", "type": "string", "indexed": false }
+            ],
+            "anonymous": false
+        }
+    ]"#;
+
+    #[test]
+    fn from_json() -> crate::Result<()> {
+        let abi = Abi::from_json(ABI)?;
+
+        assert_eq!(abi.functions().len(), 2);
+        assert_eq!(abi.constructor().unwrap().inputs[0].name, "supply");
+
+        let transfer = abi.function("transfer")?;
+        assert_eq!(transfer.signature(), "transfer(address,uint256)");
+        assert_eq!(transfer.selector(), hex!("a9059cbb"));
+        assert_eq!(transfer.state_mutability, StateMutability::NonPayable);
+
+        assert_eq!(abi.function("f(uint256,uint32[],bytes10,bytes)")?.name, "f");
+        assert_matches!(abi.function("g"), Err(Error::AbiItemNotFound { .. }));
+
+        Ok(())
+    }
+
+    #[test]
+    fn from_artifact() -> crate::Result<()> {
+        let abi = Abi::from_json(&format!(r#"{{ "contractName": "Token", "abi": {ABI} }}"#))?;
+
+        assert_eq!(abi.functions().len(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn tuple_components() -> crate::Result<()> {
+        let abi = Abi::from_json(
+            r#"[{
+                "type": "function",
+                "name": "submit",
+                "inputs": [{
+                    "name": "orders",
+                    "type": "tuple[]",
+                    "components": [
+                        { "name": "id", "type": "uint64" },
+                        {
+                            "name": "legs",
+                            "type": "tuple[2]",
+                            "components": [
+                                { "name": "token", "type": "address" },
+                                { "name": "amount", "type": "int256" }
+                            ]
+                        }
+                    ]
+                }],
+                "outputs": []
+            }]"#,
+        )?;
+
+        assert_eq!(abi.function("submit")?.signature(), "submit((uint64,(address,int256)[2])[])");
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_type_fails() {
+        assert_matches!(
+            Abi::from_json(
+                r#"[{ "type": "function", "name": "f", "inputs": [{ "type": "uint7" }] }]"#
+            ),
+            Err(Error::AbiParse(_))
+        );
+    }
+
+    #[test]
+    fn encode_call() -> crate::Result<()> {
+        let abi = Abi::from_json(ABI)?;
+
+        let data = abi.encode_call(
+            "transfer",
+            &[EvmAddress::from([0x35; 20]).into(), BigUint::from(10_u8).pow(18).into()],
+        )?;
+
+        assert_eq!(
+            data,
+            hex!(
+                "a9059cbb"
+                "0000000000000000000000003535353535353535353535353535353535353535"
+                "0000000000000000000000000000000000000000000000000de0b6b3a7640000"
+            )
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn decode_call() -> crate::Result<()> {
+        let abi = Abi::from_json(ABI)?;
+
+        let args: [AbiValue; 2] =
+            [EvmAddress::from([0x35; 20]).into(), BigUint::from(10_u8).pow(18).into()];
+
+        let data = abi.encode_call("transfer", &args)?;
+
+        let (function, values) = abi.decode_call(&data)?.unwrap();
+
+        assert_eq!(function.name, "transfer");
+        assert_eq!(values["to"], args[0]);
+        assert_eq!(values["amount"], args[1]);
+
+        assert!(abi.decode_call(&hex!("deadbeef"))?.is_none());
+        assert_matches!(abi.decode_call(&data[..20]), Err(Error::AbiDecode(_)));
+
+        Ok(())
+    }
+
+    // the example from the solidity ABI specification.
+    #[test]
+    fn encode_call_dynamic() -> crate::Result<()> {
+        let abi = Abi::from_json(ABI)?;
+
+        let data = abi.encode_call(
+            "f",
+            &[
+                0x123_u16.into(),
+                AbiValue::Array(vec![0x456_u32.into(), 0x789_u32.into()]),
+                (*b"1234567890").into(),
+                b"Hello, world!".as_slice().into(),
+            ],
+        )?;
+
+        assert_eq!(
+            data,
+            hex!(
+                "8be65246"
+                "0000000000000000000000000000000000000000000000000000000000000123"
+                "0000000000000000000000000000000000000000000000000000000000000080"
+                "3132333435363738393000000000000000000000000000000000000000000000"
+                "00000000000000000000000000000000000000000000000000000000000000e0"
+                "0000000000000000000000000000000000000000000000000000000000000002"
+                "0000000000000000000000000000000000000000000000000000000000000456"
+                "0000000000000000000000000000000000000000000000000000000000000789"
+                "000000000000000000000000000000000000000000000000000000000000000d"
+                "48656c6c6f2c20776f726c642100000000000000000000000000000000000000"
+            )
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn decode_output() -> crate::Result<()> {
+        let abi = Abi::from_json(ABI)?;
+
+        let outputs = abi.decode_output(
+            "f",
+            &hex!(
+                "0000000000000000000000000000000000000000000000000000000000000040"
+                "0000000000000000000000000000000000000000000000000000000000000007"
+                "000000000000000000000000000000000000000000000000000000000000000d"
+                "48656c6c6f2c20776f726c642100000000000000000000000000000000000000"
+            ),
+        )?;
+
+        assert_eq!(outputs.len(), 2);
+        assert_eq!(outputs["name"].as_str(), Some("Hello, world!"));
+        assert_eq!(outputs["count"], AbiValue::from(7_u64));
+        assert_eq!(outputs[1], AbiValue::from(7_u64));
+
+        Ok(())
+    }
+
+    #[test]
+    fn encode_constructor() -> crate::Result<()> {
+        let abi = Abi::from_json(ABI)?;
+
+        assert_eq!(
+            abi.encode_constructor(&[1_u8.into()])?,
+            hex!("0000000000000000000000000000000000000000000000000000000000000001")
+        );
+        assert_matches!(abi.encode_constructor(&[]), Err(Error::AbiEncode(_)));
+
+        Ok(())
+    }
+
+    fn transfer_log() -> ContractLogInfo {
+        ContractLogInfo {
+            contract_id: ContractId::new(0, 0, 1001),
+            bloom: Vec::new(),
+            topics: vec![
+                hex!("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef").to_vec(),
+                hex!("0000000000000000000000001111111111111111111111111111111111111111").to_vec(),
+                hex!("0000000000000000000000002222222222222222222222222222222222222222").to_vec(),
+            ],
+            data: hex!("00000000000000000000000000000000000000000000000000000000000003e8").to_vec(),
+        }
+    }
+
+    #[test]
+    fn event_topic() -> crate::Result<()> {
+        let abi = Abi::from_json(ABI)?;
+
+        let transfer = abi.event("Transfer")?;
+
+        assert_eq!(transfer.signature(), "Transfer(address,address,uint256)");
+        assert_eq!(
+            transfer.topic(),
+            hex!("ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef")
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn decode_log() -> crate::Result<()> {
+        let abi = Abi::from_json(ABI)?;
+
+        let log = abi.decode_log(&transfer_log())?.unwrap();
+
+        assert_eq!(log.contract_id, ContractId::new(0, 0, 1001));
+        assert_eq!(log.name, "Transfer");
+        assert_eq!(log.values["from"], AbiValue::from(EvmAddress::from([0x11; 20])));
+        assert_eq!(log.values["to"], AbiValue::from(EvmAddress::from([0x22; 20])));
+        assert_eq!(log.values["value"], AbiValue::from(1000_u16));
+
+        Ok(())
+    }
+
+    #[test]
+    fn decode_log_indexed_dynamic() -> crate::Result<()> {
+        let abi = Abi::from_json(ABI)?;
+        let event = abi.event("Named")?;
+
+        let name_hash = hex!("1c8aff950685c2ed4bc3174f3472287b56d9517b9c948127319a09a7a36deac8");
+
+        let log = ContractLogInfo {
+            contract_id: ContractId::new(0, 0, 1001),
+            bloom: Vec::new(),
+            topics: vec![event.topic().to_vec(), name_hash.to_vec()],
+            data: hex!(
+                "0000000000000000000000000000000000000000000000000000000000000005"
+                "0000000000000000000000000000000000000000000000000000000000000040"
+                "0000000000000000000000000000000000000000000000000000000000000002"
+                "6869000000000000000000000000000000000000000000000000000000000000"
+            )
+            .to_vec(),
+        };
+
+        let values = abi.decode_log(&log)?.unwrap().values;
+
+        assert_eq!(values.iter().map(|(name, _)| name).collect::<Vec<_>>(), ["id", "name", "note"]);
+        assert_eq!(values["id"], AbiValue::from(5_u8));
+        assert_eq!(values["name"], AbiValue::FixedBytes(name_hash.to_vec()));
+        assert_eq!(values["note"], AbiValue::from("hi"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn decode_log_unknown_event() -> crate::Result<()> {
+        let abi = Abi::from_json(ABI)?;
+
+        let mut log = transfer_log();
+        log.topics[0] = vec![0; 32];
+
+        assert_eq!(abi.decode_log(&log)?, None);
+        assert_eq!(abi.decode_logs(&[log, transfer_log()])?.len(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn decode_log_missing_topic() -> crate::Result<()> {
+        let abi = Abi::from_json(ABI)?;
+
+        let mut log = transfer_log();
+        log.topics.pop();
+
+        assert_eq!(abi.decode_log(&log)?, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn decode_log_indexed_count() -> crate::Result<()> {
+        // ERC-721's `Transfer` has the same signature (and so topic) as ERC-20's, but indexes `tokenId`.
+        const ERC_721_TRANSFER: &str = r#"{
+            "type": "event",
+            "name": "Transfer",
+            "inputs": [
+                { "name": "from", "type": "address", "indexed": true },
+                { "name": "to", "type": "address", "indexed": true },
+                { "name": "tokenId", "type": "uint256", "indexed": true }
+            ]
+        }"#;
+
+        let erc_20_log = transfer_log();
+
+        let mut erc_721_log = transfer_log();
+        erc_721_log.topics.push(erc_721_log.data.clone());
+        erc_721_log.data.clear();
+
+        let erc_20 = Abi::from_json(ABI)?;
+        let erc_721 = Abi::from_json(&format!("[{ERC_721_TRANSFER}]"))?;
+
+        assert_eq!(erc_20.event("Transfer")?.topic(), erc_721.event("Transfer")?.topic());
+
+        assert_eq!(erc_20.decode_log(&erc_721_log)?, None);
+        assert_eq!(erc_721.decode_log(&erc_20_log)?, None);
+
+        let logs = erc_20.decode_logs(&[erc_721_log.clone(), erc_20_log.clone()])?;
+        assert_eq!(logs.len(), 1);
+        assert_eq!(logs[0].values["value"], AbiValue::from(1000_u16));
+
+        let logs = erc_721.decode_logs(&[erc_721_log, erc_20_log])?;
+        assert_eq!(logs.len(), 1);
+        assert_eq!(logs[0].values["tokenId"], AbiValue::from(1000_u16));
+
+        Ok(())
+    }
+
+    #[test]
+    fn decode_log_indexed_static_reference_types() -> crate::Result<()> {
+        let abi = Abi::from_json(
+            r#"[{
+                "type": "event",
+                "name": "Moved",
+                "inputs": [
+                    { "name": "path", "type": "uint8[2]", "indexed": true },
+                    {
+                        "name": "point",
+                        "type": "tuple",
+                        "indexed": true,
+                        "components": [{ "name": "x", "type": "uint8" }, { "name": "y", "type": "uint8" }]
+                    }
+                ]
+            }]"#,
+        )?;
+
+        let event = abi.event("Moved")?;
+
+        let path_hash = [0x11; 32];
+        let point_hash = [0x22; 32];
+
+        let log = ContractLogInfo {
+            contract_id: ContractId::new(0, 0, 1001),
+            bloom: Vec::new(),
+            topics: vec![event.topic().to_vec(), path_hash.to_vec(), point_hash.to_vec()],
+            data: Vec::new(),
+        };
+
+        let values = abi.decode_log(&log)?.unwrap().values;
+
+        assert_eq!(values["path"], AbiValue::FixedBytes(path_hash.to_vec()));
+        assert_eq!(values["point"], AbiValue::FixedBytes(point_hash.to_vec()));
+
+        Ok(())
+    }
+}
@@ -29,6 +29,7 @@ use crate::protobuf::{
 };
 use crate::staked_id::StakedId;
 use crate::transaction::{
+    validate,
     AnyTransactionData,
     ChunkInfo,
     ToSchedulableTransactionDataProtobuf,
@@ -287,7 +288,19 @@ impl AccountCreateTransaction {
     }
 }
 
-impl TransactionData for AccountCreateTransactionData {}
+impl TransactionData for AccountCreateTransactionData {
+    fn validate(&self) -> crate::Result<()> {
+        // accounts created with an alias don't need a key.
+        if self.alias.is_none() {
+            validate::require("key", &self.key)?;
+        }
+
+        validate::optional_key("key", &self.key)?;
+        validate::memo("account_memo", &self.account_memo)?;
+
+        Ok(())
+    }
+}
 
 impl TransactionExecute for AccountCreateTransactionData {
     fn execute(
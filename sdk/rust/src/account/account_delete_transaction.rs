@@ -27,6 +27,7 @@ use crate::protobuf::{
     ToProtobuf,
 };
 use crate::transaction::{
+    validate,
     AnyTransactionData,
     ChunkInfo,
     ToSchedulableTransactionDataProtobuf,
@@ -88,7 +89,14 @@ impl AccountDeleteTransaction {
     }
 }
 
-impl TransactionData for AccountDeleteTransactionData {}
+impl TransactionData for AccountDeleteTransactionData {
+    fn validate(&self) -> crate::Result<()> {
+        validate::require("account_id", &self.account_id)?;
+        validate::require("transfer_account_id", &self.transfer_account_id)?;
+
+        Ok(())
+    }
+}
 
 impl TransactionExecute for AccountDeleteTransactionData {
     fn execute(
@@ -0,0 +1,243 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::borrow::Cow;
+use std::future::Future;
+
+use crate::transaction::TransactionSources;
+use crate::{
+    AccountId,
+    AccountInfoQuery,
+    Client,
+    Error,
+    Key,
+    KeySignatureReport,
+    Transaction,
+};
+
+/// Verify signatures against the current key of an account on the network.
+///
+/// The key is fetched with an [`AccountInfoQuery`] every time a signature is verified,
+/// so key rotations are picked up.
+#[derive(Debug, Default, Clone)]
+pub struct AccountInfoFlow {
+    account_id: Option<AccountId>,
+}
+
+impl AccountInfoFlow {
+    /// Create a new `AccountInfoFlow`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the account whose key signatures are verified against.
+    #[must_use]
+    pub fn get_account_id(&self) -> Option<AccountId> {
+        self.account_id
+    }
+
+    /// Sets the account whose key signatures are verified against.
+    pub fn account_id(&mut self, id: AccountId) -> &mut Self {
+        self.account_id = Some(id);
+        self
+    }
+
+    /// Verify a `signature` on a `msg` with the current key of the account.
+    ///
+    /// If the account's key is a key list, the signature must satisfy it on its own,
+    /// for instance, a threshold key with a threshold of `1`.
+    ///
+    /// # Errors
+    /// - [`Error::SignatureVerify`] if the signature doesn't satisfy the account's key.
+    /// - Any error returned by executing the [`AccountInfoQuery`].
+    pub async fn verify_signature(
+        &self,
+        client: &Client,
+        msg: &[u8],
+        signature: &[u8],
+    ) -> crate::Result<()> {
+        let key = self.query_key(client).await?;
+
+        verify_signature_with_key(&key, msg, signature)
+    }
+
+    /// Verify that the signatures on `transaction` satisfy the current key of the account.
+    ///
+    /// Signatures present in the signed transaction bytes are counted,
+    /// as are the signers that haven't been applied yet (including the operator) once the transaction is frozen,
+    /// see [`Key::is_satisfied_by`] for details.
+    ///
+    /// # Errors
+    /// - [`Error::SignatureVerify`] if the transaction's signatures don't satisfy the account's key.
+    /// - Any error returned by executing the [`AccountInfoQuery`].
+    pub fn verify_transaction_signature<'a, D: crate::transaction::TransactionExecute>(
+        &'a self,
+        client: &'a Client,
+        transaction: &Transaction<D>,
+    ) -> impl Future<Output = crate::Result<()>> + Send + 'a {
+        // `Transaction` holds boxed signers which aren't `Sync`,
+        // so copy out the signatures instead of holding a reference to it across the query.
+        let sources = transaction.signed_sources().map(Cow::into_owned);
+
+        async move {
+            let key = self.query_key(client).await?;
+
+            verify_sources_with_key(&key, sources.as_ref())
+        }
+    }
+
+    async fn query_key(&self, client: &Client) -> crate::Result<Key> {
+        let mut query = AccountInfoQuery::new();
+
+        if let Some(account_id) = self.account_id {
+            query.account_id(account_id);
+        }
+
+        Ok(query.execute(client).await?.key)
+    }
+}
+
+fn verify_signature_with_key(key: &Key, msg: &[u8], signature: &[u8]) -> crate::Result<()> {
+    match key {
+        Key::Single(key) => key.verify(msg, signature),
+        key => check_report(&KeySignatureReport::new(key, &|public_key| {
+            public_key.verify(msg, signature).is_ok()
+        })),
+    }
+}
+
+fn verify_sources_with_key(key: &Key, sources: Option<&TransactionSources>) -> crate::Result<()> {
+    let report = KeySignatureReport::new(key, &|public_key| {
+        sources.map_or(false, |it| it.is_signed_by(public_key))
+    });
+
+    check_report(&report)
+}
+
+fn check_report(report: &KeySignatureReport) -> crate::Result<()> {
+    match report.signatures_needed {
+        Some(0) => Ok(()),
+        Some(needed) => Err(Error::signature_verify(format!(
+            "signatures don't satisfy the account's key: {needed} more required"
+        ))),
+        None => Err(Error::signature_verify("the account's key can't be satisfied by signatures")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+
+    use super::{
+        verify_signature_with_key,
+        verify_sources_with_key,
+        AccountInfoFlow,
+    };
+    use crate::transaction::test_helpers::unsigned_transfer_transaction;
+    use crate::{
+        AnyTransaction,
+        Client,
+        Error,
+        Key,
+        KeyList,
+        PrivateKey,
+        TransferTransaction,
+    };
+
+    // compile time check that the flow can be used with `tokio::spawn`.
+    #[allow(dead_code)]
+    fn futures_are_send(flow: &AccountInfoFlow, client: &Client, tx: &TransferTransaction) {
+        fn assert_send<T: Send>(_: T) {}
+
+        assert_send(flow.verify_signature(client, &[], &[]));
+        assert_send(flow.verify_transaction_signature(client, tx));
+    }
+
+    #[test]
+    fn verify_signature_single_key() {
+        let key = PrivateKey::generate_ed25519();
+        let other = PrivateKey::generate_ed25519();
+        let signature = key.sign(b"hello");
+
+        assert_matches!(
+            verify_signature_with_key(&key.public_key().into(), b"hello", &signature),
+            Ok(())
+        );
+        assert_matches!(
+            verify_signature_with_key(&other.public_key().into(), b"hello", &signature),
+            Err(Error::SignatureVerify(_))
+        );
+    }
+
+    #[test]
+    fn verify_signature_threshold_key() {
+        let key = PrivateKey::generate_ecdsa();
+        let other = PrivateKey::generate_ed25519();
+        let signature = key.sign(b"hello");
+
+        let threshold_key = Key::KeyList(KeyList {
+            keys: vec![other.public_key().into(), key.public_key().into()],
+            threshold: Some(1),
+        });
+
+        assert_matches!(verify_signature_with_key(&threshold_key, b"hello", &signature), Ok(()));
+
+        let all_key =
+            Key::KeyList(KeyList::from(vec![other.public_key().into(), key.public_key().into()]));
+
+        assert_matches!(
+            verify_signature_with_key(&all_key, b"hello", &signature),
+            Err(Error::SignatureVerify(_))
+        );
+    }
+
+    #[test]
+    fn verify_transaction_signatures() {
+        let signer = PrivateKey::generate_ed25519();
+        let other = PrivateKey::generate_ed25519();
+
+        let mut tx = unsigned_transfer_transaction();
+
+        assert_matches!(
+            verify_sources_with_key(&signer.public_key().into(), tx.signed_sources().as_deref()),
+            Err(Error::SignatureVerify(_))
+        );
+
+        // signed locally, the signer hasn't been applied to the transaction bytes yet.
+        tx.sign(signer.clone());
+
+        assert_matches!(
+            verify_sources_with_key(&signer.public_key().into(), tx.signed_sources().as_deref()),
+            Ok(())
+        );
+
+        let tx = AnyTransaction::from_bytes(&tx.to_bytes().unwrap()).unwrap();
+
+        assert_matches!(
+            verify_sources_with_key(&signer.public_key().into(), tx.signed_sources().as_deref()),
+            Ok(())
+        );
+        assert_matches!(
+            verify_sources_with_key(&other.public_key().into(), tx.signed_sources().as_deref()),
+            Err(Error::SignatureVerify(_))
+        );
+    }
+}
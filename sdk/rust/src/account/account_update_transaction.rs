@@ -32,6 +32,7 @@ use crate::protobuf::{
 };
 use crate::staked_id::StakedId;
 use crate::transaction::{
+    validate,
     AnyTransactionData,
     ChunkInfo,
     ToSchedulableTransactionDataProtobuf,
@@ -286,7 +287,15 @@ impl AccountUpdateTransaction {
     }
 }
 
-impl TransactionData for AccountUpdateTransactionData {}
+impl TransactionData for AccountUpdateTransactionData {
+    fn validate(&self) -> crate::Result<()> {
+        validate::require("account_id", &self.account_id)?;
+        validate::optional_update_key("key", &self.key)?;
+        validate::memo("account_memo", self.account_memo.as_deref().unwrap_or_default())?;
+
+        Ok(())
+    }
+}
 
 impl TransactionExecute for AccountUpdateTransactionData {
     fn execute(
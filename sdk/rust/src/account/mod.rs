@@ -26,6 +26,7 @@ mod account_create_transaction;
 mod account_delete_transaction;
 mod account_id;
 mod account_info;
+mod account_info_flow;
 mod account_info_query;
 mod account_records_query;
 mod account_stakers_query;
@@ -45,6 +46,7 @@ pub use account_delete_transaction::AccountDeleteTransaction;
 pub(crate) use account_delete_transaction::AccountDeleteTransactionData;
 pub use account_id::AccountId;
 pub use account_info::AccountInfo;
+pub use account_info_flow::AccountInfoFlow;
 pub use account_info_query::AccountInfoQuery;
 pub(crate) use account_info_query::AccountInfoQueryData;
 pub use account_records_query::AccountRecordsQuery;
@@ -0,0 +1,106 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::fmt;
+use std::sync::Arc;
+
+use parking_lot::Mutex;
+use rand::{
+    thread_rng,
+    RngCore,
+};
+use time::OffsetDateTime;
+
+/// A source of the current time for a [`Client`](crate::Client).
+///
+/// The client uses its clock to pick the valid start of generated transaction IDs
+/// and to decide when an unhealthy node may be used again.
+pub trait Clock: Send + Sync {
+    /// Returns the current time, in UTC.
+    fn now_utc(&self) -> OffsetDateTime;
+}
+
+/// A [`Clock`] backed by the system time.
+///
+/// This is the default clock for a [`Client`](crate::Client).
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now_utc(&self) -> OffsetDateTime {
+        OffsetDateTime::now_utc()
+    }
+}
+
+impl<F> Clock for F
+where
+    F: Fn() -> OffsetDateTime + Send + Sync,
+{
+    fn now_utc(&self) -> OffsetDateTime {
+        self()
+    }
+}
+
+/// The clock and random number generator used by a client.
+///
+/// Cloning this is cheap, and clones share the same underlying generator,
+/// so that a seeded generator produces the same sequence no matter which clone draws from it.
+#[derive(Clone)]
+pub(crate) struct ClockAndRng {
+    clock: Arc<dyn Clock>,
+    // `None` means "use `thread_rng`".
+    rng: Option<Arc<Mutex<Box<dyn RngCore + Send>>>>,
+}
+
+impl ClockAndRng {
+    pub(crate) fn with_clock(&self, clock: Arc<dyn Clock>) -> Self {
+        Self { clock, rng: self.rng.clone() }
+    }
+
+    pub(crate) fn with_rng(&self, rng: Box<dyn RngCore + Send>) -> Self {
+        Self { clock: Arc::clone(&self.clock), rng: Some(Arc::new(Mutex::new(rng))) }
+    }
+
+    pub(crate) fn now_utc(&self) -> OffsetDateTime {
+        self.clock.now_utc()
+    }
+
+    /// Run `f` with exclusive access to the random number generator.
+    pub(crate) fn rng<T>(&self, f: impl FnOnce(&mut dyn RngCore) -> T) -> T {
+        match &self.rng {
+            Some(rng) => f(&mut **rng.lock()),
+            None => f(&mut thread_rng()),
+        }
+    }
+}
+
+impl Default for ClockAndRng {
+    fn default() -> Self {
+        Self { clock: Arc::new(SystemClock), rng: None }
+    }
+}
+
+impl fmt::Debug for ClockAndRng {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ClockAndRng")
+            .field("custom_rng", &self.rng.is_some())
+            .finish_non_exhaustive()
+    }
+}
@@ -28,8 +28,14 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use arc_swap::ArcSwapOption;
+pub(crate) use clock::ClockAndRng;
+pub use clock::{
+    Clock,
+    SystemClock,
+};
 pub(crate) use operator::Operator;
-use rand::thread_rng;
+use parking_lot::RwLock;
+use rand::RngCore;
 
 use self::mirror_network::MirrorNetwork;
 use crate::client::network::Network;
@@ -40,6 +46,7 @@ use crate::{
     TransactionId,
 };
 
+mod clock;
 mod mirror_network;
 mod network;
 mod operator;
@@ -51,6 +58,7 @@ struct ClientInner {
     max_transaction_fee_tinybar: AtomicU64,
     ledger_id: ArcSwapOption<LedgerId>,
     auto_validate_checksums: AtomicBool,
+    clock_and_rng: RwLock<ClockAndRng>,
 }
 
 /// Managed client for use on the Hedera network.
@@ -70,6 +78,7 @@ impl Client {
             max_transaction_fee_tinybar: AtomicU64::new(0),
             ledger_id: ArcSwapOption::new(ledger_id.into().map(Arc::new)),
             auto_validate_checksums: AtomicBool::new(false),
+            clock_and_rng: RwLock::new(ClockAndRng::default()),
         }))
     }
 
@@ -116,13 +125,35 @@ impl Client {
         self.0.ledger_id.store(ledger_id.map(Arc::new))
     }
 
+    /// Sets the clock used for generating transaction IDs and tracking node health.
+    ///
+    /// Defaults to [`SystemClock`].
+    pub fn set_clock(&self, clock: impl Clock + 'static) {
+        let mut clock_and_rng = self.0.clock_and_rng.write();
+        *clock_and_rng = clock_and_rng.with_clock(Arc::new(clock));
+    }
+
+    /// Sets the random number generator used for generating transaction IDs and selecting nodes.
+    ///
+    /// Defaults to [`rand::thread_rng`]; a seeded generator makes both deterministic.
+    pub fn set_rng(&self, rng: impl RngCore + Send + 'static) {
+        let mut clock_and_rng = self.0.clock_and_rng.write();
+        *clock_and_rng = clock_and_rng.with_rng(Box::new(rng));
+    }
+
+    pub(crate) fn clock_and_rng(&self) -> ClockAndRng {
+        self.0.clock_and_rng.read().clone()
+    }
+
     pub(crate) fn random_node_ids(&self) -> Vec<AccountId> {
-        let node_ids: Vec<_> = self.network().healthy_node_ids().collect();
+        let clock_and_rng = self.clock_and_rng();
+
+        let node_ids: Vec<_> = self.network().healthy_node_ids(clock_and_rng.now_utc()).collect();
 
         let node_sample_amount = (node_ids.len() + 2) / 3;
 
-        let node_id_indecies =
-            rand::seq::index::sample(&mut thread_rng(), node_ids.len(), node_sample_amount);
+        let node_id_indecies = clock_and_rng
+            .rng(|rng| rand::seq::index::sample(rng, node_ids.len(), node_sample_amount));
 
         node_id_indecies.into_iter().map(|index| node_ids[index]).collect()
     }
@@ -145,12 +176,25 @@ impl Client {
     /// The operator private key is used to sign all transactions executed by this client.
     ///
     pub fn set_operator(&self, id: AccountId, key: PrivateKey) {
-        self.0.operator.store(Some(Arc::new(Operator { account_id: id, signer: key })));
+        self.0.operator.store(Some(Arc::new(Operator {
+            account_id: id,
+            signer: key,
+            clock_and_rng: ClockAndRng::default(),
+        })));
     }
 
     /// Generate a new transaction ID from the stored operator account ID, if present.
     pub(crate) async fn generate_transaction_id(&self) -> Option<TransactionId> {
-        self.0.operator.load().as_deref().map(Operator::generate_transaction_id)
+        self.operator().map(|it| it.generate_transaction_id())
+    }
+
+    /// Returns a copy of the operator that uses this client's clock and RNG, if present.
+    pub(crate) fn operator(&self) -> Option<Operator> {
+        self.0
+            .operator
+            .load()
+            .as_deref()
+            .map(|it| Operator { clock_and_rng: self.clock_and_rng(), ..it.clone() })
     }
 
     /// Gets a reference to the configured network.
@@ -191,11 +191,9 @@ impl Network {
         Ok(indexes)
     }
 
-    pub(crate) fn mark_node_unhealthy(&self, node_index: usize) {
-        self.healthy[node_index].store(
-            (OffsetDateTime::now_utc() + time::Duration::minutes(30)).unix_timestamp(),
-            Ordering::Relaxed,
-        );
+    pub(crate) fn mark_node_unhealthy(&self, node_index: usize, now: OffsetDateTime) {
+        self.healthy[node_index]
+            .store((now + time::Duration::minutes(30)).unix_timestamp(), Ordering::Relaxed);
     }
 
     pub(crate) fn is_node_healthy(&self, node_index: usize, now: OffsetDateTime) -> bool {
@@ -205,14 +203,18 @@ impl Network {
         self.healthy[node_index].load(Ordering::Relaxed) < now
     }
 
-    pub(crate) fn healthy_node_indexes(&self) -> impl Iterator<Item = usize> + '_ {
-        let now = OffsetDateTime::now_utc();
-
+    pub(crate) fn healthy_node_indexes(
+        &self,
+        now: OffsetDateTime,
+    ) -> impl Iterator<Item = usize> + '_ {
         (0..self.nodes.len()).filter(move |index| self.is_node_healthy(*index, now))
     }
 
-    pub(crate) fn healthy_node_ids(&self) -> impl Iterator<Item = AccountId> + '_ {
-        self.healthy_node_indexes().map(|it| self.nodes[it])
+    pub(crate) fn healthy_node_ids(
+        &self,
+        now: OffsetDateTime,
+    ) -> impl Iterator<Item = AccountId> + '_ {
+        self.healthy_node_indexes(now).map(|it| self.nodes[it])
     }
 
     pub(crate) fn channel(&self, index: usize) -> (AccountId, Channel) {
@@ -255,3 +257,28 @@ impl Network {
         (id, channel)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use time::{
+        Duration,
+        OffsetDateTime,
+    };
+
+    use super::{
+        Network,
+        TESTNET,
+    };
+
+    #[test]
+    fn unhealthy_node_recovers_after_backoff() {
+        let network = Network::from_static(TESTNET);
+        let now = OffsetDateTime::from_unix_timestamp(1_640_995_200).unwrap();
+
+        network.mark_node_unhealthy(0, now);
+
+        assert!(!network.is_node_healthy(0, now + Duration::minutes(29)));
+        assert!(network.is_node_healthy(0, now + Duration::minutes(31)));
+        assert!(!network.healthy_node_indexes(now).any(|it| it == 0));
+    }
+}
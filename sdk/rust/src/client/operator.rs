@@ -1,3 +1,4 @@
+use super::ClockAndRng;
 use crate::{
     AccountId,
     PrivateKey,
@@ -11,6 +12,10 @@ use crate::{
 pub(crate) struct Operator {
     pub account_id: AccountId,
     pub signer: PrivateKey,
+
+    /// The clock and RNG of the client this operator was taken from.
+    #[cfg_attr(feature = "ffi", serde(skip))]
+    pub clock_and_rng: ClockAndRng,
 }
 
 impl Operator {
@@ -19,6 +24,6 @@ impl Operator {
     }
 
     pub(crate) fn generate_transaction_id(&self) -> TransactionId {
-        TransactionId::generate(self.account_id)
+        TransactionId::generate_with(self.account_id, &self.clock_and_rng)
     }
 }
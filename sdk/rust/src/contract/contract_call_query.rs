@@ -33,9 +33,12 @@ use crate::{
     ContractFunctionResult,
     ContractId,
     Error,
+    FromProtobuf,
     LedgerId,
     Query,
+    Status,
     ToProtobuf,
+    TransactionId,
     ValidateChecksums,
 };
 
@@ -157,6 +160,21 @@ impl QueryExecute for ContractCallQueryData {
             SmartContractServiceClient::new(channel).contract_call_local_method(request).await
         })
     }
+
+    fn make_error_pre_check_response(
+        &self,
+        status: Status,
+        transaction_id: Option<TransactionId>,
+        response: services::Response,
+    ) -> Option<Error> {
+        if status != Status::ContractRevertExecuted {
+            return None;
+        }
+
+        let result = ContractFunctionResult::from_protobuf(response.response?).ok()?;
+
+        Some(Error::ContractRevert { status, transaction_id, revert: Box::new(result.revert?) })
+    }
 }
 
 impl ValidateChecksums for ContractCallQueryData {
@@ -0,0 +1,404 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use time::Duration;
+
+use crate::contract::ContractCreateTransactionData;
+use crate::file::FileUpload;
+use crate::{
+    AccountId,
+    Client,
+    ContractCreateTransaction,
+    ContractFunctionParameters,
+    Error,
+    FileDeleteTransaction,
+    FileId,
+    Hbar,
+    Key,
+    TransactionReceipt,
+};
+
+/// Create a new smart contract, uploading its bytecode to a file first.
+///
+/// The network can't accept bytecode larger than a single transaction inline,
+/// so this flow:
+/// 1. Creates a file with the (hex encoded) bytecode using a [`FileCreateTransaction`](crate::FileCreateTransaction),
+///    and a [`FileAppendTransaction`](crate::FileAppendTransaction) if the bytecode doesn't fit in one transaction.
+/// 2. Creates the contract with a [`ContractCreateTransaction`] referencing that file.
+/// 3. Deletes the file with a [`FileDeleteTransaction`].
+///
+/// The file is signed for by the client's operator,
+/// and is deleted whether or not creating the contract succeeds.
+#[derive(Debug, Default)]
+pub struct ContractCreateFlow {
+    bytecode: Vec<u8>,
+    node_account_ids: Option<Vec<AccountId>>,
+    max_chunks: Option<usize>,
+    contract_create_data: ContractCreateTransactionData,
+}
+
+impl ContractCreateFlow {
+    /// Create a new `ContractCreateFlow`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the raw bytecode of the contract to create.
+    #[must_use]
+    pub fn get_bytecode(&self) -> &[u8] {
+        &self.bytecode
+    }
+
+    /// Sets the raw bytecode of the contract to create.
+    pub fn bytecode(&mut self, bytecode: impl Into<Vec<u8>>) -> &mut Self {
+        self.bytecode = bytecode.into();
+        self
+    }
+
+    /// Sets the bytecode of the contract to create from a hex string, as output by `solc`.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `bytecode` isn't valid hex.
+    pub fn bytecode_hex(&mut self, bytecode: &str) -> crate::Result<&mut Self> {
+        let bytecode = bytecode.trim();
+        let bytecode = bytecode.strip_prefix("0x").unwrap_or(bytecode);
+
+        self.bytecode = hex::decode(bytecode).map_err(Error::basic_parse)?;
+
+        Ok(self)
+    }
+
+    /// Returns the parameters to pass to the constructor.
+    #[must_use]
+    pub fn get_constructor_parameters(&self) -> &[u8] {
+        &self.contract_create_data.constructor_parameters
+    }
+
+    /// Sets the parameters to pass to the constructor as their raw bytes.
+    pub fn constructor_parameters(&mut self, parameters: impl AsRef<[u8]>) -> &mut Self {
+        self.contract_create_data.constructor_parameters = parameters.as_ref().to_vec();
+        self
+    }
+
+    /// Sets the parameters to pass to the constructor.
+    pub fn constructor_function_parameters(
+        &mut self,
+        parameters: &ContractFunctionParameters,
+    ) -> &mut Self {
+        self.constructor_parameters(parameters.to_bytes(None::<&str>))
+    }
+
+    /// Returns the gas limit to deploy the smart contract.
+    #[must_use]
+    pub fn get_gas(&self) -> u64 {
+        self.contract_create_data.gas
+    }
+
+    /// Sets the gas limit to deploy the smart contract.
+    pub fn gas(&mut self, gas: u64) -> &mut Self {
+        self.contract_create_data.gas = gas;
+        self
+    }
+
+    /// Returns the initial balance to put into the cryptocurrency account associated with the new
+    /// smart contract.
+    #[must_use]
+    pub fn get_initial_balance(&self) -> Hbar {
+        self.contract_create_data.initial_balance
+    }
+
+    /// Sets the initial balance to put into the cryptocurrency account associated with the new
+    /// smart contract.
+    pub fn initial_balance(&mut self, balance: Hbar) -> &mut Self {
+        self.contract_create_data.initial_balance = balance;
+        self
+    }
+
+    /// Returns the admin key of the new smart contract.
+    #[must_use]
+    pub fn get_admin_key(&self) -> Option<&Key> {
+        self.contract_create_data.admin_key.as_ref()
+    }
+
+    /// Sets the admin key of the new smart contract.
+    pub fn admin_key(&mut self, key: impl Into<Key>) -> &mut Self {
+        self.contract_create_data.admin_key = Some(key.into());
+        self
+    }
+
+    /// Returns the auto renew period for the new smart contract.
+    #[must_use]
+    pub fn get_auto_renew_period(&self) -> Duration {
+        self.contract_create_data.auto_renew_period
+    }
+
+    /// Sets the auto renew period for the new smart contract.
+    pub fn auto_renew_period(&mut self, period: Duration) -> &mut Self {
+        self.contract_create_data.auto_renew_period = period;
+        self
+    }
+
+    /// Returns the memo for the new smart contract.
+    #[must_use]
+    pub fn get_contract_memo(&self) -> &str {
+        &self.contract_create_data.contract_memo
+    }
+
+    /// Sets the memo for the new smart contract.
+    pub fn contract_memo(&mut self, memo: impl Into<String>) -> &mut Self {
+        self.contract_create_data.contract_memo = memo.into();
+        self
+    }
+
+    /// Returns the maximum number of tokens that the new contract can be automatically associated with.
+    #[must_use]
+    pub fn get_max_automatic_token_associations(&self) -> u32 {
+        self.contract_create_data.max_automatic_token_associations
+    }
+
+    /// Sets the maximum number of tokens that the new contract can be automatically associated with.
+    pub fn max_automatic_token_associations(&mut self, max: u32) -> &mut Self {
+        self.contract_create_data.max_automatic_token_associations = max;
+        self
+    }
+
+    /// Returns the account to be used at the contract's expiration time to extend the life of the contract.
+    #[must_use]
+    pub fn get_auto_renew_account_id(&self) -> Option<AccountId> {
+        self.contract_create_data.auto_renew_account_id
+    }
+
+    /// Sets the account to be used at the contract's expiration time to extend the life of the contract.
+    pub fn auto_renew_account_id(&mut self, account_id: AccountId) -> &mut Self {
+        self.contract_create_data.auto_renew_account_id = Some(account_id);
+        self
+    }
+
+    /// Returns the ID of the account to which the new contract is staking.
+    #[must_use]
+    pub fn get_staked_account_id(&self) -> Option<AccountId> {
+        self.contract_create_data.staked_id.and_then(|it| it.to_account_id())
+    }
+
+    /// Sets the ID of the account to which the new contract is staking.
+    pub fn staked_account_id(&mut self, id: AccountId) -> &mut Self {
+        self.contract_create_data.staked_id = Some(id.into());
+        self
+    }
+
+    /// Returns the ID of the node to which the new contract is staking.
+    #[must_use]
+    pub fn get_staked_node_id(&self) -> Option<u64> {
+        self.contract_create_data.staked_id.and_then(|it| it.to_node_id())
+    }
+
+    /// Sets the ID of the node to which the new contract is staking.
+    pub fn staked_node_id(&mut self, id: u64) -> &mut Self {
+        self.contract_create_data.staked_id = Some(id.into());
+        self
+    }
+
+    /// Returns true if the new contract should decline to pay the account's staking revenue.
+    #[must_use]
+    pub fn get_decline_staking_reward(&self) -> bool {
+        self.contract_create_data.decline_staking_reward
+    }
+
+    /// Sets to true if the new contract should decline to pay the account's staking revenue.
+    pub fn decline_staking_reward(&mut self, decline: bool) -> &mut Self {
+        self.contract_create_data.decline_staking_reward = decline;
+        self
+    }
+
+    /// Returns the account IDs of the nodes the transactions may be submitted to.
+    #[must_use]
+    pub fn get_node_account_ids(&self) -> Option<&[AccountId]> {
+        self.node_account_ids.as_deref()
+    }
+
+    /// Sets the account IDs of the nodes the transactions may be submitted to.
+    ///
+    /// Defaults to the full list of nodes configured on the client.
+    pub fn node_account_ids(&mut self, ids: impl IntoIterator<Item = AccountId>) -> &mut Self {
+        self.node_account_ids = Some(ids.into_iter().collect());
+        self
+    }
+
+    /// Returns the maximum number of chunks the [`FileAppendTransaction`](crate::FileAppendTransaction) may use.
+    #[must_use]
+    pub fn get_max_chunks(&self) -> Option<usize> {
+        self.max_chunks
+    }
+
+    /// Sets the maximum number of chunks the [`FileAppendTransaction`](crate::FileAppendTransaction) may use.
+    pub fn max_chunks(&mut self, max_chunks: usize) -> &mut Self {
+        self.max_chunks = Some(max_chunks);
+        self
+    }
+
+    /// Execute the flow, returning the receipt of the [`ContractCreateTransaction`].
+    ///
+    /// # Errors
+    /// - [`Error::NoPayerAccountOrTransactionId`] if the client has no operator.
+    /// - Any error returned by executing one of the transactions or fetching its receipt.
+    pub async fn execute(&self, client: &Client) -> crate::Result<TransactionReceipt> {
+        let operator_key = FileUpload::operator_key(client)?;
+
+        let bytecode = hex::encode(&self.bytecode).into_bytes();
+        let (create_contents, append_contents) = FileUpload::split_contents(&bytecode);
+
+        let file_id = self.file_upload().create(client, operator_key, create_contents).await?;
+
+        let receipt = self.upload_and_create(client, file_id, append_contents).await;
+
+        let deleted = match self.make_file_delete_transaction(file_id).execute(client).await {
+            Ok(response) => response.get_receipt(client).await.map(drop),
+            Err(e) => Err(e),
+        };
+
+        // an error creating the contract takes precedence over an error cleaning up.
+        let receipt = receipt?;
+        deleted?;
+
+        Ok(receipt)
+    }
+
+    async fn upload_and_create(
+        &self,
+        client: &Client,
+        file_id: FileId,
+        append_contents: &[u8],
+    ) -> crate::Result<TransactionReceipt> {
+        self.file_upload().append(client, file_id, append_contents).await?;
+
+        self.make_contract_create_transaction(file_id)
+            .execute(client)
+            .await?
+            .get_receipt(client)
+            .await
+    }
+
+    fn file_upload(&self) -> FileUpload<'_> {
+        FileUpload {
+            node_account_ids: self.node_account_ids.as_deref(),
+            max_chunks: self.max_chunks,
+        }
+    }
+
+    fn make_contract_create_transaction(&self, file_id: FileId) -> ContractCreateTransaction {
+        let mut contract_create = ContractCreateTransaction::new();
+
+        *contract_create.data_mut() = ContractCreateTransactionData {
+            bytecode: None,
+            bytecode_file_id: Some(file_id),
+            ..self.contract_create_data.clone()
+        };
+
+        if let Some(node_account_ids) = &self.node_account_ids {
+            contract_create.node_account_ids(node_account_ids.iter().copied());
+        }
+
+        contract_create
+    }
+
+    fn make_file_delete_transaction(&self, file_id: FileId) -> FileDeleteTransaction {
+        let mut file_delete = FileDeleteTransaction::new();
+        file_delete.file_id(file_id);
+
+        if let Some(node_account_ids) = &self.node_account_ids {
+            file_delete.node_account_ids(node_account_ids.iter().copied());
+        }
+
+        file_delete
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+    use time::Duration;
+
+    use crate::{
+        AccountId,
+        ContractCreateFlow,
+        Error,
+        FileId,
+        Hbar,
+        Key,
+        PrivateKey,
+    };
+
+    #[test]
+    fn bytecode_hex() -> crate::Result<()> {
+        let mut flow = ContractCreateFlow::new();
+
+        flow.bytecode_hex("0x6080604052\n")?;
+
+        assert_eq!(flow.get_bytecode(), &[0x60, 0x80, 0x60, 0x40, 0x52]);
+
+        assert_matches!(flow.bytecode_hex("608"), Err(Error::BasicParse(_)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn transactions() {
+        let admin_key = PrivateKey::generate_ed25519().public_key();
+        let file_id = FileId::from(1001);
+        let nodes = [AccountId::from(3), AccountId::from(4)];
+
+        let mut flow = ContractCreateFlow::new();
+        flow.bytecode([0x60, 0x80, 0x60, 0x40, 0x52])
+            .constructor_parameters([1, 2, 3])
+            .gas(100_000)
+            .initial_balance(Hbar::new(2))
+            .admin_key(admin_key)
+            .auto_renew_period(Duration::days(90))
+            .contract_memo("flow")
+            .max_automatic_token_associations(5)
+            .staked_node_id(7)
+            .decline_staking_reward(true)
+            .node_account_ids(nodes)
+            .max_chunks(3);
+
+        let file_upload = flow.file_upload();
+        assert_eq!(file_upload.node_account_ids, Some(&nodes[..]));
+        assert_eq!(file_upload.max_chunks, Some(3));
+
+        let contract_create = flow.make_contract_create_transaction(file_id);
+        assert_eq!(contract_create.get_bytecode_file_id(), Some(file_id));
+        assert_eq!(contract_create.get_bytecode(), None);
+        assert_eq!(contract_create.get_constructor_parameters(), &[1, 2, 3]);
+        assert_eq!(contract_create.get_gas(), 100_000);
+        assert_eq!(contract_create.get_initial_balance(), Hbar::new(2));
+        assert_eq!(contract_create.get_admin_key(), Some(&Key::Single(admin_key)));
+        assert_eq!(contract_create.get_auto_renew_period(), Duration::days(90));
+        assert_eq!(contract_create.get_contract_memo(), "flow");
+        assert_eq!(contract_create.get_max_automatic_token_associations(), 5);
+        assert_eq!(contract_create.get_staked_node_id(), Some(7));
+        assert!(contract_create.get_decline_staking_reward());
+        assert_eq!(contract_create.get_node_account_ids(), Some(&nodes[..]));
+
+        let file_delete = flow.make_file_delete_transaction(file_id);
+        assert_eq!(file_delete.get_file_id(), Some(file_id));
+        assert_eq!(file_delete.get_node_account_ids(), Some(&nodes[..]));
+    }
+}
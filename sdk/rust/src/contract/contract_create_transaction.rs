@@ -26,6 +26,7 @@ use tonic::transport::Channel;
 use crate::protobuf::FromProtobuf;
 use crate::staked_id::StakedId;
 use crate::transaction::{
+    validate,
     AnyTransactionData,
     ChunkInfo,
     ToSchedulableTransactionDataProtobuf,
@@ -58,36 +59,36 @@ pub struct ContractCreateTransactionData {
         feature = "ffi",
         serde(with = "serde_with::As::<Option<serde_with::base64::Base64>>")
     )]
-    bytecode: Option<Vec<u8>>,
+    pub(super) bytecode: Option<Vec<u8>>,
 
-    bytecode_file_id: Option<FileId>,
+    pub(super) bytecode_file_id: Option<FileId>,
 
-    admin_key: Option<Key>,
+    pub(super) admin_key: Option<Key>,
 
-    gas: u64,
+    pub(super) gas: u64,
 
-    initial_balance: Hbar,
+    pub(super) initial_balance: Hbar,
 
     #[cfg_attr(
         feature = "ffi",
         serde(with = "serde_with::As::<serde_with::DurationSeconds<i64>>")
     )]
-    auto_renew_period: Duration,
+    pub(super) auto_renew_period: Duration,
 
     #[cfg_attr(feature = "ffi", serde(with = "serde_with::As::<serde_with::base64::Base64>"))]
-    constructor_parameters: Vec<u8>,
+    pub(super) constructor_parameters: Vec<u8>,
 
-    contract_memo: String,
+    pub(super) contract_memo: String,
 
-    max_automatic_token_associations: u32,
+    pub(super) max_automatic_token_associations: u32,
 
-    auto_renew_account_id: Option<AccountId>,
+    pub(super) auto_renew_account_id: Option<AccountId>,
 
     /// ID of the account or node to which this contract is staking, if any.
     #[cfg_attr(feature = "ffi", serde(flatten))]
-    staked_id: Option<StakedId>,
+    pub(super) staked_id: Option<StakedId>,
 
-    decline_staking_reward: bool,
+    pub(super) decline_staking_reward: bool,
 }
 
 impl Default for ContractCreateTransactionData {
@@ -273,7 +274,20 @@ impl ContractCreateTransaction {
     }
 }
 
-impl TransactionData for ContractCreateTransactionData {}
+impl TransactionData for ContractCreateTransactionData {
+    fn validate(&self) -> crate::Result<()> {
+        if self.bytecode.is_none() && self.bytecode_file_id.is_none() {
+            return Err(Error::invalid_transaction_field(
+                "bytecode",
+                "either `bytecode` or `bytecode_file_id` must be set",
+            ));
+        }
+        validate::optional_key("admin_key", &self.admin_key)?;
+        validate::memo("contract_memo", &self.contract_memo)?;
+
+        Ok(())
+    }
+}
 
 impl TransactionExecute for ContractCreateTransactionData {
     fn execute(
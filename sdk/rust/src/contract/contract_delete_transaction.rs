@@ -27,6 +27,7 @@ use crate::protobuf::{
     ToProtobuf,
 };
 use crate::transaction::{
+    validate,
     AnyTransactionData,
     ChunkInfo,
     ToSchedulableTransactionDataProtobuf,
@@ -99,7 +100,20 @@ impl ContractDeleteTransaction {
     }
 }
 
-impl TransactionData for ContractDeleteTransactionData {}
+impl TransactionData for ContractDeleteTransactionData {
+    fn validate(&self) -> crate::Result<()> {
+        validate::require("contract_id", &self.contract_id)?;
+
+        if self.transfer_account_id.is_none() && self.transfer_contract_id.is_none() {
+            return Err(Error::invalid_transaction_field(
+                "transfer_account_id",
+                "either `transfer_account_id` or `transfer_contract_id` must be set",
+            ));
+        }
+
+        Ok(())
+    }
+}
 
 impl TransactionExecute for ContractDeleteTransactionData {
     fn execute(
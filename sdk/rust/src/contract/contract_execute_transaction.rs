@@ -24,6 +24,7 @@ use tonic::transport::Channel;
 
 use crate::protobuf::FromProtobuf;
 use crate::transaction::{
+    validate,
     AnyTransactionData,
     ChunkInfo,
     ToSchedulableTransactionDataProtobuf,
@@ -122,7 +123,13 @@ impl ContractExecuteTransaction {
     }
 }
 
-impl TransactionData for ContractExecuteTransactionData {}
+impl TransactionData for ContractExecuteTransactionData {
+    fn validate(&self) -> crate::Result<()> {
+        validate::require("contract_id", &self.contract_id)?;
+
+        Ok(())
+    }
+}
 
 impl TransactionExecute for ContractExecuteTransactionData {
     fn execute(
@@ -1,3 +1,4 @@
+use std::borrow::Cow;
 use std::cmp::max;
 use std::str::FromStr;
 
@@ -7,8 +8,14 @@ use num_bigint::{
     Sign,
 };
 
+use crate::abi::codec;
 use crate::contract::contract_function_selector::ContractFunctionSelector;
 use crate::evm_address::IdEvmAddress;
+use crate::{
+    AbiEncode,
+    AbiType,
+    AbiValue,
+};
 
 /// Builder for encoding parameters for a Solidity contract constructor/function call.
 #[derive(Debug, Clone)]
@@ -18,7 +25,7 @@ pub struct ContractFunctionParameters {
 
 #[derive(Debug, Clone)]
 struct Argument {
-    type_name: &'static str,
+    type_name: Cow<'static, str>,
     value_bytes: Vec<u8>,
     is_dynamic: bool,
 }
@@ -121,13 +128,15 @@ impl ContractFunctionParameters {
         //
         // see: https://www.possiblerust.com/pattern/non-generic-inner-functions
         fn inner(args: &[Argument], func_name: Option<&str>) -> Vec<u8> {
-            let mut current_dynamic_offset = args.len() * 32;
+            // static arguments (like tuples of static types) can take more than one slot in the head.
+            let mut current_dynamic_offset: usize =
+                args.iter().map(|it| if it.is_dynamic { 32 } else { it.value_bytes.len() }).sum();
             let mut arg_bytes = Vec::new();
             let mut dynamic_arg_bytes = Vec::new();
             let mut function_selector = func_name.map(ContractFunctionSelector::new);
             for arg in args {
                 if let Some(selector) = &mut function_selector {
-                    selector.add_param_type(arg.type_name);
+                    selector.add_param_type(&arg.type_name);
                 }
                 if arg.is_dynamic {
                     arg_bytes.extend_from_slice(
@@ -159,7 +168,7 @@ impl ContractFunctionParameters {
     /// Add a `string` argument to the `ContractFunctionParameters`
     pub fn add_string<T: AsRef<str>>(&mut self, val: T) -> &mut Self {
         self.args.push(Argument {
-            type_name: "string",
+            type_name: Cow::Borrowed("string"),
             value_bytes: encode_dynamic_bytes(val.as_ref().as_bytes()),
             is_dynamic: true,
         });
@@ -169,7 +178,7 @@ impl ContractFunctionParameters {
     /// Add a `string[]` argument to the `ContractFunctionParameters`
     pub fn add_string_array<T: AsRef<str>>(&mut self, val: &[T]) -> &mut Self {
         self.args.push(Argument {
-            type_name: "string[]",
+            type_name: Cow::Borrowed("string[]"),
             value_bytes: encode_array_of_dynamic_byte_arrays(
                 val.into_iter().map(|s| s.as_ref().as_bytes()),
                 val.len(),
@@ -182,7 +191,7 @@ impl ContractFunctionParameters {
     /// Add a `bytes` argument to the `ContractFunctionParameters`
     pub fn add_bytes(&mut self, val: &[u8]) -> &mut Self {
         self.args.push(Argument {
-            type_name: "bytes",
+            type_name: Cow::Borrowed("bytes"),
             value_bytes: encode_dynamic_bytes(val),
             is_dynamic: true,
         });
@@ -192,7 +201,7 @@ impl ContractFunctionParameters {
     /// Add a `bytes[]` argument to the `ContractFunctionParameters`
     pub fn add_bytes_array(&mut self, val: &[&[u8]]) -> &mut Self {
         self.args.push(Argument {
-            type_name: "bytes[]",
+            type_name: Cow::Borrowed("bytes[]"),
             value_bytes: encode_array_of_dynamic_byte_arrays(val, val.len()),
             is_dynamic: true,
         });
@@ -202,7 +211,7 @@ impl ContractFunctionParameters {
     /// Add a `bytes32` argument to the `ContractFunctionParameters`
     pub fn add_bytes32(&mut self, val: &[u8; 32]) -> &mut Self {
         self.args.push(Argument {
-            type_name: "bytes32",
+            type_name: Cow::Borrowed("bytes32"),
             value_bytes: val.to_vec(),
             is_dynamic: false,
         });
@@ -212,7 +221,7 @@ impl ContractFunctionParameters {
     /// Add a `bytes32[]` argument to the `ContractFunctionParameters`
     pub fn add_bytes32_array(&mut self, val: &[[u8; 32]]) -> &mut Self {
         self.args.push(Argument {
-            type_name: "bytes32",
+            type_name: Cow::Borrowed("bytes32"),
             value_bytes: encode_array_of_32_byte_elements(val.into_iter().copied(), val.len()),
             is_dynamic: true,
         });
@@ -222,7 +231,7 @@ impl ContractFunctionParameters {
     /// Add a `bool` argument to the `ContractFunctionParameters`
     pub fn add_bool(&mut self, val: bool) -> &mut Self {
         self.args.push(Argument {
-            type_name: "bool",
+            type_name: Cow::Borrowed("bool"),
             value_bytes: left_pad_32_bytes(
                 // a bool in rust is guaranteed to be of value 0 or 1
                 (val as u32).to_be_bytes().as_slice(),
@@ -239,7 +248,7 @@ impl ContractFunctionParameters {
         T: IntEncode,
     {
         self.args.push(Argument {
-            type_name,
+            type_name: Cow::Borrowed(type_name),
             value_bytes: truncate_and_left_pad_32_bytes(val, byte_count).to_vec(),
             is_dynamic: false,
         });
@@ -256,7 +265,7 @@ impl ContractFunctionParameters {
         T: IntEncode,
     {
         self.args.push(Argument {
-            type_name,
+            type_name: Cow::Borrowed(type_name),
             value_bytes: encode_array_of_32_byte_elements(
                 values.into_iter().map(|val| truncate_and_left_pad_32_bytes(val, byte_count)),
                 values.len(),
@@ -909,7 +918,7 @@ impl ContractFunctionParameters {
     /// Add an `address` argument to the `ContractFunctionParameters`
     pub fn add_address(&mut self, address: &str) -> &mut Self {
         self.args.push(Argument {
-            type_name: "address",
+            type_name: Cow::Borrowed("address"),
             value_bytes: encode_address(address).to_vec(),
             is_dynamic: false,
         });
@@ -919,7 +928,7 @@ impl ContractFunctionParameters {
     /// Add an `address[]` argument to the `ContractFunctionParameters`
     pub fn add_address_array(&mut self, addresses: &[&str]) -> &mut Self {
         self.args.push(Argument {
-            type_name: "address[]",
+            type_name: Cow::Borrowed("address[]"),
             value_bytes: encode_array_of_32_byte_elements(
                 addresses.iter().map(|addr| encode_address(addr)),
                 addresses.len(),
@@ -929,6 +938,40 @@ impl ContractFunctionParameters {
         self
     }
 
+    /// Add an argument of any ABI type to the `ContractFunctionParameters`.
+    ///
+    /// This is how tuples (Solidity `struct`s), fixed-size arrays, and nested arrays are added.
+    ///
+    /// # Errors
+    /// - [`Error::AbiEncode`](crate::Error::AbiEncode) if `value` can't be encoded as `ty`.
+    pub fn add_value(&mut self, ty: &AbiType, value: &AbiValue) -> crate::Result<&mut Self> {
+        self.args.push(Argument {
+            type_name: Cow::Owned(ty.to_string()),
+            value_bytes: codec::encode_single(ty, value)?,
+            is_dynamic: ty.is_dynamic(),
+        });
+
+        Ok(self)
+    }
+
+    /// Add an argument of any type that implements [`AbiEncode`], such as a struct with `#[derive(AbiEncode)]`.
+    ///
+    /// # Panics
+    /// - if `T`'s [`AbiEncode`] implementation produces a value that can't be encoded as its own [`abi_type`](AbiEncode::abi_type),
+    ///   including if that type isn't valid (such as `AbiFixedBytes<40>`).
+    pub fn add<T: AbiEncode + ?Sized>(&mut self, value: &T) -> &mut Self {
+        let ty = T::abi_type();
+
+        self.args.push(Argument {
+            type_name: Cow::Owned(ty.to_string()),
+            value_bytes: codec::encode_single(&ty, &value.to_abi_value())
+                .expect("`AbiEncode` produced a value that can't be encoded as its type"),
+            is_dynamic: ty.is_dynamic(),
+        });
+
+        self
+    }
+
     /// Add a `function` argument to the `ContractFunctionParameters`
     pub fn add_function(
         &mut self,
@@ -938,7 +981,7 @@ impl ContractFunctionParameters {
         let mut value_bytes = IdEvmAddress::from_str(address).unwrap().to_bytes().to_vec();
         value_bytes.extend(selector.finish());
         self.args.push(Argument {
-            type_name: "function",
+            type_name: Cow::Borrowed("function"),
             value_bytes: right_pad_32_bytes(value_bytes.as_slice()).to_vec(),
             is_dynamic: false,
         });
@@ -1016,6 +1059,7 @@ where
 
 #[cfg(test)]
 mod tests {
+    use assert_matches::assert_matches;
     use num_bigint::{
         BigInt,
         BigUint,
@@ -1023,6 +1067,14 @@ mod tests {
 
     use crate::contract::contract_function_parameters::ContractFunctionParameters;
     use crate::contract::contract_function_selector::ContractFunctionSelector;
+    use crate::{
+        AbiEncode,
+        AbiFixedBytes,
+        AbiFunction,
+        AbiType,
+        AbiValue,
+        Error,
+    };
 
     #[test]
     fn misc_params() {
@@ -1146,4 +1198,125 @@ mod tests {
                 112233445566778899001122334455667788990063441d820000000000000000"
         );
     }
+
+    #[test]
+    fn tuple_params() -> crate::Result<()> {
+        let param_bytes = ContractFunctionParameters::new()
+            .add_value(
+                &"(uint256,bool)".parse::<AbiType>()?,
+                &AbiValue::Tuple(vec![1_u8.into(), true.into()]),
+            )?
+            .add_string("hi")
+            .add_value(
+                &"(string,uint8[2])".parse::<AbiType>()?,
+                &AbiValue::Tuple(vec![
+                    "yo".into(),
+                    AbiValue::FixedArray(vec![3_u8.into(), 4_u8.into()]),
+                ]),
+            )?
+            .to_bytes(Some("foo"));
+
+        assert_eq!(
+            &param_bytes[..4],
+            ContractFunctionSelector::new("foo")
+                .add_param_type("(uint256,bool)")
+                .add_string()
+                .add_param_type("(string,uint8[2])")
+                .finish()
+        );
+
+        assert_eq!(
+            hex::encode(&param_bytes[4..]),
+            "0000000000000000000000000000000000000000000000000000000000000001\
+                0000000000000000000000000000000000000000000000000000000000000001\
+                0000000000000000000000000000000000000000000000000000000000000080\
+                00000000000000000000000000000000000000000000000000000000000000c0\
+                0000000000000000000000000000000000000000000000000000000000000002\
+                6869000000000000000000000000000000000000000000000000000000000000\
+                0000000000000000000000000000000000000000000000000000000000000060\
+                0000000000000000000000000000000000000000000000000000000000000003\
+                0000000000000000000000000000000000000000000000000000000000000004\
+                0000000000000000000000000000000000000000000000000000000000000002\
+                796f000000000000000000000000000000000000000000000000000000000000"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn derived_params() -> crate::Result<()> {
+        #[derive(AbiEncode)]
+        struct Point {
+            x: BigUint,
+            on: bool,
+        }
+
+        #[derive(AbiEncode)]
+        struct Named(String, [u8; 2]);
+
+        let derived = ContractFunctionParameters::new()
+            .add(&Point { x: BigUint::from(1_u8), on: true })
+            .add("hi")
+            .add(&Named("yo".to_owned(), [3, 4]))
+            .to_bytes(Some("foo"));
+
+        let manual = ContractFunctionParameters::new()
+            .add_value(
+                &"(uint256,bool)".parse::<AbiType>()?,
+                &AbiValue::Tuple(vec![1_u8.into(), true.into()]),
+            )?
+            .add_string("hi")
+            .add_value(
+                &"(string,uint8[2])".parse::<AbiType>()?,
+                &AbiValue::Tuple(vec![
+                    "yo".into(),
+                    AbiValue::FixedArray(vec![3_u8.into(), 4_u8.into()]),
+                ]),
+            )?
+            .to_bytes(Some("foo"));
+
+        assert_eq!(derived, manual);
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_fixed_bytes_len() {
+        assert_matches!(
+            ContractFunctionParameters::new()
+                .add_value(&AbiType::FixedBytes(40), &AbiValue::FixedBytes(vec![0; 40])),
+            Err(Error::AbiEncode(_))
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "`AbiEncode` produced a value that can't be encoded as its type")]
+    fn add_invalid_fixed_bytes_len() {
+        ContractFunctionParameters::new().add(&AbiFixedBytes([0; 40]));
+    }
+
+    #[test]
+    fn decode_params() -> crate::Result<()> {
+        let data = ContractFunctionParameters::new()
+            .add_uint64(7)
+            .add_string("hi")
+            .add_bool(true)
+            .to_bytes(Some("foo"));
+
+        let values = AbiFunction::from_signature("foo(uint64,string,bool)")?.decode_input(&data)?;
+
+        assert_eq!(values[0], AbiValue::from(7_u64));
+        assert_eq!(values[1].as_str(), Some("hi"));
+        assert_eq!(values[2].as_bool(), Some(true));
+
+        assert_matches!(
+            AbiFunction::from_signature("bar(uint64,string,bool)")?.decode_input(&data),
+            Err(Error::AbiDecode(_))
+        );
+
+        assert_matches!(AbiFunction::from_signature("foo(uint7)"), Err(Error::AbiParse(_)));
+        assert_matches!(AbiFunction::from_signature("(uint64)"), Err(Error::AbiParse(_)));
+
+        Ok(())
+    }
 }
@@ -27,11 +27,21 @@ use num_bigint::{
     BigUint,
 };
 
+use crate::abi::codec;
 use crate::{
+    Abi,
+    AbiDecode,
+    AbiEventLog,
+    AbiFunction,
+    AbiType,
+    AbiValue,
+    AbiValues,
     AccountId,
     ContractId,
     ContractLogInfo,
+    ContractRevert,
     FromProtobuf,
+    Status,
 };
 
 /// The result returned by a call to a smart contract function.
@@ -52,6 +62,13 @@ pub struct ContractFunctionResult {
     /// Message if there was an error during smart contract execution.
     pub error_message: Option<String>,
 
+    /// Why the contract reverted, if there was an error during smart contract execution.
+    ///
+    /// Custom errors can't be decoded without the contract's ABI,
+    /// use [`ContractRevert::with_abi`] to decode them.
+    #[cfg_attr(feature = "ffi", serde(skip))]
+    pub revert: Option<ContractRevert>,
+
     /// Bloom filter for record.
     #[cfg_attr(feature = "ffi", serde(with = "serde_with::As::<serde_with::base64::Base64>"))]
     pub bloom: Vec<u8>,
@@ -200,6 +217,63 @@ impl ContractFunctionResult {
     pub fn get_i256(&self, index: usize) -> Option<BigInt> {
         self.get_bytes32(index).map(|it| BigInt::from_signed_bytes_be(it))
     }
+
+    /// Get the value at `index` as a value of any ABI type, such as a tuple (Solidity `struct`) or nested array.
+    ///
+    /// Like the other getters, this assumes every value before `index` takes exactly one slot,
+    /// use [`decode`](Self::decode) for results that contain static tuples or fixed-size arrays.
+    ///
+    /// # Errors
+    /// - [`Error::AbiDecode`](crate::Error::AbiDecode) if the value isn't a valid encoding of `ty`.
+    pub fn get_value(&self, index: usize, ty: &AbiType) -> crate::Result<AbiValue> {
+        codec::decode_at(ty, &self.bytes, index * Self::SLOT_SIZE)
+    }
+
+    /// Decode the whole function result as a tuple of `types`.
+    ///
+    /// # Errors
+    /// - [`Error::AbiDecode`](crate::Error::AbiDecode) if the result isn't a valid encoding of `types`.
+    pub fn decode(&self, types: &[AbiType]) -> crate::Result<Vec<AbiValue>> {
+        codec::decode(types, &self.bytes)
+    }
+
+    /// Decode the whole function result as a `T`.
+    ///
+    /// A `T` that maps to a tuple, such as a Rust tuple or a struct with `#[derive(AbiDecode)]`,
+    /// is decoded from all the return values, one per element (or field),
+    /// any other `T` is decoded from the only return value.
+    ///
+    /// To decode a function that returns a single Solidity `struct`, use a one element tuple (`(MyStruct,)`).
+    ///
+    /// # Errors
+    /// - [`Error::AbiDecode`](crate::Error::AbiDecode) if the result isn't a valid encoding of `T`.
+    pub fn decode_as<T: AbiDecode>(&self) -> crate::Result<T> {
+        let value = match T::abi_type() {
+            AbiType::Tuple(types) => AbiValue::Tuple(codec::decode(&types, &self.bytes)?),
+            ty => {
+                let mut values = codec::decode(&[ty], &self.bytes)?;
+                values.swap_remove(0)
+            }
+        };
+
+        T::from_abi_value(value)
+    }
+
+    /// Decode the whole function result as the outputs of `function`.
+    ///
+    /// # Errors
+    /// - [`Error::AbiDecode`](crate::Error::AbiDecode) if the result isn't a valid encoding of `function`'s outputs.
+    pub fn decode_outputs(&self, function: &AbiFunction) -> crate::Result<AbiValues> {
+        function.decode_output(&self.bytes)
+    }
+
+    /// Decode the logs produced by this call that match an event in `abi`, skipping the rest.
+    ///
+    /// # Errors
+    /// - [`Error::AbiDecode`](crate::Error::AbiDecode) if a log matches an event, but isn't a valid encoding of it.
+    pub fn decode_logs(&self, abi: &Abi) -> crate::Result<Vec<AbiEventLog>> {
+        abi.decode_logs(&self.logs)
+    }
 }
 
 impl FromProtobuf<services::ContractFunctionResult> for ContractFunctionResult {
@@ -219,6 +293,21 @@ impl FromProtobuf<services::ContractFunctionResult> for ContractFunctionResult {
 
         let error_message = if pb.error_message.is_empty() { None } else { Some(pb.error_message) };
 
+        // the revert data is either the call result, or hex encoded in the error message.
+        // any other error message is the name of a status that isn't a revert (like `INSUFFICIENT_GAS`).
+        let revert = error_message.as_deref().and_then(|message| {
+            let data = match message.strip_prefix("0x").map(hex::decode) {
+                Some(Ok(data)) if pb.contract_call_result.is_empty() => data,
+                Some(Ok(_)) => pb.contract_call_result.clone(),
+                _ if message == Status::ContractRevertExecuted.as_str_name() => {
+                    pb.contract_call_result.clone()
+                }
+                _ => return None,
+            };
+
+            Some(ContractRevert::decode(&data))
+        });
+
         // if an exception was thrown, the call result is encoded like the params
         // for a function `Error(string)`
         // https://solidity.readthedocs.io/en/v0.6.2/control-structures.html#revert
@@ -235,6 +324,7 @@ impl FromProtobuf<services::ContractFunctionResult> for ContractFunctionResult {
             contract_id,
             bytes,
             error_message,
+            revert,
             bloom: pb.bloom,
             gas_used: pb.gas_used,
             gas: pb.gas as u64,
@@ -263,6 +353,7 @@ impl FromProtobuf<services::response::Response> for ContractFunctionResult {
 
 #[cfg(test)]
 mod tests {
+    use assert_matches::assert_matches;
     use fraction::{
         BigInt,
         BigUint,
@@ -275,9 +366,16 @@ mod tests {
         ToProtobuf,
     };
     use crate::{
+        Abi,
+        AbiDecode,
+        AbiFixedBytes,
+        AbiType,
+        AbiValue,
         AccountId,
         ContractFunctionResult,
         ContractId,
+        ContractRevert,
+        Error,
     };
 
     const CALL_RESULT: [u8; 320] = hex!(
@@ -396,6 +494,179 @@ mod tests {
         );
     }
 
+    #[test]
+    fn decode_outputs() -> crate::Result<()> {
+        let abi = Abi::from_json(
+            r#"[{
+                "type": "function",
+                "name": "getResults",
+                "inputs": [],
+                "outputs": [
+                    { "name": "small", "type": "uint32" },
+                    { "name": "max", "type": "int256" },
+                    { "name": "owner", "type": "address" },
+                    { "name": "big", "type": "uint256" },
+                    { "name": "greeting", "type": "string" },
+                    { "name": "again", "type": "string" }
+                ],
+                "stateMutability": "view"
+            }]"#,
+        )?;
+
+        let result = services::ContractFunctionResult {
+            contract_id: Some(ContractId::from(3).to_protobuf()),
+            contract_call_result: CALL_RESULT.to_vec(),
+            ..Default::default()
+        };
+
+        let result = ContractFunctionResult::from_protobuf(result)?;
+        let outputs = result.decode_outputs(abi.function("getResults")?)?;
+
+        assert_eq!(outputs["small"], AbiValue::from(u32::MAX));
+        assert_eq!(outputs["max"], AbiValue::Int((BigInt::from(1) << 255) - 1));
+        assert_eq!(
+            outputs["owner"].as_address().unwrap().to_bytes(),
+            hex!("11223344556677889900aabbccddeeff00112233")
+        );
+        assert_eq!(outputs["big"], AbiValue::Uint((BigUint::from(1_u8) << 256) - 1_u32));
+        assert_eq!(outputs["greeting"].as_str(), Some("Hello, world!"));
+        assert_eq!(outputs["again"].as_str(), Some("Hello, world, again!"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn tuple_results() -> crate::Result<()> {
+        let result = services::ContractFunctionResult {
+            contract_id: Some(ContractId::from(3).to_protobuf()),
+            contract_call_result: hex!(
+                // `(uint256,bool)`, in place.
+                "0000000000000000000000000000000000000000000000000000000000000007"
+                "0000000000000000000000000000000000000000000000000000000000000001"
+                // offset of `(string,uint8[2])[]`.
+                "0000000000000000000000000000000000000000000000000000000000000060"
+                "0000000000000000000000000000000000000000000000000000000000000001"
+                "0000000000000000000000000000000000000000000000000000000000000020"
+                "0000000000000000000000000000000000000000000000000000000000000060"
+                "0000000000000000000000000000000000000000000000000000000000000003"
+                "0000000000000000000000000000000000000000000000000000000000000004"
+                "0000000000000000000000000000000000000000000000000000000000000002"
+                "6869000000000000000000000000000000000000000000000000000000000000"
+            )
+            .to_vec(),
+            ..Default::default()
+        };
+
+        let result = ContractFunctionResult::from_protobuf(result)?;
+
+        let values = result.decode(&[
+            "(uint256,bool)".parse::<AbiType>()?,
+            "(string,uint8[2])[]".parse::<AbiType>()?,
+        ])?;
+
+        assert_eq!(values[0], AbiValue::Tuple(vec![7_u8.into(), true.into()]));
+        assert_eq!(
+            values[1],
+            AbiValue::Array(vec![AbiValue::Tuple(vec![
+                "hi".into(),
+                AbiValue::FixedArray(vec![3_u8.into(), 4_u8.into()]),
+            ])])
+        );
+
+        // `get_value` only works with slots, which the static tuple breaks,
+        // but the first slot is still the `uint256`.
+        assert_eq!(result.get_value(0, &AbiType::Uint(256))?, AbiValue::from(7_u8));
+
+        Ok(())
+    }
+
+    #[test]
+    fn derived_results() -> crate::Result<()> {
+        #[derive(Debug, PartialEq, AbiDecode)]
+        struct Balance {
+            amount: u64,
+            frozen: bool,
+        }
+
+        let result = services::ContractFunctionResult {
+            contract_id: Some(ContractId::from(3).to_protobuf()),
+            contract_call_result: hex!(
+                "0000000000000000000000000000000000000000000000000000000000000007"
+                "0000000000000000000000000000000000000000000000000000000000000001"
+            )
+            .to_vec(),
+            ..Default::default()
+        };
+
+        let result = ContractFunctionResult::from_protobuf(result)?;
+
+        // a struct with a field for each return value.
+        assert_eq!(result.decode_as::<Balance>()?, Balance { amount: 7, frozen: true });
+        assert_eq!(result.decode_as::<(u64, bool)>()?, (7, true));
+        // the first return value alone.
+        assert_eq!(result.decode_as::<u8>()?, 7);
+
+        // `bytes40` isn't an ABI type.
+        assert_matches!(result.decode_as::<AbiFixedBytes<40>>(), Err(Error::AbiDecode(_)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn revert_reason() -> crate::Result<()> {
+        let result = services::ContractFunctionResult {
+            contract_id: Some(ContractId::from(3).to_protobuf()),
+            error_message: "CONTRACT_REVERT_EXECUTED".to_owned(),
+            contract_call_result: hex!(
+                "08c379a0"
+                "0000000000000000000000000000000000000000000000000000000000000020"
+                "000000000000000000000000000000000000000000000000000000000000000d"
+                "6e6f7420746865206f776e657200000000000000000000000000000000000000"
+            )
+            .to_vec(),
+            ..Default::default()
+        };
+
+        let result = ContractFunctionResult::from_protobuf(result)?;
+
+        assert_eq!(result.revert, Some(ContractRevert::Error("not the owner".to_owned())));
+
+        Ok(())
+    }
+
+    #[test]
+    fn revert_data_in_error_message() -> crate::Result<()> {
+        let result = services::ContractFunctionResult {
+            contract_id: Some(ContractId::from(3).to_protobuf()),
+            error_message:
+                "0x4e487b710000000000000000000000000000000000000000000000000000000000000012"
+                    .to_owned(),
+            ..Default::default()
+        };
+
+        let result = ContractFunctionResult::from_protobuf(result)?;
+
+        assert_eq!(result.revert, Some(ContractRevert::Panic(0x12)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn no_revert_for_other_errors() -> crate::Result<()> {
+        let result = services::ContractFunctionResult {
+            contract_id: Some(ContractId::from(3).to_protobuf()),
+            error_message: "INSUFFICIENT_GAS".to_owned(),
+            ..Default::default()
+        };
+
+        let result = ContractFunctionResult::from_protobuf(result)?;
+
+        assert_eq!(result.error_message.as_deref(), Some("INSUFFICIENT_GAS"));
+        assert_eq!(result.revert, None);
+
+        Ok(())
+    }
+
     #[test]
     fn str_array_results() {
         let result = services::ContractFunctionResult {
@@ -36,8 +36,10 @@ use crate::entity_id::{
 use crate::evm_address::IdEvmAddress;
 use crate::{
     Client,
+    ContractInfoQuery,
     EntityId,
     Error,
+    EvmAddress,
     FromProtobuf,
     LedgerId,
     ToProtobuf,
@@ -149,6 +151,30 @@ impl ContractId {
                 .await
         }
     }
+
+    /// Resolve the contract deployed at `address` in `shard.realm` into its `shard.realm.num` ID,
+    /// with a [`ContractInfoQuery`].
+    ///
+    /// This is how addresses computed ahead of time with [`EvmAddress::create`] or [`EvmAddress::create2`]
+    /// are turned into a usable `ContractId`, once the contract has been deployed.
+    ///
+    /// # Errors
+    /// - a query pre-check error with [`Status::InvalidContractId`](crate::Status::InvalidContractId)
+    ///   if no contract is deployed at `address` (yet).
+    /// - any other error that executing the [`ContractInfoQuery`] can return.
+    pub async fn from_evm_address_deployed(
+        client: &Client,
+        shard: u64,
+        realm: u64,
+        address: EvmAddress,
+    ) -> crate::Result<Self> {
+        let info = ContractInfoQuery::new()
+            .contract_id(Self::from_evm_address_bytes(shard, realm, address.to_bytes()))
+            .execute(client)
+            .await?;
+
+        Ok(info.contract_id)
+    }
 }
 
 impl ValidateChecksums for ContractId {
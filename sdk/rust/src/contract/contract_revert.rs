@@ -0,0 +1,249 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::fmt;
+
+use crate::abi::codec;
+use crate::{
+    Abi,
+    AbiType,
+    AbiValues,
+};
+
+/// The selector of `Error(string)`, used by `revert("reason")` and `require(condition, "reason")`.
+const ERROR_SELECTOR: [u8; 4] = [0x08, 0xc3, 0x79, 0xa0];
+
+/// The selector of `Panic(uint256)`, used by failed `assert`s, arithmetic overflow, and the like.
+const PANIC_SELECTOR: [u8; 4] = [0x4e, 0x48, 0x7b, 0x71];
+
+/// Why a contract call or execution reverted, decoded from the revert data the contract returned.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ContractRevert {
+    /// The contract reverted with a reason string (`Error(string)`).
+    Error(String),
+
+    /// The contract panicked (`Panic(uint256)`) with the given code,
+    /// see [`panic_description`](Self::panic_description).
+    Panic(u64),
+
+    /// The contract reverted with a custom error declared in its ABI.
+    Custom {
+        /// The name of the error.
+        name: String,
+
+        /// The parameters of the error.
+        values: AbiValues,
+    },
+
+    /// The revert data couldn't be decoded.
+    ///
+    /// This is also the result for custom errors when no [`Abi`] declaring them was provided,
+    /// use [`with_abi`](Self::with_abi) to decode those.
+    Unknown(Vec<u8>),
+}
+
+impl ContractRevert {
+    /// Decode `data` as a standard `Error(string)` or `Panic(uint256)`.
+    #[must_use]
+    pub fn decode(data: &[u8]) -> Self {
+        let (selector, args) = match data {
+            [a, b, c, d, args @ ..] => ([*a, *b, *c, *d], args),
+            _ => return Self::Unknown(data.to_vec()),
+        };
+
+        let decoded = match selector {
+            ERROR_SELECTOR => codec::decode(&[AbiType::String], args)
+                .ok()
+                .and_then(|it| it[0].as_str().map(|it| Self::Error(it.to_owned()))),
+
+            PANIC_SELECTOR => codec::decode(&[AbiType::Uint(256)], args)
+                .ok()
+                .and_then(|it| it[0].as_uint().and_then(|it| u64::try_from(it).ok()))
+                .map(Self::Panic),
+
+            _ => None,
+        };
+
+        decoded.unwrap_or_else(|| Self::Unknown(data.to_vec()))
+    }
+
+    /// Decode `data` as a standard `Error(string)` or `Panic(uint256)`, or as a custom error declared in `abi`.
+    #[must_use]
+    pub fn decode_with_abi(data: &[u8], abi: &Abi) -> Self {
+        Self::decode(data).with_abi(abi)
+    }
+
+    /// Try to decode [`Unknown`](Self::Unknown) revert data as a custom error declared in `abi`.
+    ///
+    /// Any other revert is returned as is.
+    #[must_use]
+    pub fn with_abi(self, abi: &Abi) -> Self {
+        let data = match self {
+            Self::Unknown(data) => data,
+            _ => return self,
+        };
+
+        let error = match data.get(..4) {
+            Some(selector) => abi.errors().iter().find(|it| it.selector() == selector),
+            None => None,
+        };
+
+        match error.map(|it| (it, it.decode(&data))) {
+            Some((error, Ok(values))) => Self::Custom { name: error.name.clone(), values },
+            _ => Self::Unknown(data),
+        }
+    }
+
+    /// Returns what a [`Panic`](Self::Panic) code means, as documented by Solidity.
+    #[must_use]
+    pub fn panic_description(code: u64) -> Option<&'static str> {
+        let description = match code {
+            0x00 => "generic compiler inserted panic",
+            0x01 => "assertion failed",
+            0x11 => "arithmetic overflow or underflow",
+            0x12 => "division or modulo by zero",
+            0x21 => "conversion to an invalid enum value",
+            0x22 => "access to an incorrectly encoded storage byte array",
+            0x31 => "`pop()` on an empty array",
+            0x32 => "array index out of bounds",
+            0x41 => "too much memory allocated",
+            0x51 => "call to a zero-initialized internal function",
+            _ => return None,
+        };
+
+        Some(description)
+    }
+}
+
+impl fmt::Display for ContractRevert {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Error(reason) => write!(f, "reverted with reason `{reason}`"),
+            Self::Panic(code) => {
+                write!(f, "panicked with code 0x{code:02x}")?;
+
+                match Self::panic_description(*code) {
+                    Some(description) => write!(f, " ({description})"),
+                    None => Ok(()),
+                }
+            }
+            Self::Custom { name, values } => {
+                write!(
+                    f,
+                    "reverted with custom error `{name}`: {:?}",
+                    values.iter().collect::<Vec<_>>()
+                )
+            }
+            Self::Unknown(data) if data.is_empty() => f.write_str("reverted without data"),
+            Self::Unknown(data) => write!(f, "reverted with data 0x{}", hex::encode(data)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hex_literal::hex;
+
+    use crate::{
+        Abi,
+        AbiValue,
+        ContractRevert,
+    };
+
+    const REASON: [u8; 100] = hex!(
+        "08c379a0"
+        "0000000000000000000000000000000000000000000000000000000000000020"
+        "000000000000000000000000000000000000000000000000000000000000000d"
+        "6e6f7420746865206f776e657200000000000000000000000000000000000000"
+    );
+
+    #[test]
+    fn decode_error() {
+        assert_eq!(
+            ContractRevert::decode(&REASON),
+            ContractRevert::Error("not the owner".to_owned())
+        );
+    }
+
+    #[test]
+    fn decode_panic() {
+        let revert = ContractRevert::decode(&hex!(
+            "4e487b71"
+            "0000000000000000000000000000000000000000000000000000000000000011"
+        ));
+
+        assert_eq!(revert, ContractRevert::Panic(0x11));
+        assert_eq!(
+            revert.to_string(),
+            "panicked with code 0x11 (arithmetic overflow or underflow)"
+        );
+    }
+
+    #[test]
+    fn decode_unknown() {
+        assert_eq!(ContractRevert::decode(&[]), ContractRevert::Unknown(Vec::new()));
+        assert_eq!(
+            ContractRevert::decode(&REASON[..40]),
+            ContractRevert::Unknown(REASON[..40].to_vec())
+        );
+    }
+
+    #[test]
+    fn decode_custom() -> crate::Result<()> {
+        let abi = Abi::from_json(
+            r#"[{
+                "type": "error",
+                "name": "InsufficientBalance",
+                "inputs": [
+                    { "name": "available", "type": "uint256" },
+                    { "name": "required", "type": "uint256" }
+                ]
+            }]"#,
+        )?;
+
+        let error = abi.error("InsufficientBalance")?;
+
+        let mut data = error.selector().to_vec();
+        data.extend_from_slice(&hex!(
+            "0000000000000000000000000000000000000000000000000000000000000005"
+            "000000000000000000000000000000000000000000000000000000000000000a"
+        ));
+
+        let revert = ContractRevert::decode_with_abi(&data, &abi);
+
+        match revert {
+            ContractRevert::Custom { name, values } => {
+                assert_eq!(name, "InsufficientBalance");
+                assert_eq!(values["available"], AbiValue::from(5_u8));
+                assert_eq!(values["required"], AbiValue::from(10_u8));
+            }
+            _ => panic!("expected a custom error, got {revert:?}"),
+        }
+
+        // standard errors are unaffected by the ABI.
+        assert_eq!(
+            ContractRevert::decode_with_abi(&REASON, &abi),
+            ContractRevert::Error("not the owner".to_owned())
+        );
+
+        Ok(())
+    }
+}
@@ -29,6 +29,7 @@ use tonic::transport::Channel;
 use crate::protobuf::FromProtobuf;
 use crate::staked_id::StakedId;
 use crate::transaction::{
+    validate,
     AnyTransactionData,
     ChunkInfo,
     ToSchedulableTransactionDataProtobuf,
@@ -227,7 +228,15 @@ impl ContractUpdateTransaction {
     }
 }
 
-impl TransactionData for ContractUpdateTransactionData {}
+impl TransactionData for ContractUpdateTransactionData {
+    fn validate(&self) -> crate::Result<()> {
+        validate::require("contract_id", &self.contract_id)?;
+        validate::optional_update_key("admin_key", &self.admin_key)?;
+        validate::memo("contract_memo", self.contract_memo.as_deref().unwrap_or_default())?;
+
+        Ok(())
+    }
+}
 
 impl TransactionExecute for ContractUpdateTransactionData {
     fn execute(
@@ -20,6 +20,7 @@
 
 mod contract_bytecode_query;
 mod contract_call_query;
+mod contract_create_flow;
 mod contract_create_transaction;
 mod contract_delete_transaction;
 mod contract_execute_transaction;
@@ -30,12 +31,14 @@ mod contract_id;
 mod contract_info;
 mod contract_info_query;
 mod contract_log_info;
+mod contract_revert;
 mod contract_update_transaction;
 
 pub use contract_bytecode_query::ContractBytecodeQuery;
 pub(crate) use contract_bytecode_query::ContractBytecodeQueryData;
 pub use contract_call_query::ContractCallQuery;
 pub(crate) use contract_call_query::ContractCallQueryData;
+pub use contract_create_flow::ContractCreateFlow;
 pub use contract_create_transaction::ContractCreateTransaction;
 pub(crate) use contract_create_transaction::ContractCreateTransactionData;
 pub use contract_delete_transaction::ContractDeleteTransaction;
@@ -44,10 +47,12 @@ pub use contract_execute_transaction::ContractExecuteTransaction;
 pub(crate) use contract_execute_transaction::ContractExecuteTransactionData;
 pub use contract_function_parameters::ContractFunctionParameters;
 pub use contract_function_result::ContractFunctionResult;
+pub(crate) use contract_function_selector::ContractFunctionSelector;
 pub use contract_id::ContractId;
 pub use contract_info::ContractInfo;
 pub use contract_info_query::ContractInfoQuery;
 pub(crate) use contract_info_query::ContractInfoQueryData;
 pub use contract_log_info::ContractLogInfo;
+pub use contract_revert::ContractRevert;
 pub use contract_update_transaction::ContractUpdateTransaction;
 pub(crate) use contract_update_transaction::ContractUpdateTransactionData;
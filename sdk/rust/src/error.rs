@@ -24,8 +24,11 @@ use std::result::Result as StdResult;
 use crate::entity_id::Checksum;
 use crate::{
     AccountId,
+    ContractRevert,
     Hbar,
+    NftId,
     Status,
+    TokenId,
     TransactionId,
 };
 
@@ -179,6 +182,10 @@ pub enum Error {
     #[error("Failed to derive a key: {0}")]
     KeyDerive(#[source] BoxStdError),
 
+    /// Failed to encrypt a [`PrivateKey`](crate::PrivateKey), usually due to invalid encryption parameters.
+    #[error("failed to encrypt a key: {0}")]
+    KeyEncrypt(#[source] BoxStdError),
+
     /// Failed to parse a [`Mnemonic`](crate::Mnemonic) due to the given `reason`.
     ///
     /// the `Mnemonic` is provided because invalid `Mnemonics`
@@ -241,6 +248,83 @@ pub enum Error {
     #[error("failed to verify a signature: {0}")]
     SignatureVerify(#[source] BoxStdError),
 
+    /// The hbar or fungible token transfers in a [`TransferTransaction`](crate::TransferTransaction) don't sum to zero.
+    #[error(
+        "transfers of {} must sum to zero, but sum to `{sum}`",
+        token_id.map_or_else(|| "hbar".to_owned(), |it| format!("token `{it}`"))
+    )]
+    TransfersNotBalanced {
+        /// The token whose transfers don't balance, or `None` for hbar transfers.
+        token_id: Option<TokenId>,
+        /// What the transfers actually sum to (in tinybars or the token's smallest denomination).
+        sum: i128,
+    },
+
+    /// The transfers for an account in a [`TransferTransaction`](crate::TransferTransaction) overflow when merged.
+    #[error(
+        "transfers of {} for account `{account_id}` overflow when merged",
+        token_id.map_or_else(|| "hbar".to_owned(), |it| format!("token `{it}`"))
+    )]
+    TransferAmountOverflow {
+        /// The token whose transfers overflow, or `None` for hbar transfers.
+        token_id: Option<TokenId>,
+        /// The account whose transfers overflow.
+        account_id: AccountId,
+    },
+
+    /// An NFT transfer in a [`TransferTransaction`](crate::TransferTransaction) is inconsistent with the others.
+    #[error("inconsistent transfer of NFT `{nft_id}`: {reason}")]
+    NftTransferInconsistent {
+        /// The NFT with the inconsistent transfer.
+        nft_id: NftId,
+        /// Why the transfer is inconsistent.
+        reason: &'static str,
+    },
+
+    /// A field of a transaction failed local validation before the transaction was frozen.
+    #[error("invalid transaction field `{field}`: {reason}")]
+    InvalidTransactionField {
+        /// The name of the offending field.
+        field: &'static str,
+        /// Why the field is invalid.
+        reason: String,
+    },
+
+    /// A contract call or execution reverted.
+    ///
+    /// Custom errors can't be decoded without the contract's ABI,
+    /// use [`ContractRevert::with_abi`] to decode them.
+    #[error("contract failed with status `{status:?}` and {revert}")]
+    ContractRevert {
+        /// The status of the failed call or execution.
+        status: Status,
+        /// The ID of the transaction that executed the contract, or paid for the query that called it.
+        transaction_id: Option<TransactionId>,
+        /// Why the contract reverted.
+        revert: Box<ContractRevert>,
+    },
+
+    /// Failed to parse a Solidity contract ABI or ABI type.
+    #[error("failed to parse ABI: {0}")]
+    AbiParse(#[source] BoxStdError),
+
+    /// Failed to encode values with the Solidity contract ABI.
+    #[error("failed to ABI encode values: {0}")]
+    AbiEncode(#[source] BoxStdError),
+
+    /// Failed to decode data with the Solidity contract ABI.
+    #[error("failed to ABI decode data: {0}")]
+    AbiDecode(#[source] BoxStdError),
+
+    /// The requested item wasn't found in (or was ambiguous in) a Solidity contract ABI.
+    #[error("no unique {kind} `{name}` in ABI")]
+    AbiItemNotFound {
+        /// The kind of item (EG `function`).
+        kind: &'static str,
+        /// The name or signature that was looked up.
+        name: String,
+    },
+
     /// Failed to parse a request from JSON.
     #[cfg(feature = "ffi")]
     #[error("failed to parse a request from JSON: {0}")]
@@ -260,10 +344,26 @@ impl Error {
         Self::KeyDerive(error.into())
     }
 
+    pub(crate) fn key_encrypt<E: Into<BoxStdError>>(error: E) -> Self {
+        Self::KeyEncrypt(error.into())
+    }
+
     pub(crate) fn basic_parse<E: Into<BoxStdError>>(error: E) -> Self {
         Self::BasicParse(error.into())
     }
 
+    pub(crate) fn abi_parse<E: Into<BoxStdError>>(error: E) -> Self {
+        Self::AbiParse(error.into())
+    }
+
+    pub(crate) fn abi_encode<E: Into<BoxStdError>>(error: E) -> Self {
+        Self::AbiEncode(error.into())
+    }
+
+    pub(crate) fn abi_decode<E: Into<BoxStdError>>(error: E) -> Self {
+        Self::AbiDecode(error.into())
+    }
+
     #[cfg(feature = "ffi")]
     pub(crate) fn request_parse<E: Into<BoxStdError>>(error: E) -> Self {
         Self::RequestParse(error.into())
@@ -276,6 +376,13 @@ impl Error {
     pub(crate) fn signature_verify(error: impl Into<BoxStdError>) -> Self {
         Self::SignatureVerify(error.into())
     }
+
+    pub(crate) fn invalid_transaction_field(
+        field: &'static str,
+        reason: impl Into<String>,
+    ) -> Self {
+        Self::InvalidTransactionField { field, reason: reason.into() }
+    }
 }
 
 /// Failed to parse a mnemonic.
@@ -326,4 +433,8 @@ pub enum MnemonicEntropyError {
     /// Used a passphrase with a legacy [`Mnemonic`](crate::Mnemonic).
     #[error("used a passphrase with a legacy mnemonic")]
     LegacyWithPassphrase,
+
+    /// Tried to create a [`Mnemonic`](crate::Mnemonic) from entropy of an unexpected length.
+    #[error("bad entropy length: expected `16` or `32` bytes, found `{0}` bytes")]
+    BadEntropyLength(usize),
 }
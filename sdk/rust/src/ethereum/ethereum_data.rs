@@ -0,0 +1,479 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use super::rlp::RlpItem;
+use crate::{
+    Error,
+    EvmAddress,
+};
+
+/// The transaction type byte prefixing an EIP-2930 transaction.
+pub(super) const EIP_2930_TYPE: u8 = 0x01;
+
+/// The transaction type byte prefixing an EIP-1559 transaction.
+pub(super) const EIP_1559_TYPE: u8 = 0x02;
+
+/// The data of a signed Ethereum transaction, as submitted in an [`EthereumTransaction`](crate::EthereumTransaction).
+///
+/// Integer fields are kept as the minimal big-endian byte strings they're encoded as, so that
+/// decoding and re-encoding a transaction reproduces it exactly (and its signature remains valid).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum EthereumData {
+    /// A legacy (type 0) transaction, optionally with EIP-155 replay protection.
+    Legacy(LegacyEthereumData),
+
+    /// An EIP-2930 (type 1) transaction.
+    Eip2930(Eip2930EthereumData),
+
+    /// An EIP-1559 (type 2) transaction.
+    Eip1559(Eip1559EthereumData),
+}
+
+/// The data of a legacy (type 0) Ethereum transaction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LegacyEthereumData {
+    /// The transaction's nonce.
+    pub nonce: Vec<u8>,
+
+    /// The price for one unit of gas, in weibars.
+    pub gas_price: Vec<u8>,
+
+    /// The maximum amount of gas the transaction may use.
+    pub gas_limit: Vec<u8>,
+
+    /// The receiver of the transaction, empty for contract creation.
+    pub to: Vec<u8>,
+
+    /// The amount of weibars transferred.
+    pub value: Vec<u8>,
+
+    /// The call data (or init code, for contract creation).
+    pub call_data: Vec<u8>,
+
+    /// The `v` value of the signature (including the chain ID, as per EIP-155).
+    pub v: Vec<u8>,
+
+    /// The `r` value of the signature.
+    pub r: Vec<u8>,
+
+    /// The `s` value of the signature.
+    pub s: Vec<u8>,
+}
+
+/// An entry in the access list of an EIP-2930 or EIP-1559 transaction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccessListItem {
+    /// The address the transaction plans to access.
+    pub address: EvmAddress,
+
+    /// The storage keys of `address` the transaction plans to access.
+    pub storage_keys: Vec<[u8; 32]>,
+}
+
+/// The data of an EIP-2930 (type 1) Ethereum transaction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Eip2930EthereumData {
+    /// The ID of the chain the transaction is for.
+    pub chain_id: Vec<u8>,
+
+    /// The transaction's nonce.
+    pub nonce: Vec<u8>,
+
+    /// The price for one unit of gas, in weibars.
+    pub gas_price: Vec<u8>,
+
+    /// The maximum amount of gas the transaction may use.
+    pub gas_limit: Vec<u8>,
+
+    /// The receiver of the transaction, empty for contract creation.
+    pub to: Vec<u8>,
+
+    /// The amount of weibars transferred.
+    pub value: Vec<u8>,
+
+    /// The call data (or init code, for contract creation).
+    pub call_data: Vec<u8>,
+
+    /// The addresses and storage keys the transaction plans to access.
+    pub access_list: Vec<AccessListItem>,
+
+    /// The recovery ID (`y parity`) of the signature.
+    pub recovery_id: Vec<u8>,
+
+    /// The `r` value of the signature.
+    pub r: Vec<u8>,
+
+    /// The `s` value of the signature.
+    pub s: Vec<u8>,
+}
+
+/// The data of an EIP-1559 (type 2) Ethereum transaction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Eip1559EthereumData {
+    /// The ID of the chain the transaction is for.
+    pub chain_id: Vec<u8>,
+
+    /// The transaction's nonce.
+    pub nonce: Vec<u8>,
+
+    /// The maximum priority fee per unit of gas, in weibars.
+    pub max_priority_gas: Vec<u8>,
+
+    /// The maximum total fee per unit of gas, in weibars.
+    pub max_gas: Vec<u8>,
+
+    /// The maximum amount of gas the transaction may use.
+    pub gas_limit: Vec<u8>,
+
+    /// The receiver of the transaction, empty for contract creation.
+    pub to: Vec<u8>,
+
+    /// The amount of weibars transferred.
+    pub value: Vec<u8>,
+
+    /// The call data (or init code, for contract creation).
+    pub call_data: Vec<u8>,
+
+    /// The addresses and storage keys the transaction plans to access.
+    pub access_list: Vec<AccessListItem>,
+
+    /// The recovery ID (`y parity`) of the signature.
+    pub recovery_id: Vec<u8>,
+
+    /// The `r` value of the signature.
+    pub r: Vec<u8>,
+
+    /// The `s` value of the signature.
+    pub s: Vec<u8>,
+}
+
+impl EthereumData {
+    /// Decode a signed Ethereum transaction from its (RLP based) encoding.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `bytes` isn't a valid legacy, EIP-2930, or EIP-1559 transaction.
+    pub fn from_bytes(bytes: &[u8]) -> crate::Result<Self> {
+        match bytes.split_first() {
+            Some((&EIP_2930_TYPE, rest)) => {
+                Eip2930EthereumData::from_rlp(RlpItem::decode(rest)?).map(Self::Eip2930)
+            }
+            Some((&EIP_1559_TYPE, rest)) => {
+                Eip1559EthereumData::from_rlp(RlpItem::decode(rest)?).map(Self::Eip1559)
+            }
+            Some((first, _)) if *first >= 0xc0 => {
+                LegacyEthereumData::from_rlp(RlpItem::decode(bytes)?).map(Self::Legacy)
+            }
+            Some((first, _)) => Err(Error::basic_parse(format!(
+                "unsupported Ethereum transaction type `{first:#04x}`"
+            ))),
+            None => Err(Error::basic_parse("empty Ethereum transaction")),
+        }
+    }
+
+    /// Encode this transaction the way it's submitted to the network.
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        match self {
+            Self::Legacy(it) => it.to_rlp().encode(),
+            Self::Eip2930(it) => [&[EIP_2930_TYPE][..], &it.to_rlp().encode()].concat(),
+            Self::Eip1559(it) => [&[EIP_1559_TYPE][..], &it.to_rlp().encode()].concat(),
+        }
+    }
+
+    /// Returns the call data of this transaction.
+    #[must_use]
+    pub fn call_data(&self) -> &[u8] {
+        match self {
+            Self::Legacy(it) => &it.call_data,
+            Self::Eip2930(it) => &it.call_data,
+            Self::Eip1559(it) => &it.call_data,
+        }
+    }
+
+    /// Returns a mutable reference to the call data of this transaction.
+    pub fn call_data_mut(&mut self) -> &mut Vec<u8> {
+        match self {
+            Self::Legacy(it) => &mut it.call_data,
+            Self::Eip2930(it) => &mut it.call_data,
+            Self::Eip1559(it) => &mut it.call_data,
+        }
+    }
+}
+
+/// Splits an RLP list into exactly `N` byte strings, or fails with a message naming `what`.
+fn fields<const N: usize>(item: RlpItem, what: &str) -> crate::Result<[RlpItem; N]> {
+    let items = item.into_list()?;
+    let len = items.len();
+
+    items
+        .try_into()
+        .map_err(|_| Error::basic_parse(format!("{what}: expected {N} fields, found {len}")))
+}
+
+impl LegacyEthereumData {
+    fn from_rlp(item: RlpItem) -> crate::Result<Self> {
+        let [nonce, gas_price, gas_limit, to, value, call_data, v, r, s] =
+            fields(item, "legacy Ethereum transaction")?;
+
+        Ok(Self {
+            nonce: nonce.into_bytes()?,
+            gas_price: gas_price.into_bytes()?,
+            gas_limit: gas_limit.into_bytes()?,
+            to: to.into_bytes()?,
+            value: value.into_bytes()?,
+            call_data: call_data.into_bytes()?,
+            v: v.into_bytes()?,
+            r: r.into_bytes()?,
+            s: s.into_bytes()?,
+        })
+    }
+
+    fn to_rlp(&self) -> RlpItem {
+        RlpItem::List(vec![
+            self.nonce.clone().into(),
+            self.gas_price.clone().into(),
+            self.gas_limit.clone().into(),
+            self.to.clone().into(),
+            self.value.clone().into(),
+            self.call_data.clone().into(),
+            self.v.clone().into(),
+            self.r.clone().into(),
+            self.s.clone().into(),
+        ])
+    }
+}
+
+impl AccessListItem {
+    fn list_from_rlp(item: RlpItem) -> crate::Result<Vec<Self>> {
+        item.into_list()?.into_iter().map(Self::from_rlp).collect()
+    }
+
+    fn from_rlp(item: RlpItem) -> crate::Result<Self> {
+        let [address, storage_keys] = fields(item, "access list item")?;
+
+        let address = EvmAddress::try_from(address.into_bytes()?)?;
+
+        let storage_keys = storage_keys
+            .into_list()?
+            .into_iter()
+            .map(|key| {
+                <[u8; 32]>::try_from(key.into_bytes()?).map_err(|it| {
+                    Error::basic_parse(format!(
+                        "access list storage keys must be 32 bytes, found {}",
+                        it.len()
+                    ))
+                })
+            })
+            .collect::<crate::Result<_>>()?;
+
+        Ok(Self { address, storage_keys })
+    }
+
+    pub(super) fn list_to_rlp(list: &[Self]) -> RlpItem {
+        RlpItem::List(list.iter().map(Self::to_rlp).collect())
+    }
+
+    fn to_rlp(&self) -> RlpItem {
+        RlpItem::List(vec![
+            self.address.to_bytes()[..].into(),
+            RlpItem::List(self.storage_keys.iter().map(|it| it[..].into()).collect()),
+        ])
+    }
+}
+
+impl Eip2930EthereumData {
+    fn from_rlp(item: RlpItem) -> crate::Result<Self> {
+        let [chain_id, nonce, gas_price, gas_limit, to, value, call_data, access_list, recovery_id, r, s] =
+            fields(item, "EIP-2930 Ethereum transaction")?;
+
+        Ok(Self {
+            chain_id: chain_id.into_bytes()?,
+            nonce: nonce.into_bytes()?,
+            gas_price: gas_price.into_bytes()?,
+            gas_limit: gas_limit.into_bytes()?,
+            to: to.into_bytes()?,
+            value: value.into_bytes()?,
+            call_data: call_data.into_bytes()?,
+            access_list: AccessListItem::list_from_rlp(access_list)?,
+            recovery_id: recovery_id.into_bytes()?,
+            r: r.into_bytes()?,
+            s: s.into_bytes()?,
+        })
+    }
+
+    fn to_rlp(&self) -> RlpItem {
+        RlpItem::List(vec![
+            self.chain_id.clone().into(),
+            self.nonce.clone().into(),
+            self.gas_price.clone().into(),
+            self.gas_limit.clone().into(),
+            self.to.clone().into(),
+            self.value.clone().into(),
+            self.call_data.clone().into(),
+            AccessListItem::list_to_rlp(&self.access_list),
+            self.recovery_id.clone().into(),
+            self.r.clone().into(),
+            self.s.clone().into(),
+        ])
+    }
+}
+
+impl Eip1559EthereumData {
+    fn from_rlp(item: RlpItem) -> crate::Result<Self> {
+        let [chain_id, nonce, max_priority_gas, max_gas, gas_limit, to, value, call_data, access_list, recovery_id, r, s] =
+            fields(item, "EIP-1559 Ethereum transaction")?;
+
+        Ok(Self {
+            chain_id: chain_id.into_bytes()?,
+            nonce: nonce.into_bytes()?,
+            max_priority_gas: max_priority_gas.into_bytes()?,
+            max_gas: max_gas.into_bytes()?,
+            gas_limit: gas_limit.into_bytes()?,
+            to: to.into_bytes()?,
+            value: value.into_bytes()?,
+            call_data: call_data.into_bytes()?,
+            access_list: AccessListItem::list_from_rlp(access_list)?,
+            recovery_id: recovery_id.into_bytes()?,
+            r: r.into_bytes()?,
+            s: s.into_bytes()?,
+        })
+    }
+
+    fn to_rlp(&self) -> RlpItem {
+        RlpItem::List(vec![
+            self.chain_id.clone().into(),
+            self.nonce.clone().into(),
+            self.max_priority_gas.clone().into(),
+            self.max_gas.clone().into(),
+            self.gas_limit.clone().into(),
+            self.to.clone().into(),
+            self.value.clone().into(),
+            self.call_data.clone().into(),
+            AccessListItem::list_to_rlp(&self.access_list),
+            self.recovery_id.clone().into(),
+            self.r.clone().into(),
+            self.s.clone().into(),
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+    use hex_literal::hex;
+
+    use super::{
+        AccessListItem,
+        Eip2930EthereumData,
+        EthereumData,
+    };
+    use crate::EvmAddress;
+
+    // the EIP-155 example transaction.
+    const LEGACY: [u8; 110] = hex!(
+        "f86c098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a7640000"
+        "8025a028ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276a067cbe9d8997f"
+        "761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83"
+    );
+
+    const EIP_1559: [u8; 115] = hex!(
+        "02f87082012a022f2f83018000947e3a9eaf9bcc39e2ffa38eb30bf7a93feacbc181880de0b6b3a76400"
+        "0083123456c001a0df48f2efd10421811de2bfb125ab75b2d3c44139c4642837fb1fccce911fd479a01a"
+        "af7ae92bee896651dfc9d99ae422a296bf5d9f1ca49b2d96d82b79eb112d66"
+    );
+
+    #[test]
+    fn legacy_round_trip() -> crate::Result<()> {
+        let data = EthereumData::from_bytes(&LEGACY)?;
+
+        let legacy = assert_matches!(&data, EthereumData::Legacy(it) => it);
+
+        assert_eq!(legacy.nonce, [0x09]);
+        assert_eq!(legacy.gas_limit, hex!("5208"));
+        assert_eq!(legacy.to, [0x35; 20]);
+        assert_eq!(legacy.v, [0x25]);
+        assert!(legacy.call_data.is_empty());
+
+        assert_eq!(data.to_bytes(), LEGACY);
+
+        Ok(())
+    }
+
+    #[test]
+    fn eip_1559_round_trip() -> crate::Result<()> {
+        let data = EthereumData::from_bytes(&EIP_1559)?;
+
+        let eip_1559 = assert_matches!(&data, EthereumData::Eip1559(it) => it);
+
+        assert_eq!(eip_1559.chain_id, hex!("012a"));
+        assert_eq!(eip_1559.call_data, hex!("123456"));
+        assert_eq!(eip_1559.recovery_id, [0x01]);
+        assert!(eip_1559.access_list.is_empty());
+
+        assert_eq!(data.to_bytes(), EIP_1559);
+
+        Ok(())
+    }
+
+    #[test]
+    fn eip_2930_round_trip() -> crate::Result<()> {
+        let data = EthereumData::Eip2930(Eip2930EthereumData {
+            chain_id: hex!("012a").to_vec(),
+            nonce: Vec::new(),
+            gas_price: hex!("2f").to_vec(),
+            gas_limit: hex!("018000").to_vec(),
+            to: [0x35; 20].to_vec(),
+            value: Vec::new(),
+            call_data: hex!("123456").to_vec(),
+            access_list: vec![AccessListItem {
+                address: EvmAddress::from([0x11; 20]),
+                storage_keys: vec![[0x22; 32]],
+            }],
+            recovery_id: Vec::new(),
+            r: [0x33; 32].to_vec(),
+            s: [0x44; 32].to_vec(),
+        });
+
+        let bytes = data.to_bytes();
+
+        assert_eq!(bytes[0], 0x01);
+        assert_eq!(EthereumData::from_bytes(&bytes)?, data);
+
+        Ok(())
+    }
+
+    #[test]
+    fn call_data_mut() -> crate::Result<()> {
+        let mut data = EthereumData::from_bytes(&EIP_1559)?;
+
+        data.call_data_mut().clear();
+
+        assert!(EthereumData::from_bytes(&data.to_bytes())?.call_data().is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn unsupported_type() {
+        assert_matches!(EthereumData::from_bytes(&hex!("03c0")), Err(crate::Error::BasicParse(_)));
+        assert_matches!(EthereumData::from_bytes(&[]), Err(crate::Error::BasicParse(_)));
+    }
+}
@@ -0,0 +1,272 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use super::EthereumData;
+use crate::file::FileUpload;
+use crate::{
+    AccountId,
+    Client,
+    Error,
+    EthereumTransaction,
+    FileId,
+    TransactionResponse,
+};
+
+/// The largest (encoded) Ethereum transaction that's submitted inline,
+/// anything larger has its call data moved to a file.
+const MAX_ETHEREUM_DATA_SIZE: usize = 5120;
+
+/// Submit an Ethereum transaction, moving its call data to a file if it's too large to submit inline.
+///
+/// If the encoded transaction is larger than the network accepts, the call data is uploaded
+/// (hex encoded) with a [`FileCreateTransaction`](crate::FileCreateTransaction) (and a [`FileAppendTransaction`](crate::FileAppendTransaction) if needed),
+/// and the [`EthereumTransaction`] is submitted with empty call data and that file as its
+/// [`call_data_file_id`](EthereumTransaction::call_data_file_id).
+///
+/// The file is signed for by the client's operator.
+#[derive(Debug, Default, Clone)]
+pub struct EthereumFlow {
+    ethereum_data: Option<EthereumData>,
+    max_gas_allowance_hbar: u64,
+    node_account_ids: Option<Vec<AccountId>>,
+    max_chunks: Option<usize>,
+}
+
+impl EthereumFlow {
+    /// Create a new `EthereumFlow`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the Ethereum transaction to submit.
+    #[must_use]
+    pub fn get_ethereum_data(&self) -> Option<&EthereumData> {
+        self.ethereum_data.as_ref()
+    }
+
+    /// Sets the raw Ethereum transaction to submit (RLP encoded type 0, 1, and 2).
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `data` isn't a valid Ethereum transaction.
+    pub fn ethereum_data(&mut self, data: &[u8]) -> crate::Result<&mut Self> {
+        self.ethereum_data = Some(EthereumData::from_bytes(data)?);
+
+        Ok(self)
+    }
+
+    /// Returns the maximum amount that the payer of the hedera transaction
+    /// is willing to pay to complete the transaction.
+    #[must_use]
+    pub fn get_max_gas_allowance_hbar(&self) -> u64 {
+        self.max_gas_allowance_hbar
+    }
+
+    /// Sets the maximum amount that the payer of the hedera transaction
+    /// is willing to pay to complete the transaction.
+    pub fn max_gas_allowance_hbar(&mut self, allowance: u64) -> &mut Self {
+        self.max_gas_allowance_hbar = allowance;
+        self
+    }
+
+    /// Returns the account IDs of the nodes the transactions may be submitted to.
+    #[must_use]
+    pub fn get_node_account_ids(&self) -> Option<&[AccountId]> {
+        self.node_account_ids.as_deref()
+    }
+
+    /// Sets the account IDs of the nodes the transactions may be submitted to.
+    ///
+    /// Defaults to the full list of nodes configured on the client.
+    pub fn node_account_ids(&mut self, ids: impl IntoIterator<Item = AccountId>) -> &mut Self {
+        self.node_account_ids = Some(ids.into_iter().collect());
+        self
+    }
+
+    /// Returns the maximum number of chunks the [`FileAppendTransaction`](crate::FileAppendTransaction) may use.
+    #[must_use]
+    pub fn get_max_chunks(&self) -> Option<usize> {
+        self.max_chunks
+    }
+
+    /// Sets the maximum number of chunks the [`FileAppendTransaction`](crate::FileAppendTransaction) may use.
+    pub fn max_chunks(&mut self, max_chunks: usize) -> &mut Self {
+        self.max_chunks = Some(max_chunks);
+        self
+    }
+
+    /// Execute the flow, returning the response of the [`EthereumTransaction`].
+    ///
+    /// # Errors
+    /// - [`Error::InvalidTransactionField`] if the Ethereum data isn't set.
+    /// - [`Error::NoPayerAccountOrTransactionId`] if the call data must be moved to a file,
+    ///   but the client has no operator.
+    /// - Any error returned by executing one of the transactions or fetching its receipt.
+    pub async fn execute(&self, client: &Client) -> crate::Result<TransactionResponse> {
+        let ethereum_data = self
+            .ethereum_data
+            .as_ref()
+            .ok_or_else(|| Error::invalid_transaction_field("ethereum_data", "must be set"))?;
+
+        let (ethereum_data, call_data) = split_call_data(ethereum_data);
+
+        let call_data_file_id = match call_data {
+            Some(call_data) => Some(self.file_upload().upload(client, &call_data).await?),
+            None => None,
+        };
+
+        self.make_ethereum_transaction(&ethereum_data, call_data_file_id).execute(client).await
+    }
+
+    fn file_upload(&self) -> FileUpload<'_> {
+        FileUpload {
+            node_account_ids: self.node_account_ids.as_deref(),
+            max_chunks: self.max_chunks,
+        }
+    }
+
+    fn make_ethereum_transaction(
+        &self,
+        ethereum_data: &EthereumData,
+        call_data_file_id: Option<FileId>,
+    ) -> EthereumTransaction {
+        let mut transaction = EthereumTransaction::new();
+        transaction
+            .ethereum_data(ethereum_data.to_bytes())
+            .max_gas_allowance_hbar(self.max_gas_allowance_hbar);
+
+        if let Some(file_id) = call_data_file_id {
+            transaction.call_data_file_id(file_id);
+        }
+
+        if let Some(node_account_ids) = &self.node_account_ids {
+            transaction.node_account_ids(node_account_ids.iter().copied());
+        }
+
+        transaction
+    }
+}
+
+/// Moves the call data out of `ethereum_data` if the encoded transaction is too large to submit inline.
+///
+/// Returns the Ethereum data to submit, and the call data to upload to a file, if any.
+fn split_call_data(ethereum_data: &EthereumData) -> (EthereumData, Option<Vec<u8>>) {
+    let mut ethereum_data = ethereum_data.clone();
+
+    if ethereum_data.to_bytes().len() <= MAX_ETHEREUM_DATA_SIZE {
+        return (ethereum_data, None);
+    }
+
+    let call_data = std::mem::take(ethereum_data.call_data_mut());
+
+    (ethereum_data, Some(call_data))
+}
+
+#[cfg(test)]
+mod tests {
+    use hex_literal::hex;
+
+    use super::{
+        split_call_data,
+        EthereumFlow,
+        MAX_ETHEREUM_DATA_SIZE,
+    };
+    use crate::ethereum::EthereumData;
+    use crate::{
+        AccountId,
+        FileId,
+    };
+
+    const EIP_1559: [u8; 115] = hex!(
+        "02f87082012a022f2f83018000947e3a9eaf9bcc39e2ffa38eb30bf7a93feacbc181880de0b6b3a76400"
+        "0083123456c001a0df48f2efd10421811de2bfb125ab75b2d3c44139c4642837fb1fccce911fd479a01a"
+        "af7ae92bee896651dfc9d99ae422a296bf5d9f1ca49b2d96d82b79eb112d66"
+    );
+
+    /// Returns the EIP-1559 example transaction with its call data grown until it encodes to `size` bytes.
+    fn ethereum_data_of_size(size: usize) -> crate::Result<EthereumData> {
+        let mut data = EthereumData::from_bytes(&EIP_1559)?;
+
+        while data.to_bytes().len() < size {
+            data.call_data_mut().push(0xab);
+        }
+
+        assert_eq!(data.to_bytes().len(), size);
+
+        Ok(data)
+    }
+
+    #[test]
+    fn split_call_data_small() -> crate::Result<()> {
+        let data = ethereum_data_of_size(MAX_ETHEREUM_DATA_SIZE)?;
+
+        let (inline, call_data) = split_call_data(&data);
+
+        assert_eq!(inline, data);
+        assert_eq!(call_data, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn split_call_data_large() -> crate::Result<()> {
+        let data = ethereum_data_of_size(MAX_ETHEREUM_DATA_SIZE + 1)?;
+
+        let (inline, call_data) = split_call_data(&data);
+
+        assert!(inline.call_data().is_empty());
+        assert_eq!(call_data.as_deref(), Some(data.call_data()));
+
+        let mut rehydrated = inline;
+        *rehydrated.call_data_mut() = data.call_data().to_vec();
+        assert_eq!(rehydrated, data);
+
+        Ok(())
+    }
+
+    #[test]
+    fn make_ethereum_transaction() -> crate::Result<()> {
+        let data = ethereum_data_of_size(MAX_ETHEREUM_DATA_SIZE + 1)?;
+        let file_id = FileId::from(1001);
+        let nodes = [AccountId::from(3), AccountId::from(4)];
+
+        let mut flow = EthereumFlow::new();
+        flow.max_gas_allowance_hbar(25).node_account_ids(nodes).max_chunks(3);
+
+        let (inline, _) = split_call_data(&data);
+
+        let transaction = flow.make_ethereum_transaction(&inline, Some(file_id));
+        assert_eq!(transaction.get_ethereum_data(), inline.to_bytes());
+        assert_eq!(transaction.get_call_data_file_id(), Some(file_id));
+        assert_eq!(transaction.get_max_gas_allowance_hbar(), 25);
+        assert_eq!(transaction.get_node_account_ids(), Some(&nodes[..]));
+
+        let transaction = EthereumFlow::new().make_ethereum_transaction(&data, None);
+        assert_eq!(transaction.get_ethereum_data(), data.to_bytes());
+        assert_eq!(transaction.get_call_data_file_id(), None);
+        assert_eq!(transaction.get_node_account_ids(), None);
+
+        let file_upload = flow.file_upload();
+        assert_eq!(file_upload.node_account_ids, Some(&nodes[..]));
+        assert_eq!(file_upload.max_chunks, Some(3));
+
+        Ok(())
+    }
+}
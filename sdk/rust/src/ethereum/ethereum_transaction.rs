@@ -52,11 +52,11 @@ pub struct EthereumTransactionData {
     ethereum_data: Vec<u8>,
 
     /// For large transactions (for example contract create) this should be used to
-    /// set the FileId of an HFS file containing the call_data
-    /// of the ethereum_data. The data in the ethereum_data will be re-written with
-    /// the call_data element as a zero length string with the original contents in
-    /// the referenced file at time of execution. The ethereum_data will need to be
-    /// "rehydrated" with the call_data for signature validation to pass.
+    /// set the [`FileId`] of an HFS file containing the `call_data`
+    /// of the `ethereum_data`. The data in the `ethereum_data` will be re-written with
+    /// the `call_data` element as a zero length string with the original contents in
+    /// the referenced file at time of execution. The `ethereum_data` will need to be
+    /// "rehydrated" with the `call_data` for signature validation to pass.
     call_data_file_id: Option<FileId>,
 
     /// The maximum amount that the payer of the hedera transaction
@@ -111,7 +111,15 @@ impl EthereumTransaction {
     }
 }
 
-impl TransactionData for EthereumTransactionData {}
+impl TransactionData for EthereumTransactionData {
+    fn validate(&self) -> crate::Result<()> {
+        if self.ethereum_data.is_empty() {
+            return Err(Error::invalid_transaction_field("ethereum_data", "must not be empty"));
+        }
+
+        Ok(())
+    }
+}
 
 impl TransactionExecute for EthereumTransactionData {
     fn execute(
@@ -0,0 +1,44 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+mod ethereum_data;
+mod ethereum_flow;
+mod ethereum_transaction;
+pub(crate) mod rlp;
+pub(crate) mod signed_message;
+mod typed_data;
+mod unsigned_ethereum_data;
+
+pub use ethereum_data::{
+    AccessListItem,
+    Eip1559EthereumData,
+    Eip2930EthereumData,
+    EthereumData,
+    LegacyEthereumData,
+};
+pub use ethereum_flow::EthereumFlow;
+pub use ethereum_transaction::EthereumTransaction;
+pub(crate) use ethereum_transaction::EthereumTransactionData;
+pub use typed_data::TypedData;
+pub use unsigned_ethereum_data::{
+    UnsignedEip1559EthereumData,
+    UnsignedEip2930EthereumData,
+    UnsignedLegacyEthereumData,
+};
@@ -0,0 +1,279 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+//! A minimal implementation of Ethereum's Recursive Length Prefix (RLP) serialization.
+//!
+//! See <https://ethereum.org/en/developers/docs/data-structures-and-encoding/rlp/>.
+
+use crate::Error;
+
+/// The maximum depth of nested lists accepted when decoding.
+///
+/// Ethereum transactions only nest a few levels deep (access lists),
+/// this just prevents malicious input from overflowing the stack.
+const MAX_DEPTH: usize = 16;
+
+/// A single RLP item, either a byte string or a list of items.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum RlpItem {
+    Bytes(Vec<u8>),
+    List(Vec<RlpItem>),
+}
+
+impl RlpItem {
+    /// Encodes an unsigned integer as the shortest big-endian byte string (empty for zero).
+    pub(crate) fn uint(value: impl Into<u128>) -> Self {
+        Self::Bytes(trim_leading_zeros(&value.into().to_be_bytes()).to_vec())
+    }
+
+    pub(crate) fn encode(&self) -> Vec<u8> {
+        let mut buf = Vec::new();
+        self.encode_to(&mut buf);
+        buf
+    }
+
+    fn encode_to(&self, buf: &mut Vec<u8>) {
+        match self {
+            Self::Bytes(bytes) if bytes.len() == 1 && bytes[0] < 0x80 => buf.push(bytes[0]),
+            Self::Bytes(bytes) => {
+                encode_header(buf, 0x80, bytes.len());
+                buf.extend_from_slice(bytes);
+            }
+            Self::List(items) => {
+                let mut payload = Vec::new();
+                for item in items {
+                    item.encode_to(&mut payload);
+                }
+
+                encode_header(buf, 0xc0, payload.len());
+                buf.extend_from_slice(&payload);
+            }
+        }
+    }
+
+    /// Decodes exactly one item from `bytes`, failing if there are any trailing bytes.
+    pub(crate) fn decode(bytes: &[u8]) -> crate::Result<Self> {
+        let (item, rest) = Self::decode_prefix(bytes, 0)?;
+
+        if !rest.is_empty() {
+            return Err(Error::basic_parse(format!(
+                "RLP: {} unexpected trailing bytes",
+                rest.len()
+            )));
+        }
+
+        Ok(item)
+    }
+
+    fn decode_prefix(bytes: &[u8], depth: usize) -> crate::Result<(Self, &[u8])> {
+        let (&first, rest) = bytes
+            .split_first()
+            .ok_or_else(|| Error::basic_parse("RLP: unexpected end of input"))?;
+
+        match first {
+            0x00..=0x7f => Ok((Self::Bytes(vec![first]), rest)),
+            0x80..=0xbf => {
+                let (payload, rest) = split_payload(first - 0x80, rest)?;
+
+                if payload.len() == 1 && payload[0] < 0x80 {
+                    return Err(Error::basic_parse("RLP: non-canonical single byte string"));
+                }
+
+                Ok((Self::Bytes(payload.to_vec()), rest))
+            }
+            0xc0..=0xff => {
+                if depth >= MAX_DEPTH {
+                    return Err(Error::basic_parse(format!(
+                        "RLP: lists nested more than {MAX_DEPTH} levels deep"
+                    )));
+                }
+
+                let (mut payload, rest) = split_payload(first - 0xc0, rest)?;
+
+                let mut items = Vec::new();
+                while !payload.is_empty() {
+                    let (item, remaining) = Self::decode_prefix(payload, depth + 1)?;
+                    items.push(item);
+                    payload = remaining;
+                }
+
+                Ok((Self::List(items), rest))
+            }
+        }
+    }
+
+    pub(crate) fn into_bytes(self) -> crate::Result<Vec<u8>> {
+        match self {
+            Self::Bytes(bytes) => Ok(bytes),
+            Self::List(_) => Err(Error::basic_parse("RLP: expected bytes, found a list")),
+        }
+    }
+
+    pub(crate) fn into_list(self) -> crate::Result<Vec<Self>> {
+        match self {
+            Self::List(items) => Ok(items),
+            Self::Bytes(_) => Err(Error::basic_parse("RLP: expected a list, found bytes")),
+        }
+    }
+}
+
+impl From<Vec<u8>> for RlpItem {
+    fn from(bytes: Vec<u8>) -> Self {
+        Self::Bytes(bytes)
+    }
+}
+
+impl From<&[u8]> for RlpItem {
+    fn from(bytes: &[u8]) -> Self {
+        Self::Bytes(bytes.to_vec())
+    }
+}
+
+/// Strips the leading zero bytes of a big-endian integer, as RLP requires.
+pub(crate) fn trim_leading_zeros(bytes: &[u8]) -> &[u8] {
+    let leading_zeros = bytes.iter().take_while(|it| **it == 0).count();
+
+    &bytes[leading_zeros..]
+}
+
+/// Decodes a big-endian integer from an RLP byte string, naming `field` in any error.
+pub(crate) fn decode_uint<T: TryFrom<u128>>(field: &str, bytes: &[u8]) -> crate::Result<T> {
+    if bytes.first() == Some(&0) {
+        return Err(Error::basic_parse(format!("RLP: `{field}` has leading zeros")));
+    }
+
+    let value = match bytes.len() {
+        0..=16 => bytes.iter().fold(0_u128, |acc, it| (acc << 8) | u128::from(*it)),
+        _ => return Err(Error::basic_parse(format!("RLP: `{field}` is too large"))),
+    };
+
+    T::try_from(value).map_err(|_| Error::basic_parse(format!("RLP: `{field}` is too large")))
+}
+
+fn encode_header(buf: &mut Vec<u8>, offset: u8, len: usize) {
+    if len <= 55 {
+        buf.push(offset + len as u8);
+        return;
+    }
+
+    let len = (len as u64).to_be_bytes();
+    let len = trim_leading_zeros(&len);
+
+    buf.push(offset + 55 + len.len() as u8);
+    buf.extend_from_slice(len);
+}
+
+/// Splits the payload described by `tag` (a header byte with the type offset already removed) from `bytes`.
+fn split_payload(tag: u8, bytes: &[u8]) -> crate::Result<(&[u8], &[u8])> {
+    let (len, bytes) = if tag <= 55 {
+        (usize::from(tag), bytes)
+    } else {
+        let len_len = usize::from(tag - 55);
+
+        if bytes.len() < len_len {
+            return Err(Error::basic_parse("RLP: unexpected end of input"));
+        }
+
+        let (len_bytes, bytes) = bytes.split_at(len_len);
+
+        if len_bytes[0] == 0 {
+            return Err(Error::basic_parse("RLP: length has leading zeros"));
+        }
+
+        let len = len_bytes
+            .iter()
+            .try_fold(0_usize, |acc, it| acc.checked_mul(256)?.checked_add(usize::from(*it)))
+            .ok_or_else(|| Error::basic_parse("RLP: length overflow"))?;
+
+        if len <= 55 {
+            return Err(Error::basic_parse("RLP: non-canonical length"));
+        }
+
+        (len, bytes)
+    };
+
+    if bytes.len() < len {
+        return Err(Error::basic_parse("RLP: unexpected end of input"));
+    }
+
+    Ok(bytes.split_at(len))
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+    use hex_literal::hex;
+
+    use super::RlpItem;
+
+    #[test]
+    fn encode_decode_known_values() -> crate::Result<()> {
+        let cases: [(RlpItem, &[u8]); 6] = [
+            (RlpItem::Bytes(Vec::new()), &hex!("80")),
+            (RlpItem::Bytes(b"dog".to_vec()), &hex!("83646f67")),
+            (RlpItem::uint(0_u64), &hex!("80")),
+            (RlpItem::uint(1024_u64), &hex!("820400")),
+            (RlpItem::List(Vec::new()), &hex!("c0")),
+            (
+                RlpItem::List(vec![b"cat".to_vec().into(), b"dog".to_vec().into()]),
+                &hex!("c88363617483646f67"),
+            ),
+        ];
+
+        for (item, encoded) in cases {
+            assert_eq!(item.encode(), encoded);
+            assert_eq!(RlpItem::decode(encoded)?, item);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn long_string() -> crate::Result<()> {
+        let item = RlpItem::Bytes(vec![b'a'; 56]);
+        let encoded = item.encode();
+
+        assert_eq!(&encoded[..2], &hex!("b838"));
+        assert_eq!(RlpItem::decode(&encoded)?, item);
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid() {
+        assert_matches!(RlpItem::decode(&[]), Err(_));
+        assert_matches!(RlpItem::decode(&hex!("83646f")), Err(_));
+        assert_matches!(RlpItem::decode(&hex!("8100")), Err(_));
+        assert_matches!(RlpItem::decode(&hex!("80 80")), Err(_));
+    }
+
+    #[test]
+    fn nesting_depth() -> crate::Result<()> {
+        let nested =
+            |depth| (0..depth).fold(RlpItem::List(Vec::new()), |it, _| RlpItem::List(vec![it]));
+
+        let item = nested(super::MAX_DEPTH - 1);
+        assert_eq!(RlpItem::decode(&item.encode())?, item);
+
+        assert_matches!(RlpItem::decode(&nested(super::MAX_DEPTH).encode()), Err(_));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,87 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+//! Ethereum style (`r || s || v`) signatures over keccak-256 hashes, as used by `personal_sign` and EIP-712.
+
+use k256::ecdsa::{
+    self,
+    recoverable,
+};
+use sha3::{
+    Digest,
+    Keccak256,
+};
+
+use crate::Error;
+
+/// The message that `personal_sign` (EIP-191 version `0x45`) actually signs for `message`.
+pub(crate) fn personal_message(message: &[u8]) -> Vec<u8> {
+    let mut out = format!("\x19Ethereum Signed Message:\n{}", message.len()).into_bytes();
+    out.extend_from_slice(message);
+    out
+}
+
+/// Combines a signature and its recovery ID into `r || s || v`, with `v` being `27` or `28`.
+pub(crate) fn to_rsv(r_s: [u8; 64], recovery_id: u8) -> Vec<u8> {
+    let mut out = Vec::with_capacity(65);
+    out.extend_from_slice(&r_s);
+    out.push(27 + recovery_id);
+    out
+}
+
+/// Recovers the key that produced the `r || s || v` `signature` over the keccak-256 hash of `message`.
+///
+/// `v` may either be the recovery ID itself (`0` or `1`), or the recovery ID plus 27.
+pub(crate) fn recover(message: &[u8], signature: &[u8]) -> crate::Result<ecdsa::VerifyingKey> {
+    let (r_s, v) = match signature {
+        [r_s @ .., v] if r_s.len() == 64 => (r_s, *v),
+        _ => {
+            return Err(Error::signature_verify(format!(
+                "expected a 65 byte signature, got {} bytes",
+                signature.len()
+            )))
+        }
+    };
+
+    let recovery_id = match v {
+        0 | 1 => v,
+        27 | 28 => v - 27,
+        _ => return Err(Error::signature_verify(format!("invalid recovery ID `{v}`"))),
+    };
+
+    let recovery_id = recoverable::Id::new(recovery_id).map_err(Error::signature_verify)?;
+    let signature = ecdsa::Signature::try_from(r_s).map_err(Error::signature_verify)?;
+    let signature =
+        recoverable::Signature::new(&signature, recovery_id).map_err(Error::signature_verify)?;
+
+    signature
+        .recover_verifying_key_from_digest(Keccak256::new_with_prefix(message))
+        .map_err(Error::signature_verify)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::personal_message;
+
+    #[test]
+    fn personal_message_prefix() {
+        assert_eq!(personal_message(b"hello"), b"\x19Ethereum Signed Message:\n5hello");
+    }
+}
@@ -0,0 +1,442 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::collections::{
+    BTreeMap,
+    BTreeSet,
+};
+use std::iter;
+use std::str::FromStr;
+
+use num_bigint::{
+    BigInt,
+    BigUint,
+};
+use serde_json::{
+    Map,
+    Value,
+};
+use sha3::{
+    Digest,
+    Keccak256,
+};
+
+use crate::abi::codec;
+use crate::{
+    AbiType,
+    AbiValue,
+    Error,
+    EvmAddress,
+};
+
+const DOMAIN_TYPE: &str = "EIP712Domain";
+
+/// The fields an `EIP712Domain` can have, in the order they're encoded in.
+const DOMAIN_FIELDS: [(&str, &str); 5] = [
+    ("name", "string"),
+    ("version", "string"),
+    ("chainId", "uint256"),
+    ("verifyingContract", "address"),
+    ("salt", "bytes32"),
+];
+
+/// Typed structured data, as signed with [EIP-712](https://eips.ethereum.org/EIPS/eip-712)
+/// (`eth_signTypedData_v4`).
+///
+/// See [`PrivateKey::sign_typed_data`](crate::PrivateKey::sign_typed_data)
+/// and [`PublicKey::verify_typed_data`](crate::PublicKey::verify_typed_data).
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TypedData {
+    #[serde(default)]
+    types: BTreeMap<String, Vec<Field>>,
+    primary_type: String,
+    #[serde(default)]
+    domain: Map<String, Value>,
+    message: Value,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize)]
+struct Field {
+    name: String,
+    #[serde(rename = "type")]
+    ty: String,
+}
+
+impl TypedData {
+    /// Parse typed data from the JSON accepted by `eth_signTypedData_v4`
+    /// (an object with `types`, `primaryType`, `domain` and `message`).
+    ///
+    /// If `types` doesn't contain `EIP712Domain`, it's inferred from the fields present in `domain`.
+    ///
+    /// # Errors
+    /// - [`Error::AbiParse`] if `json` isn't valid typed data.
+    pub fn from_json(json: &str) -> crate::Result<Self> {
+        let mut data: Self = serde_json::from_str(json).map_err(Error::abi_parse)?;
+
+        if !data.types.contains_key(DOMAIN_TYPE) {
+            let fields = DOMAIN_FIELDS
+                .iter()
+                .filter(|(name, _)| data.domain.contains_key(*name))
+                .map(|(name, ty)| Field { name: (*name).to_owned(), ty: (*ty).to_owned() })
+                .collect();
+
+            data.types.insert(DOMAIN_TYPE.to_owned(), fields);
+        }
+
+        if !data.types.contains_key(&data.primary_type) {
+            return Err(Error::abi_parse(format!("unknown primary type `{}`", data.primary_type)));
+        }
+
+        Ok(data)
+    }
+
+    /// Returns the EIP-712 domain separator, the hash of `domain`.
+    ///
+    /// # Errors
+    /// - [`Error::AbiEncode`] if `domain` doesn't match the `EIP712Domain` type.
+    pub fn domain_separator(&self) -> crate::Result<[u8; 32]> {
+        self.hash_struct(DOMAIN_TYPE, &Value::Object(self.domain.clone()))
+    }
+
+    /// Returns the hash of `message`, as an instance of the primary type.
+    ///
+    /// # Errors
+    /// - [`Error::AbiEncode`] if `message` doesn't match the primary type.
+    pub fn struct_hash(&self) -> crate::Result<[u8; 32]> {
+        self.hash_struct(&self.primary_type, &self.message)
+    }
+
+    /// Returns the hash that gets signed, `keccak256(0x19 || 0x01 || domain_separator || struct_hash)`.
+    ///
+    /// # Errors
+    /// - [`Error::AbiEncode`] if `domain` or `message` don't match their types.
+    pub fn signing_hash(&self) -> crate::Result<[u8; 32]> {
+        Ok(Keccak256::digest(self.encode()?).into())
+    }
+
+    /// Returns the bytes whose keccak-256 hash gets signed.
+    pub(crate) fn encode(&self) -> crate::Result<Vec<u8>> {
+        Ok([&[0x19, 0x01][..], &self.domain_separator()?, &self.struct_hash()?].concat())
+    }
+
+    fn fields(&self, name: &str) -> crate::Result<&[Field]> {
+        self.types
+            .get(name)
+            .map(Vec::as_slice)
+            .ok_or_else(|| Error::abi_encode(format!("unknown type `{name}`")))
+    }
+
+    /// Returns the encoding of type `name`, for example `Mail(Person from,Person to,string contents)Person(string name,address wallet)`.
+    fn encode_type(&self, name: &str) -> crate::Result<String> {
+        let mut dependencies = BTreeSet::new();
+        self.collect_dependencies(name, &mut dependencies)?;
+        dependencies.remove(name);
+
+        let mut out = String::new();
+
+        for ty in iter::once(name).chain(dependencies) {
+            let fields: Vec<_> =
+                self.fields(ty)?.iter().map(|it| format!("{} {}", it.ty, it.name)).collect();
+
+            out.push_str(ty);
+            out.push('(');
+            out.push_str(&fields.join(","));
+            out.push(')');
+        }
+
+        Ok(out)
+    }
+
+    fn collect_dependencies<'a>(
+        &'a self,
+        name: &'a str,
+        dependencies: &mut BTreeSet<&'a str>,
+    ) -> crate::Result<()> {
+        if !dependencies.insert(name) {
+            return Ok(());
+        }
+
+        for field in self.fields(name)? {
+            let base = base_type(&field.ty);
+
+            if self.types.contains_key(base) {
+                self.collect_dependencies(base, dependencies)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn hash_struct(&self, name: &str, value: &Value) -> crate::Result<[u8; 32]> {
+        let object = value
+            .as_object()
+            .ok_or_else(|| Error::abi_encode(format!("expected an object of type `{name}`")))?;
+
+        let mut hasher = Keccak256::new();
+        hasher.update(Keccak256::digest(self.encode_type(name)?));
+
+        for field in self.fields(name)? {
+            let value = object.get(&field.name).ok_or_else(|| {
+                Error::abi_encode(format!("missing field `{}` of `{name}`", field.name))
+            })?;
+
+            hasher.update(self.encode_field(&field.ty, value)?);
+        }
+
+        Ok(hasher.finalize().into())
+    }
+
+    fn encode_field(&self, ty: &str, value: &Value) -> crate::Result<[u8; 32]> {
+        let invalid = || Error::abi_encode(format!("invalid `{ty}` value `{value}`"));
+
+        if let Some(rest) = ty.strip_suffix(']') {
+            let (element, len) = rest.rsplit_once('[').ok_or_else(invalid)?;
+            let items = value.as_array().ok_or_else(invalid)?;
+
+            if !len.is_empty() && len.parse::<usize>().ok() != Some(items.len()) {
+                return Err(invalid());
+            }
+
+            let mut hasher = Keccak256::new();
+
+            for item in items {
+                hasher.update(self.encode_field(element, item)?);
+            }
+
+            return Ok(hasher.finalize().into());
+        }
+
+        if self.types.contains_key(ty) {
+            return self.hash_struct(ty, value);
+        }
+
+        match ty {
+            "string" => Ok(Keccak256::digest(value.as_str().ok_or_else(invalid)?).into()),
+            "bytes" => Ok(Keccak256::digest(hex_bytes(value).ok_or_else(invalid)?).into()),
+            _ => {
+                let abi_type = AbiType::from_str(ty)
+                    .map_err(|_| Error::abi_encode(format!("unknown type `{ty}`")))?;
+                let abi_value = atomic_value(&abi_type, value).ok_or_else(invalid)?;
+
+                // atomic values are always encoded in exactly one 32 byte slot.
+                codec::encode_single(&abi_type, &abi_value)?.try_into().map_err(|_| invalid())
+            }
+        }
+    }
+}
+
+impl FromStr for TypedData {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_json(s)
+    }
+}
+
+/// Strips any array suffixes from `ty` (`Person[][2]` becomes `Person`).
+fn base_type(mut ty: &str) -> &str {
+    while let Some(rest) = ty.strip_suffix(']') {
+        ty = match rest.rsplit_once('[') {
+            Some((element, _)) => element,
+            None => break,
+        };
+    }
+
+    ty
+}
+
+fn atomic_value(ty: &AbiType, value: &Value) -> Option<AbiValue> {
+    match ty {
+        AbiType::Address => value.as_str()?.parse::<EvmAddress>().ok().map(AbiValue::Address),
+        AbiType::Bool => value.as_bool().map(AbiValue::Bool),
+        AbiType::Uint(_) => BigUint::try_from(parse_int(value)?).ok().map(AbiValue::Uint),
+        AbiType::Int(_) => parse_int(value).map(AbiValue::Int),
+        AbiType::FixedBytes(_) => hex_bytes(value).map(AbiValue::FixedBytes),
+        _ => None,
+    }
+}
+
+/// Parses an integer given as a JSON number, or as a decimal or `0x` prefixed hex string.
+fn parse_int(value: &Value) -> Option<BigInt> {
+    match value {
+        Value::Number(number) => {
+            number.as_u64().map(BigInt::from).or_else(|| number.as_i64().map(BigInt::from))
+        }
+        Value::String(s) => {
+            let (negative, digits) = match s.strip_prefix('-') {
+                Some(digits) => (true, digits),
+                None => (false, s.as_str()),
+            };
+
+            let magnitude = match digits.strip_prefix("0x") {
+                Some(hex) => BigUint::parse_bytes(hex.as_bytes(), 16)?,
+                None => BigUint::parse_bytes(digits.as_bytes(), 10)?,
+            };
+
+            let value = BigInt::from(magnitude);
+
+            Some(if negative { -value } else { value })
+        }
+        _ => None,
+    }
+}
+
+fn hex_bytes(value: &Value) -> Option<Vec<u8>> {
+    hex::decode(value.as_str()?.strip_prefix("0x")?).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+    use hex_literal::hex;
+
+    use crate::{
+        Error,
+        PrivateKey,
+        TypedData,
+    };
+
+    // the example from EIP-712.
+    const MAIL: &str = r#"{
+        "types": {
+            "EIP712Domain": [
+                { "name": "name", "type": "string" },
+                { "name": "version", "type": "string" },
+                { "name": "chainId", "type": "uint256" },
+                { "name": "verifyingContract", "type": "address" }
+            ],
+            "Person": [
+                { "name": "name", "type": "string" },
+                { "name": "wallet", "type": "address" }
+            ],
+            "Mail": [
+                { "name": "from", "type": "Person" },
+                { "name": "to", "type": "Person" },
+                { "name": "contents", "type": "string" }
+            ]
+        },
+        "primaryType": "Mail",
+        "domain": {
+            "name": "Ether Mail",
+            "version": "1",
+            "chainId": 1,
+            "verifyingContract": "0xCcCCccccCCCCcCCCCCCcCcCccCcCCCcCcccccccC"
+        },
+        "message": {
+            "from": { "name": "Cow", "wallet": "0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826" },
+            "to": { "name": "Bob", "wallet": "0xbBbBBBBbbBBBbbbBbbBbbbbBBbBbbbbBbBbbBBbB" },
+            "contents": "Hello, Bob!"
+        }
+    }"#;
+
+    #[test]
+    fn encode_type() -> crate::Result<()> {
+        let data = TypedData::from_json(MAIL)?;
+
+        assert_eq!(
+            data.encode_type("Mail")?,
+            "Mail(Person from,Person to,string contents)Person(string name,address wallet)"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn hashes() -> crate::Result<()> {
+        let data = TypedData::from_json(MAIL)?;
+
+        assert_eq!(
+            data.domain_separator()?,
+            hex!("f2cee375fa42b42143804025fc449deafd50cc031ca257e0b194a650a912090f")
+        );
+        assert_eq!(
+            data.struct_hash()?,
+            hex!("c52c0ee5d84264471806290a3f2c4cecfc5490626bf912d01f240d7a274b371e")
+        );
+        assert_eq!(
+            data.signing_hash()?,
+            hex!("be609aee343fb3c4b28e1df9e632fca64fcfaede20f02e86244efddf30957bd2")
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn sign_and_verify() -> crate::Result<()> {
+        let data = TypedData::from_json(MAIL)?;
+
+        // `keccak256("cow")`, the key of the example.
+        let key = PrivateKey::from_bytes_ecdsa(&hex!(
+            "c85ef7d79691fe79573b1a7064c19c1a9819ebdbd1faaab1a8ec92344438aaf4"
+        ))?;
+
+        let signature = key.sign_typed_data(&data)?;
+
+        assert_eq!(
+            signature,
+            hex!(
+                "4355c47d63924e8a72e509b65029052eb6c299d53a04e167c5775fd466751c9d"
+                "07299936d304c153f6443dfa05f40ff007d72911b6f72307f996231605b91562"
+                "1c"
+            )
+        );
+
+        key.public_key().verify_typed_data(&data, &signature)?;
+
+        let other = PrivateKey::generate_ecdsa();
+        assert_matches!(
+            other.public_key().verify_typed_data(&data, &signature),
+            Err(Error::SignatureVerify(_))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn inferred_domain_type() -> crate::Result<()> {
+        // the same as `MAIL`, without the `EIP712Domain` type.
+        let mut json: serde_json::Value = serde_json::from_str(MAIL).unwrap();
+        json["types"].as_object_mut().unwrap().remove("EIP712Domain");
+
+        let data = TypedData::from_json(&json.to_string())?;
+
+        assert_eq!(
+            data.domain_separator()?,
+            hex!("f2cee375fa42b42143804025fc449deafd50cc031ca257e0b194a650a912090f")
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_message() -> crate::Result<()> {
+        let mut json: serde_json::Value = serde_json::from_str(MAIL).unwrap();
+        json["message"]["from"].as_object_mut().unwrap().remove("wallet");
+
+        let data = TypedData::from_json(&json.to_string())?;
+        assert_matches!(data.struct_hash(), Err(Error::AbiEncode(_)));
+
+        json["primaryType"] = "Letter".into();
+        assert_matches!(TypedData::from_json(&json.to_string()), Err(Error::AbiParse(_)));
+
+        Ok(())
+    }
+}
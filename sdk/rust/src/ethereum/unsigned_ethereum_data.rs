@@ -0,0 +1,487 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use super::rlp::{
+    decode_uint,
+    trim_leading_zeros,
+    RlpItem,
+};
+use super::{
+    AccessListItem,
+    Eip1559EthereumData,
+    Eip2930EthereumData,
+    EthereumData,
+    LegacyEthereumData,
+};
+use crate::{
+    Error,
+    EvmAddress,
+    PrivateKey,
+};
+
+/// An unsigned legacy (type 0) Ethereum transaction.
+///
+/// Sign it with [`sign`](Self::sign) to get [`LegacyEthereumData`] to submit,
+/// or recover it from a signed transaction with [`LegacyEthereumData::to_unsigned`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UnsignedLegacyEthereumData {
+    /// The ID of the chain the transaction is for, enabling EIP-155 replay protection.
+    ///
+    /// See [`LedgerId::ethereum_chain_id`](crate::LedgerId::ethereum_chain_id).
+    pub chain_id: Option<u64>,
+
+    /// The transaction's nonce.
+    pub nonce: u64,
+
+    /// The price for one unit of gas, in weibars.
+    pub gas_price: u128,
+
+    /// The maximum amount of gas the transaction may use.
+    pub gas_limit: u64,
+
+    /// The receiver of the transaction, `None` for contract creation.
+    pub to: Option<EvmAddress>,
+
+    /// The amount of weibars transferred.
+    pub value: u128,
+
+    /// The call data (or init code, for contract creation).
+    pub call_data: Vec<u8>,
+}
+
+/// An unsigned EIP-2930 (type 1) Ethereum transaction.
+///
+/// Sign it with [`sign`](Self::sign) to get [`Eip2930EthereumData`] to submit,
+/// or recover it from a signed transaction with [`Eip2930EthereumData::to_unsigned`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UnsignedEip2930EthereumData {
+    /// The ID of the chain the transaction is for.
+    ///
+    /// See [`LedgerId::ethereum_chain_id`](crate::LedgerId::ethereum_chain_id).
+    pub chain_id: u64,
+
+    /// The transaction's nonce.
+    pub nonce: u64,
+
+    /// The price for one unit of gas, in weibars.
+    pub gas_price: u128,
+
+    /// The maximum amount of gas the transaction may use.
+    pub gas_limit: u64,
+
+    /// The receiver of the transaction, `None` for contract creation.
+    pub to: Option<EvmAddress>,
+
+    /// The amount of weibars transferred.
+    pub value: u128,
+
+    /// The call data (or init code, for contract creation).
+    pub call_data: Vec<u8>,
+
+    /// The addresses and storage keys the transaction plans to access.
+    pub access_list: Vec<AccessListItem>,
+}
+
+/// An unsigned EIP-1559 (type 2) Ethereum transaction.
+///
+/// Sign it with [`sign`](Self::sign) to get [`Eip1559EthereumData`] to submit,
+/// or recover it from a signed transaction with [`Eip1559EthereumData::to_unsigned`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UnsignedEip1559EthereumData {
+    /// The ID of the chain the transaction is for.
+    ///
+    /// See [`LedgerId::ethereum_chain_id`](crate::LedgerId::ethereum_chain_id).
+    pub chain_id: u64,
+
+    /// The transaction's nonce.
+    pub nonce: u64,
+
+    /// The maximum priority fee per unit of gas, in weibars.
+    pub max_priority_gas: u128,
+
+    /// The maximum total fee per unit of gas, in weibars.
+    pub max_gas: u128,
+
+    /// The maximum amount of gas the transaction may use.
+    pub gas_limit: u64,
+
+    /// The receiver of the transaction, `None` for contract creation.
+    pub to: Option<EvmAddress>,
+
+    /// The amount of weibars transferred.
+    pub value: u128,
+
+    /// The call data (or init code, for contract creation).
+    pub call_data: Vec<u8>,
+
+    /// The addresses and storage keys the transaction plans to access.
+    pub access_list: Vec<AccessListItem>,
+}
+
+fn to_bytes(to: Option<EvmAddress>) -> Vec<u8> {
+    to.map(|it| it.to_bytes().to_vec()).unwrap_or_default()
+}
+
+fn to_address(to: &[u8]) -> crate::Result<Option<EvmAddress>> {
+    match to {
+        [] => Ok(None),
+        _ => EvmAddress::try_from(to.to_vec()).map(Some),
+    }
+}
+
+/// Signs `payload`, returning the (RLP integer encoded) `r` and `s` values and the recovery ID.
+fn sign(key: &PrivateKey, payload: &[u8]) -> crate::Result<(Vec<u8>, Vec<u8>, u8)> {
+    let (signature, recovery_id) = key.sign_ecdsa_recoverable(payload)?;
+    let (r, s) = signature.split_at(32);
+
+    Ok((trim_leading_zeros(r).to_vec(), trim_leading_zeros(s).to_vec(), recovery_id))
+}
+
+impl UnsignedLegacyEthereumData {
+    fn signing_payload(&self) -> Vec<u8> {
+        let mut fields = vec![
+            RlpItem::uint(self.nonce),
+            RlpItem::uint(self.gas_price),
+            RlpItem::uint(self.gas_limit),
+            to_bytes(self.to).into(),
+            RlpItem::uint(self.value),
+            self.call_data.clone().into(),
+        ];
+
+        // EIP-155: the chain ID is signed over, followed by two empty values.
+        if let Some(chain_id) = self.chain_id {
+            fields.extend([RlpItem::uint(chain_id), RlpItem::uint(0_u64), RlpItem::uint(0_u64)]);
+        }
+
+        RlpItem::List(fields).encode()
+    }
+
+    /// Sign this transaction with an ECDSA (secp256k1) `key`.
+    ///
+    /// # Errors
+    /// - [`Error::WrongKeyType`] if `key` isn't an ECDSA key.
+    pub fn sign(&self, key: &PrivateKey) -> crate::Result<LegacyEthereumData> {
+        let (r, s, recovery_id) = sign(key, &self.signing_payload())?;
+
+        let v = match self.chain_id {
+            Some(chain_id) => u128::from(chain_id) * 2 + 35 + u128::from(recovery_id),
+            None => 27 + u128::from(recovery_id),
+        };
+
+        Ok(LegacyEthereumData {
+            nonce: RlpItem::uint(self.nonce).into_bytes()?,
+            gas_price: RlpItem::uint(self.gas_price).into_bytes()?,
+            gas_limit: RlpItem::uint(self.gas_limit).into_bytes()?,
+            to: to_bytes(self.to),
+            value: RlpItem::uint(self.value).into_bytes()?,
+            call_data: self.call_data.clone(),
+            v: RlpItem::uint(v).into_bytes()?,
+            r,
+            s,
+        })
+    }
+}
+
+impl LegacyEthereumData {
+    /// Decode the fields of this transaction, dropping the signature.
+    ///
+    /// The chain ID is recovered from `v`, as per EIP-155.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if any of the fields are out of range, or `v` is invalid.
+    pub fn to_unsigned(&self) -> crate::Result<UnsignedLegacyEthereumData> {
+        let chain_id = match decode_uint::<u128>("v", &self.v)? {
+            27 | 28 => None,
+            v @ 35.. => Some(
+                u64::try_from((v - 35) / 2)
+                    .map_err(|_| Error::basic_parse("RLP: chain ID in `v` is too large"))?,
+            ),
+            v => {
+                return Err(Error::basic_parse(format!(
+                    "invalid legacy transaction `v` value `{v}`"
+                )))
+            }
+        };
+
+        Ok(UnsignedLegacyEthereumData {
+            chain_id,
+            nonce: decode_uint("nonce", &self.nonce)?,
+            gas_price: decode_uint("gas_price", &self.gas_price)?,
+            gas_limit: decode_uint("gas_limit", &self.gas_limit)?,
+            to: to_address(&self.to)?,
+            value: decode_uint("value", &self.value)?,
+            call_data: self.call_data.clone(),
+        })
+    }
+}
+
+impl UnsignedEip2930EthereumData {
+    fn signing_payload(&self) -> Vec<u8> {
+        let fields = RlpItem::List(vec![
+            RlpItem::uint(self.chain_id),
+            RlpItem::uint(self.nonce),
+            RlpItem::uint(self.gas_price),
+            RlpItem::uint(self.gas_limit),
+            to_bytes(self.to).into(),
+            RlpItem::uint(self.value),
+            self.call_data.clone().into(),
+            AccessListItem::list_to_rlp(&self.access_list),
+        ]);
+
+        [&[super::ethereum_data::EIP_2930_TYPE][..], &fields.encode()].concat()
+    }
+
+    /// Sign this transaction with an ECDSA (secp256k1) `key`.
+    ///
+    /// # Errors
+    /// - [`Error::WrongKeyType`] if `key` isn't an ECDSA key.
+    pub fn sign(&self, key: &PrivateKey) -> crate::Result<Eip2930EthereumData> {
+        let (r, s, recovery_id) = sign(key, &self.signing_payload())?;
+
+        Ok(Eip2930EthereumData {
+            chain_id: RlpItem::uint(self.chain_id).into_bytes()?,
+            nonce: RlpItem::uint(self.nonce).into_bytes()?,
+            gas_price: RlpItem::uint(self.gas_price).into_bytes()?,
+            gas_limit: RlpItem::uint(self.gas_limit).into_bytes()?,
+            to: to_bytes(self.to),
+            value: RlpItem::uint(self.value).into_bytes()?,
+            call_data: self.call_data.clone(),
+            access_list: self.access_list.clone(),
+            recovery_id: RlpItem::uint(recovery_id).into_bytes()?,
+            r,
+            s,
+        })
+    }
+}
+
+impl Eip2930EthereumData {
+    /// Decode the fields of this transaction, dropping the signature.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if any of the fields are out of range.
+    pub fn to_unsigned(&self) -> crate::Result<UnsignedEip2930EthereumData> {
+        Ok(UnsignedEip2930EthereumData {
+            chain_id: decode_uint("chain_id", &self.chain_id)?,
+            nonce: decode_uint("nonce", &self.nonce)?,
+            gas_price: decode_uint("gas_price", &self.gas_price)?,
+            gas_limit: decode_uint("gas_limit", &self.gas_limit)?,
+            to: to_address(&self.to)?,
+            value: decode_uint("value", &self.value)?,
+            call_data: self.call_data.clone(),
+            access_list: self.access_list.clone(),
+        })
+    }
+}
+
+impl UnsignedEip1559EthereumData {
+    fn signing_payload(&self) -> Vec<u8> {
+        let fields = RlpItem::List(vec![
+            RlpItem::uint(self.chain_id),
+            RlpItem::uint(self.nonce),
+            RlpItem::uint(self.max_priority_gas),
+            RlpItem::uint(self.max_gas),
+            RlpItem::uint(self.gas_limit),
+            to_bytes(self.to).into(),
+            RlpItem::uint(self.value),
+            self.call_data.clone().into(),
+            AccessListItem::list_to_rlp(&self.access_list),
+        ]);
+
+        [&[super::ethereum_data::EIP_1559_TYPE][..], &fields.encode()].concat()
+    }
+
+    /// Sign this transaction with an ECDSA (secp256k1) `key`.
+    ///
+    /// # Errors
+    /// - [`Error::WrongKeyType`] if `key` isn't an ECDSA key.
+    pub fn sign(&self, key: &PrivateKey) -> crate::Result<Eip1559EthereumData> {
+        let (r, s, recovery_id) = sign(key, &self.signing_payload())?;
+
+        Ok(Eip1559EthereumData {
+            chain_id: RlpItem::uint(self.chain_id).into_bytes()?,
+            nonce: RlpItem::uint(self.nonce).into_bytes()?,
+            max_priority_gas: RlpItem::uint(self.max_priority_gas).into_bytes()?,
+            max_gas: RlpItem::uint(self.max_gas).into_bytes()?,
+            gas_limit: RlpItem::uint(self.gas_limit).into_bytes()?,
+            to: to_bytes(self.to),
+            value: RlpItem::uint(self.value).into_bytes()?,
+            call_data: self.call_data.clone(),
+            access_list: self.access_list.clone(),
+            recovery_id: RlpItem::uint(recovery_id).into_bytes()?,
+            r,
+            s,
+        })
+    }
+}
+
+impl Eip1559EthereumData {
+    /// Decode the fields of this transaction, dropping the signature.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if any of the fields are out of range.
+    pub fn to_unsigned(&self) -> crate::Result<UnsignedEip1559EthereumData> {
+        Ok(UnsignedEip1559EthereumData {
+            chain_id: decode_uint("chain_id", &self.chain_id)?,
+            nonce: decode_uint("nonce", &self.nonce)?,
+            max_priority_gas: decode_uint("max_priority_gas", &self.max_priority_gas)?,
+            max_gas: decode_uint("max_gas", &self.max_gas)?,
+            gas_limit: decode_uint("gas_limit", &self.gas_limit)?,
+            to: to_address(&self.to)?,
+            value: decode_uint("value", &self.value)?,
+            call_data: self.call_data.clone(),
+            access_list: self.access_list.clone(),
+        })
+    }
+}
+
+impl From<LegacyEthereumData> for EthereumData {
+    fn from(data: LegacyEthereumData) -> Self {
+        Self::Legacy(data)
+    }
+}
+
+impl From<Eip2930EthereumData> for EthereumData {
+    fn from(data: Eip2930EthereumData) -> Self {
+        Self::Eip2930(data)
+    }
+}
+
+impl From<Eip1559EthereumData> for EthereumData {
+    fn from(data: Eip1559EthereumData) -> Self {
+        Self::Eip1559(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+    use hex_literal::hex;
+
+    use super::{
+        UnsignedEip1559EthereumData,
+        UnsignedEip2930EthereumData,
+        UnsignedLegacyEthereumData,
+    };
+    use crate::{
+        Error,
+        EthereumData,
+        EvmAddress,
+        LedgerId,
+        PrivateKey,
+    };
+
+    // the EIP-155 example transaction.
+    const LEGACY: [u8; 110] = hex!(
+        "f86c098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a7640000"
+        "8025a028ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276a067cbe9d8997f"
+        "761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83"
+    );
+
+    fn legacy() -> UnsignedLegacyEthereumData {
+        UnsignedLegacyEthereumData {
+            chain_id: Some(1),
+            nonce: 9,
+            gas_price: 20_000_000_000,
+            gas_limit: 21000,
+            to: Some(EvmAddress::from([0x35; 20])),
+            value: 1_000_000_000_000_000_000,
+            call_data: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn legacy_sign_matches_eip_155() -> crate::Result<()> {
+        let key = PrivateKey::from_bytes_ecdsa(&[0x46; 32])?;
+
+        let signed = legacy().sign(&key)?;
+
+        assert_eq!(EthereumData::from(signed).to_bytes(), LEGACY);
+
+        Ok(())
+    }
+
+    #[test]
+    fn legacy_to_unsigned() -> crate::Result<()> {
+        let data =
+            assert_matches!(EthereumData::from_bytes(&LEGACY)?, EthereumData::Legacy(it) => it);
+
+        assert_eq!(data.to_unsigned()?, legacy());
+
+        Ok(())
+    }
+
+    #[test]
+    fn eip_2930_round_trip() -> crate::Result<()> {
+        let key = PrivateKey::generate_ecdsa();
+
+        let unsigned = UnsignedEip2930EthereumData {
+            chain_id: LedgerId::testnet().ethereum_chain_id().unwrap(),
+            nonce: 1,
+            gas_price: 1_000,
+            gas_limit: 50_000,
+            to: None,
+            value: 0,
+            call_data: hex!("6080604052").to_vec(),
+            access_list: Vec::new(),
+        };
+
+        let bytes = EthereumData::from(unsigned.sign(&key)?).to_bytes();
+
+        let data =
+            assert_matches!(EthereumData::from_bytes(&bytes)?, EthereumData::Eip2930(it) => it);
+
+        assert_eq!(data.to_unsigned()?, unsigned);
+
+        Ok(())
+    }
+
+    #[test]
+    fn eip_1559_round_trip() -> crate::Result<()> {
+        let key = PrivateKey::generate_ecdsa();
+
+        let unsigned = UnsignedEip1559EthereumData {
+            chain_id: LedgerId::mainnet().ethereum_chain_id().unwrap(),
+            nonce: 2,
+            max_priority_gas: 10,
+            max_gas: 1_000_000_000_000,
+            gas_limit: 100_000,
+            to: Some(EvmAddress::from([0x7e; 20])),
+            value: u128::MAX,
+            call_data: hex!("123456").to_vec(),
+            access_list: Vec::new(),
+        };
+
+        let bytes = EthereumData::from(unsigned.sign(&key)?).to_bytes();
+
+        let data =
+            assert_matches!(EthereumData::from_bytes(&bytes)?, EthereumData::Eip1559(it) => it);
+
+        assert_eq!(data.to_unsigned()?, unsigned);
+
+        Ok(())
+    }
+
+    #[test]
+    fn sign_with_ed25519_fails() {
+        let key = PrivateKey::generate_ed25519();
+
+        assert_matches!(legacy().sign(&key), Err(Error::WrongKeyType { .. }));
+    }
+}
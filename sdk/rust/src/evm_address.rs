@@ -2,7 +2,12 @@ use std::fmt;
 use std::str::FromStr;
 
 use hex::FromHexError;
+use sha3::{
+    Digest,
+    Keccak256,
+};
 
+use crate::ethereum::rlp::RlpItem;
 use crate::{
     EntityId,
     Error,
@@ -26,6 +31,44 @@ impl EvmAddress {
     pub fn to_bytes(self) -> [u8; 20] {
         self.0
     }
+
+    /// Returns the address of the contract created by `sender` with the `CREATE` opcode,
+    /// while `sender` has the given `nonce`.
+    ///
+    /// For a contract deploying another contract, `sender` is the deploying contract's address,
+    /// and `nonce` the number of contracts it has created so far (starting at 1).
+    #[must_use]
+    pub fn create(sender: EvmAddress, nonce: u64) -> Self {
+        let rlp = RlpItem::List(vec![RlpItem::from(&sender.0[..]), RlpItem::uint(nonce)]).encode();
+
+        Self::from_hash(Keccak256::digest(rlp).into())
+    }
+
+    /// Returns the address of the contract created by `deployer` with the `CREATE2` opcode,
+    /// given the `salt` and the Keccak-256 hash of the contract's init code.
+    #[must_use]
+    pub fn create2(deployer: EvmAddress, salt: [u8; 32], init_code_hash: [u8; 32]) -> Self {
+        let mut hasher = Keccak256::new();
+        hasher.update([0xff]);
+        hasher.update(deployer.0);
+        hasher.update(salt);
+        hasher.update(init_code_hash);
+
+        Self::from_hash(hasher.finalize().into())
+    }
+
+    /// Returns the address of the contract created by `deployer` with the `CREATE2` opcode,
+    /// given the `salt` and the contract's init code (creation bytecode with constructor arguments appended).
+    #[must_use]
+    pub fn create2_with_init_code(deployer: EvmAddress, salt: [u8; 32], init_code: &[u8]) -> Self {
+        Self::create2(deployer, salt, Keccak256::digest(init_code).into())
+    }
+
+    fn from_hash(hash: [u8; 32]) -> Self {
+        let mut address = [0; 20];
+        address.copy_from_slice(&hash[12..]);
+        Self(address)
+    }
 }
 
 // potential point of confusion: This type is specifically for the `shard.realm.num` in 20 byte format.
@@ -219,3 +262,62 @@ impl fmt::LowerHex for EvmAddress {
         f.write_str(output)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use hex_literal::hex;
+
+    use crate::EvmAddress;
+
+    #[test]
+    fn create() {
+        let sender = EvmAddress::from(hex!("6ac7ea33f8831ea9dcc53393aaa88b25a785dbf0"));
+
+        assert_eq!(
+            EvmAddress::create(sender, 0),
+            EvmAddress::from(hex!("cd234a471b72ba2f1ccf0a70fcaba648a5eecd8d"))
+        );
+
+        assert_eq!(
+            EvmAddress::create(sender, 1),
+            EvmAddress::from(hex!("343c43a37d37dff08ae8c4a11544c718abb4fcf8"))
+        );
+
+        assert_eq!(
+            EvmAddress::create(sender, 2),
+            EvmAddress::from(hex!("f778b86fa74e846c4f0a1fbd1335fe81c00a0c91"))
+        );
+    }
+
+    // examples from EIP-1014.
+    #[test]
+    fn create2() {
+        assert_eq!(
+            EvmAddress::create2_with_init_code(EvmAddress::from([0; 20]), [0; 32], &[0x00]),
+            EvmAddress::from(hex!("4d1a2e2bb4f88f0250f26ffff098b0b30b26bf38"))
+        );
+
+        assert_eq!(
+            EvmAddress::create2_with_init_code(
+                EvmAddress::from(hex!("deadbeef00000000000000000000000000000000")),
+                hex!("000000000000000000000000feed000000000000000000000000000000000000"),
+                &[0x00]
+            ),
+            EvmAddress::from(hex!("d04116cdd17bebe565eb2422f2497e06cc1c9833"))
+        );
+
+        assert_eq!(
+            EvmAddress::create2_with_init_code(
+                EvmAddress::from(hex!("00000000000000000000000000000000deadbeef")),
+                hex!("00000000000000000000000000000000000000000000000000000000cafebabe"),
+                &hex!("deadbeef")
+            ),
+            EvmAddress::from(hex!("60f3f640a8508fc6a86d45df051962668e1e8ac7"))
+        );
+
+        assert_eq!(
+            EvmAddress::create2_with_init_code(EvmAddress::from([0; 20]), [0; 32], &[]),
+            EvmAddress::from(hex!("e33c0c7f7df4809055c3eba6c09cfe4baf1bd9e0"))
+        );
+    }
+}
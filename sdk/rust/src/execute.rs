@@ -21,11 +21,10 @@
 use backoff::backoff::Backoff;
 use backoff::ExponentialBackoff;
 use prost::Message;
-use rand::thread_rng;
-use time::OffsetDateTime;
 use tokio::time::sleep;
 use tonic::transport::Channel;
 
+use crate::client::ClockAndRng;
 use crate::{
     AccountId,
     BoxGrpcFuture,
@@ -104,6 +103,18 @@ pub(crate) trait Execute: ValidateChecksums {
         transaction_id: Option<TransactionId>,
     ) -> crate::Error;
 
+    /// Create an error from the given pre-check status and the response that carried it.
+    ///
+    /// Only used for pre-checks that fail immediately, by default the response is ignored.
+    fn make_error_pre_check_response(
+        &self,
+        status: Status,
+        transaction_id: Option<TransactionId>,
+        _response: Self::GrpcResponse,
+    ) -> crate::Error {
+        self.make_error_pre_check(status, transaction_id)
+    }
+
     /// Extract the pre-check status from the GRPC response.
     fn response_pre_check_status(response: &Self::GrpcResponse) -> crate::Result<i32>;
 }
@@ -162,38 +173,15 @@ where
     // an attempt is counted when we have a successful response from a node that must either
     // be retried immediately (on a new node) or retried after a backoff.
     loop {
-        // if no explicit set of node account IDs, we randomly sample 1/3 of all
-        // healthy nodes on the client. this set of healthy nodes can change on
-        // each iteration
-
-        let healthy_node_indexes: Option<Vec<_>> = explicit_node_indexes
-            .is_none()
-            .then(|| client.network().healthy_node_indexes().collect());
-
-        let node_indexes =
-            explicit_node_indexes.as_deref().or(healthy_node_indexes.as_deref()).unwrap();
-
-        let node_sample_amount = if explicit_node_indexes.is_none() {
-            (node_indexes.len() + 2) / 3
-        } else {
-            node_indexes.len()
-        };
-
-        let node_index_indexes =
-            rand::seq::index::sample(&mut thread_rng(), node_indexes.len(), node_sample_amount);
-
-        for index in node_index_indexes.iter() {
-            // logic:
-            // if there are no explicit node indexes, all nodes we pick are healthy.
-            // if we're including unhealthy nodes, then it doesn't matter if it's healthy.
-            if explicit_node_indexes.is_some()
-                && !include_unhealthy
-                && !client.network().is_node_healthy(index, OffsetDateTime::now_utc())
-            {
-                continue;
-            }
-
-            let node_index = node_indexes[index];
+        // one clock (and rng) per attempt, so that a clock configured on the client is read consistently.
+        let clock_and_rng = client.clock_and_rng();
+
+        for node_index in sample_node_indexes(
+            client,
+            &clock_and_rng,
+            explicit_node_indexes.as_deref(),
+            include_unhealthy,
+        ) {
             let (node_account_id, channel) = client.network().channel(node_index);
 
             let (request, context) = executable.make_request(&transaction_id, node_account_id)?;
@@ -204,7 +192,9 @@ where
                     match status.code() {
                         tonic::Code::Unavailable | tonic::Code::ResourceExhausted => {
                             // NOTE: this is an "unhealthy" node
-                            client.network().mark_node_unhealthy(node_index);
+                            client
+                                .network()
+                                .mark_node_unhealthy(node_index, clock_and_rng.now_utc());
 
                             // try the next node in our allowed list, immediately
                             last_error = Some(status.into());
@@ -260,7 +250,11 @@ where
 
                     _ => {
                         // any other pre-check is an error that the user needs to fix, fail immediately
-                        return Err(executable.make_error_pre_check(status, transaction_id));
+                        return Err(executable.make_error_pre_check_response(
+                            status,
+                            transaction_id,
+                            response,
+                        ));
                     }
                 },
 
@@ -284,3 +278,43 @@ where
         include_unhealthy = explicit_node_indexes.is_some();
     }
 }
+
+/// Randomly samples the indexes of the nodes to try (in order) in a single attempt.
+///
+/// If there's no explicit set of node account IDs, this samples 1/3 of all
+/// healthy nodes on the client. This set of healthy nodes can change on
+/// each attempt.
+fn sample_node_indexes(
+    client: &Client,
+    clock_and_rng: &ClockAndRng,
+    explicit_node_indexes: Option<&[usize]>,
+    include_unhealthy: bool,
+) -> Vec<usize> {
+    let healthy_node_indexes: Option<Vec<_>> = explicit_node_indexes
+        .is_none()
+        .then(|| client.network().healthy_node_indexes(clock_and_rng.now_utc()).collect());
+
+    let node_indexes = explicit_node_indexes.or(healthy_node_indexes.as_deref()).unwrap();
+
+    let node_sample_amount = if explicit_node_indexes.is_none() {
+        (node_indexes.len() + 2) / 3
+    } else {
+        node_indexes.len()
+    };
+
+    let node_index_indexes = clock_and_rng
+        .rng(|rng| rand::seq::index::sample(rng, node_indexes.len(), node_sample_amount));
+
+    node_index_indexes
+        .iter()
+        // logic:
+        // if there are no explicit node indexes, all nodes we pick are healthy.
+        // if we're including unhealthy nodes, then it doesn't matter if it's healthy.
+        .filter(|index| {
+            explicit_node_indexes.is_none()
+                || include_unhealthy
+                || client.network().is_node_healthy(*index, clock_and_rng.now_utc())
+        })
+        .map(|index| node_indexes[index])
+        .collect()
+}
@@ -78,6 +78,16 @@ pub enum Error {
     CannotPerformTaskWithoutLedgerId,
     NoEvmAddressPresent,
     WrongKeyType,
+    TransfersNotBalanced,
+    NftTransferInconsistent,
+    TransferAmountOverflow,
+    InvalidTransactionField,
+    AbiParse,
+    AbiEncode,
+    AbiDecode,
+    AbiItemNotFound,
+    ContractRevert,
+    KeyEncrypt,
 }
 
 impl Error {
@@ -114,6 +124,16 @@ impl Error {
             crate::Error::NoEvmAddressPresent { .. } => Self::NoEvmAddressPresent,
             crate::Error::WrongKeyType { .. } => Self::WrongKeyType,
             crate::Error::FreezeUnsetNodeAccountIds => Self::FreezeUnsetNodeAccountIds,
+            crate::Error::TransfersNotBalanced { .. } => Self::TransfersNotBalanced,
+            crate::Error::NftTransferInconsistent { .. } => Self::NftTransferInconsistent,
+            crate::Error::TransferAmountOverflow { .. } => Self::TransferAmountOverflow,
+            crate::Error::InvalidTransactionField { .. } => Self::InvalidTransactionField,
+            crate::Error::AbiParse(_) => Self::AbiParse,
+            crate::Error::AbiEncode(_) => Self::AbiEncode,
+            crate::Error::AbiDecode(_) => Self::AbiDecode,
+            crate::Error::AbiItemNotFound { .. } => Self::AbiItemNotFound,
+            crate::Error::ContractRevert { .. } => Self::ContractRevert,
+            crate::Error::KeyEncrypt(_) => Self::KeyEncrypt,
         };
 
         set_last_error(error);
@@ -30,6 +30,7 @@ use crate::protobuf::{
     ToProtobuf,
 };
 use crate::transaction::{
+    validate,
     AnyTransactionData,
     ChunkData,
     ChunkInfo,
@@ -91,6 +92,12 @@ impl FileAppendTransaction {
 }
 
 impl TransactionData for FileAppendTransactionData {
+    fn validate(&self) -> crate::Result<()> {
+        validate::require("file_id", &self.file_id)?;
+
+        Ok(())
+    }
+
     fn maybe_chunk_data(&self) -> Option<&ChunkData> {
         Some(self.chunk_data())
     }
@@ -32,6 +32,7 @@ use crate::protobuf::{
     ToProtobuf,
 };
 use crate::transaction::{
+    validate,
     AnyTransactionData,
     ChunkInfo,
     ToSchedulableTransactionDataProtobuf,
@@ -178,7 +179,17 @@ impl FileCreateTransaction {
     }
 }
 
-impl TransactionData for FileCreateTransactionData {}
+impl TransactionData for FileCreateTransactionData {
+    fn validate(&self) -> crate::Result<()> {
+        validate::memo("file_memo", &self.file_memo)?;
+
+        if let Some(keys) = &self.keys {
+            keys.iter().try_for_each(|it| validate::key("keys", it))?;
+        }
+
+        Ok(())
+    }
+}
 
 impl TransactionExecute for FileCreateTransactionData {
     fn execute(
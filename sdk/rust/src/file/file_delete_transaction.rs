@@ -27,6 +27,7 @@ use crate::protobuf::{
     ToProtobuf,
 };
 use crate::transaction::{
+    validate,
     AnyTransactionData,
     ChunkInfo,
     ToSchedulableTransactionDataProtobuf,
@@ -74,7 +75,13 @@ impl FileDeleteTransaction {
     }
 }
 
-impl TransactionData for FileDeleteTransactionData {}
+impl TransactionData for FileDeleteTransactionData {
+    fn validate(&self) -> crate::Result<()> {
+        validate::require("file_id", &self.file_id)?;
+
+        Ok(())
+    }
+}
 
 impl TransactionExecute for FileDeleteTransactionData {
     fn execute(
@@ -31,6 +31,7 @@ use crate::protobuf::{
     ToProtobuf,
 };
 use crate::transaction::{
+    validate,
     AnyTransactionData,
     ChunkInfo,
     ToSchedulableTransactionDataProtobuf,
@@ -185,7 +186,18 @@ impl FileUpdateTransaction {
     }
 }
 
-impl TransactionData for FileUpdateTransactionData {}
+impl TransactionData for FileUpdateTransactionData {
+    fn validate(&self) -> crate::Result<()> {
+        validate::require("file_id", &self.file_id)?;
+        validate::memo("file_memo", self.file_memo.as_deref().unwrap_or_default())?;
+
+        if let Some(keys) = &self.keys {
+            keys.iter().try_for_each(|it| validate::key("keys", it))?;
+        }
+
+        Ok(())
+    }
+}
 
 impl TransactionExecute for FileUpdateTransactionData {
     fn execute(
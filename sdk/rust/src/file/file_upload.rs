@@ -0,0 +1,192 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use crate::{
+    AccountId,
+    Client,
+    Error,
+    FileAppendTransaction,
+    FileCreateTransaction,
+    FileId,
+    PublicKey,
+};
+
+/// The maximum number of (hex encoded) bytes put in the initial [`FileCreateTransaction`],
+/// the rest is appended with a [`FileAppendTransaction`].
+const FILE_CREATE_MAX_BYTES: usize = 2048;
+
+/// Uploads data too large for a single transaction to a new file, hex encoded,
+/// for the flows that reference it by file ID instead.
+///
+/// The file is created with a [`FileCreateTransaction`] holding the first [`FILE_CREATE_MAX_BYTES`],
+/// and the rest is appended with a [`FileAppendTransaction`].
+#[derive(Debug, Default, Clone, Copy)]
+pub(crate) struct FileUpload<'a> {
+    pub(crate) node_account_ids: Option<&'a [AccountId]>,
+    pub(crate) max_chunks: Option<usize>,
+}
+
+impl FileUpload<'_> {
+    /// Returns the key of `client`'s operator, which the flows make the file's key.
+    pub(crate) fn operator_key(client: &Client) -> crate::Result<PublicKey> {
+        client
+            .operator_internal()
+            .as_deref()
+            .map(|it| it.signer.public_key())
+            .ok_or(Error::NoPayerAccountOrTransactionId)
+    }
+
+    /// Splits the (hex encoded) `contents` into the contents of the [`FileCreateTransaction`]
+    /// and of the [`FileAppendTransaction`].
+    pub(crate) fn split_contents(contents: &[u8]) -> (&[u8], &[u8]) {
+        contents.split_at(contents.len().min(FILE_CREATE_MAX_BYTES))
+    }
+
+    /// Creates a file with `contents`, returning its ID.
+    pub(crate) async fn create(
+        &self,
+        client: &Client,
+        key: PublicKey,
+        contents: &[u8],
+    ) -> crate::Result<FileId> {
+        let file_id = self
+            .make_file_create_transaction(key, contents)
+            .execute(client)
+            .await?
+            .get_receipt(client)
+            .await?
+            .file_id;
+
+        file_id.ok_or_else(|| {
+            Error::from_protobuf("receipt for `FileCreateTransaction` is missing the file ID")
+        })
+    }
+
+    /// Appends `contents` to the file, if there are any.
+    pub(crate) async fn append(
+        &self,
+        client: &Client,
+        file_id: FileId,
+        contents: &[u8],
+    ) -> crate::Result<()> {
+        if !contents.is_empty() {
+            // note: `FileAppendTransaction` waits for the receipt of each chunk by itself.
+            self.make_file_append_transaction(file_id, contents).execute_all(client).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Uploads `data`, hex encoded, to a new file owned by the `client`'s operator, returning its ID.
+    pub(crate) async fn upload(&self, client: &Client, data: &[u8]) -> crate::Result<FileId> {
+        let key = Self::operator_key(client)?;
+
+        let contents = hex::encode(data).into_bytes();
+        let (create_contents, append_contents) = Self::split_contents(&contents);
+
+        let file_id = self.create(client, key, create_contents).await?;
+
+        self.append(client, file_id, append_contents).await?;
+
+        Ok(file_id)
+    }
+
+    pub(crate) fn make_file_create_transaction(
+        &self,
+        key: PublicKey,
+        contents: &[u8],
+    ) -> FileCreateTransaction {
+        let mut file_create = FileCreateTransaction::new();
+        file_create.keys([key]).contents(contents);
+
+        if let Some(node_account_ids) = self.node_account_ids {
+            file_create.node_account_ids(node_account_ids.iter().copied());
+        }
+
+        file_create
+    }
+
+    pub(crate) fn make_file_append_transaction(
+        &self,
+        file_id: FileId,
+        contents: &[u8],
+    ) -> FileAppendTransaction {
+        let mut file_append = FileAppendTransaction::new();
+        file_append.file_id(file_id).contents(contents);
+
+        if let Some(max_chunks) = self.max_chunks {
+            file_append.max_chunks(max_chunks);
+        }
+
+        if let Some(node_account_ids) = self.node_account_ids {
+            file_append.node_account_ids(node_account_ids.iter().copied());
+        }
+
+        file_append
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        FileUpload,
+        FILE_CREATE_MAX_BYTES,
+    };
+    use crate::{
+        AccountId,
+        FileId,
+        Key,
+        PrivateKey,
+    };
+
+    #[test]
+    fn split_contents() {
+        let contents = hex::encode([0xab; 1500]).into_bytes();
+
+        let (create, append) = FileUpload::split_contents(&contents);
+        assert_eq!(create.len(), FILE_CREATE_MAX_BYTES);
+        assert_eq!(append.len(), 3000 - FILE_CREATE_MAX_BYTES);
+        assert_eq!([create, append].concat(), contents);
+
+        let (create, append) = FileUpload::split_contents(b"6080");
+        assert_eq!(create, b"6080");
+        assert!(append.is_empty());
+    }
+
+    #[test]
+    fn transactions() {
+        let key = PrivateKey::generate_ed25519().public_key();
+        let file_id = FileId::from(1001);
+        let nodes = [AccountId::from(3), AccountId::from(4)];
+
+        let upload = FileUpload { node_account_ids: Some(&nodes), max_chunks: Some(3) };
+
+        let file_create = upload.make_file_create_transaction(key, b"6080");
+        assert_eq!(file_create.get_contents(), Some(&b"6080"[..]));
+        assert_eq!(file_create.get_keys().map(|it| &it.keys[..]), Some(&[Key::Single(key)][..]));
+        assert_eq!(file_create.get_node_account_ids(), Some(&nodes[..]));
+
+        let file_append = upload.make_file_append_transaction(file_id, b"604052");
+        assert_eq!(file_append.get_file_id(), Some(file_id));
+        assert_eq!(file_append.get_contents(), Some(&b"604052"[..]));
+        assert_eq!(file_append.get_max_chunks(), 3);
+        assert_eq!(file_append.get_node_account_ids(), Some(&nodes[..]));
+    }
+}
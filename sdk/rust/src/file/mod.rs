@@ -27,6 +27,7 @@ mod file_id;
 mod file_info;
 mod file_info_query;
 mod file_update_transaction;
+mod file_upload;
 
 pub use file_append_transaction::FileAppendTransaction;
 pub(crate) use file_append_transaction::FileAppendTransactionData;
@@ -43,3 +44,4 @@ pub use file_info_query::FileInfoQuery;
 pub(crate) use file_info_query::FileInfoQueryData;
 pub use file_update_transaction::FileUpdateTransaction;
 pub(crate) use file_update_transaction::FileUpdateTransactionData;
+pub(crate) use file_upload::FileUpload;
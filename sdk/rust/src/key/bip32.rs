@@ -0,0 +1,158 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+//! [BIP-32](https://github.com/bitcoin/bips/blob/master/bip-0032.mediawiki) child key derivation for secp256k1 keys.
+
+use hmac::{
+    Hmac,
+    Mac,
+};
+use k256::ecdsa::{
+    SigningKey,
+    VerifyingKey,
+};
+use k256::elliptic_curve::PrimeField;
+use k256::{
+    FieldBytes,
+    ProjectivePoint,
+    Scalar,
+};
+use sha2::Sha512;
+
+use crate::Error;
+
+/// Indexes with this bit set derive hardened child keys.
+pub(super) const HARDENED: u32 = 1 << 31;
+
+/// Computes the master key and chain code from a BIP-39 `seed`.
+pub(super) fn master(seed: &[u8]) -> crate::Result<(SigningKey, [u8; 32])> {
+    let invalid = || Error::key_derive("the seed produces an invalid master key");
+
+    let (scalar, chain_code) = hmac(b"Bitcoin seed", &[seed]).map_err(|_| invalid())?;
+
+    // fails if the master key is zero.
+    let key = SigningKey::from_bytes(&scalar.to_bytes()).map_err(|_| invalid())?;
+
+    Ok((key, chain_code))
+}
+
+/// Parses a derivation path such as `m/44'/60'/0'/0/0` into its indexes, with [`HARDENED`] set on hardened ones.
+///
+/// The leading `m` is optional, and hardened indexes may be marked with either `'`, `h` or `H`.
+pub(super) fn parse_path(path: &str) -> crate::Result<Vec<u32>> {
+    let invalid = || Error::key_derive(format!("invalid derivation path `{path}`"));
+
+    let rest = match path.strip_prefix('m') {
+        Some("") => return Ok(Vec::new()),
+        Some(rest) => rest.strip_prefix('/').ok_or_else(invalid)?,
+        None => path,
+    };
+
+    rest.split('/')
+        .map(|component| {
+            let (index, hardened) = match component.strip_suffix(&['\'', 'h', 'H'][..]) {
+                Some(index) => (index, true),
+                None => (component, false),
+            };
+
+            // `u32::from_str` would also accept a leading `+`.
+            if index.is_empty() || !index.bytes().all(|it| it.is_ascii_digit()) {
+                return Err(invalid());
+            }
+
+            let index: u32 = index.parse().map_err(|_| invalid())?;
+
+            if index & HARDENED != 0 {
+                return Err(invalid());
+            }
+
+            Ok(if hardened { index | HARDENED } else { index })
+        })
+        .collect()
+}
+
+/// Derives the child of the private `key` at `index`, returning it along with its chain code.
+pub(super) fn derive_private(
+    key: &SigningKey,
+    chain_code: &[u8; 32],
+    index: u32,
+) -> crate::Result<(SigningKey, [u8; 32])> {
+    let (tweak, chain_code) = if index & HARDENED == 0 {
+        hmac(chain_code, &[&key.verifying_key().to_bytes()[..], &index.to_be_bytes()])?
+    } else {
+        hmac(chain_code, &[&[0], &key.to_bytes()[..], &index.to_be_bytes()])?
+    };
+
+    // `key` is always a valid scalar.
+    let scalar = Option::<Scalar>::from(Scalar::from_repr(key.to_bytes())).unwrap();
+
+    // fails if the child key is zero.
+    let key = SigningKey::from_bytes(&(tweak + scalar).to_bytes()).map_err(|_| invalid_child())?;
+
+    Ok((key, chain_code))
+}
+
+/// Derives the child of the public `key` at the non-hardened `index`, returning it along with its chain code.
+pub(super) fn derive_public(
+    key: &VerifyingKey,
+    chain_code: &[u8; 32],
+    index: u32,
+) -> crate::Result<(VerifyingKey, [u8; 32])> {
+    if index & HARDENED != 0 {
+        return Err(Error::key_derive("public keys can't derive hardened child keys"));
+    }
+
+    let (tweak, chain_code) = hmac(chain_code, &[&key.to_bytes()[..], &index.to_be_bytes()])?;
+
+    let point = ProjectivePoint::GENERATOR * tweak + ProjectivePoint::from(key);
+
+    // fails if the child key is the point at infinity.
+    let key = VerifyingKey::try_from(point.to_affine()).map_err(|_| invalid_child())?;
+
+    Ok((key, chain_code))
+}
+
+/// Computes `HMAC-SHA512(key, data)`, returning the left half as a scalar and the right half as the child's chain code.
+///
+/// `key` is the parent's chain code, or `"Bitcoin seed"` for the master key.
+fn hmac(key: &[u8], data: &[&[u8]]) -> crate::Result<(Scalar, [u8; 32])> {
+    let mut mac = Hmac::<Sha512>::new_from_slice(key).expect("HMAC can take keys of any size");
+
+    for part in data {
+        mac.update(part);
+    }
+
+    let output: [u8; 64] = mac.finalize().into_bytes().into();
+
+    // todo: use `split_array_ref` when that's stable.
+    let (left, right) = output.split_at(32);
+
+    // fails if the left half is not less than the curve order.
+    let tweak = Option::<Scalar>::from(Scalar::from_repr(FieldBytes::clone_from_slice(left)))
+        .ok_or_else(invalid_child)?;
+
+    // this is exactly 32 bytes
+    Ok((tweak, right.try_into().unwrap()))
+}
+
+// BIP-32 says to proceed with the next index in this (astronomically unlikely) case.
+fn invalid_child() -> Error {
+    Error::key_derive("the derived key is invalid, derive the next index instead")
+}
@@ -0,0 +1,84 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use super::bip32;
+use crate::{
+    Error,
+    PublicKey,
+};
+
+/// An ECDSA(secp256k1) [`PublicKey`] with a BIP-32 chain code (an "xpub"),
+/// which can derive non-hardened child public keys without the private key.
+///
+/// Create one with [`PrivateKey::to_extended_public_key`](crate::PrivateKey::to_extended_public_key),
+/// or from a public key and chain code exported elsewhere with [`new`](Self::new).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ExtendedPublicKey {
+    public_key: PublicKey,
+    chain_code: [u8; 32],
+}
+
+impl ExtendedPublicKey {
+    /// Create an `ExtendedPublicKey` from an ECDSA `public_key` and its `chain_code`.
+    ///
+    /// # Errors
+    /// - [`Error::WrongKeyType`] if `public_key` is an Ed25519 key.
+    pub fn new(public_key: PublicKey, chain_code: [u8; 32]) -> crate::Result<Self> {
+        if !public_key.is_ecdsa() {
+            return Err(Error::WrongKeyType {
+                task: "create an extended public key",
+                key_enum: "PublicKey",
+                key_variant: "Ed25519",
+            });
+        }
+
+        Ok(Self { public_key, chain_code })
+    }
+
+    /// Returns the public key itself.
+    #[must_use]
+    pub fn public_key(&self) -> PublicKey {
+        self.public_key
+    }
+
+    /// Returns the chain code of the public key.
+    #[must_use]
+    pub fn chain_code(&self) -> [u8; 32] {
+        self.chain_code
+    }
+
+    /// Derives the child public key at `index`, which matches the public key of
+    /// [`PrivateKey::derive`](crate::PrivateKey::derive) with the same `index`.
+    ///
+    /// # Errors
+    /// - [`Error::KeyDerive`] if `index` is hardened (negative), only private keys can derive those.
+    /// - [`Error::KeyDerive`] if the derived key is invalid (astronomically unlikely), derive the next index instead.
+    pub fn derive(&self, index: i32) -> crate::Result<Self> {
+        // `new` ensures this is an ECDSA key.
+        let key = match self.public_key.as_ecdsa() {
+            Some(key) => key,
+            None => return Err(Error::key_derive("only ECDSA public keys can be derived")),
+        };
+
+        let (key, chain_code) = bip32::derive_public(key, &self.chain_code, index as u32)?;
+
+        Ok(Self { public_key: PublicKey::ecdsa(key), chain_code })
+    }
+}
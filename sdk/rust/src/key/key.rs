@@ -25,8 +25,10 @@ use crate::{
     Error,
     FromProtobuf,
     KeyList,
+    KeySignatureReport,
     PublicKey,
     ToProtobuf,
+    Transaction,
 };
 
 /// Any method that can be used to authorize an operation on Hedera.
@@ -54,6 +56,38 @@ impl Key {
     pub fn to_bytes(&self) -> Vec<u8> {
         ToProtobuf::to_bytes(self)
     }
+
+    /// Returns `true` if the signatures on `transaction` satisfy this key.
+    ///
+    /// Signatures already present in the signed transaction (for instance, one from [`Transaction::from_bytes`]) are counted,
+    /// as are the signers added with [`Transaction::sign`] and the operator, once the transaction is frozen.
+    ///
+    /// See [`signature_report`](Self::signature_report) for how key lists and contract keys are handled.
+    #[must_use]
+    pub fn is_satisfied_by<D: crate::transaction::TransactionExecute>(
+        &self,
+        transaction: &Transaction<D>,
+    ) -> bool {
+        self.signature_report(transaction).satisfied
+    }
+
+    /// Returns which public keys in this key have signed `transaction` and which are still missing.
+    ///
+    /// Key lists require all of their keys to sign, unless they have a threshold,
+    /// in which case only `threshold` of their keys must sign. Key lists can be nested.
+    ///
+    /// Contract keys can't be satisfied by signatures, so they are ignored.
+    #[must_use]
+    pub fn signature_report<D: crate::transaction::TransactionExecute>(
+        &self,
+        transaction: &Transaction<D>,
+    ) -> KeySignatureReport {
+        let sources = transaction.signed_sources();
+
+        KeySignatureReport::new(self, &|key| {
+            sources.as_ref().map_or(false, |it| it.is_signed_by(key))
+        })
+    }
 }
 
 impl ToProtobuf for Key {
@@ -0,0 +1,853 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::str::FromStr;
+
+use aes::cipher::generic_array::GenericArray;
+use aes::cipher::{
+    KeyIvInit,
+    StreamCipher,
+};
+use hmac::{
+    Hmac,
+    Mac,
+};
+use rand::{
+    thread_rng,
+    RngCore,
+};
+use salsa20::cipher::typenum::U4;
+use salsa20::cipher::StreamCipherCore;
+use salsa20::SalsaCore;
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use sha2::{
+    Sha256,
+    Sha384,
+};
+use sha3::{
+    Digest,
+    Keccak256,
+};
+use subtle::ConstantTimeEq;
+
+use crate::{
+    Error,
+    Pbes2Kdf,
+    PrivateKey,
+};
+
+type Aes128Ctr = ctr::Ctr128BE<aes::Aes128>;
+
+/// The number of PBKDF2 iterations the other Hedera SDKs use for their keystores.
+const HEDERA_ITERATIONS: u32 = 262_144;
+
+/// The scrypt parameters Web3 wallets (geth, ethers, ...) use by default.
+const WEB3_KDF: Pbes2Kdf = Pbes2Kdf::Scrypt { log_n: 18, r: 8, p: 1 };
+
+/// The most PBKDF2 iterations a keystore may use, 16 times [`HEDERA_ITERATIONS`].
+///
+/// Keystores are untrusted input, so this (and the scrypt limits below) stop a keystore from making
+/// [`Keystore::decrypt`] spin for hours or exhaust memory.
+const MAX_PBKDF2_ITERATIONS: u32 = 1 << 22;
+
+/// The most memory scrypt may use, 1 GiB.
+///
+/// scrypt uses `128 * r * n` bytes for its table, and `128 * r * p` bytes for its blocks.
+const MAX_SCRYPT_MEMORY: u64 = 1 << 30;
+
+/// The most parallel scrypt lanes (`p`) a keystore may use.
+const MAX_SCRYPT_P: u32 = 16;
+
+/// A password protected [`PrivateKey`], serialized as JSON in one of two formats:
+/// - The keystore format of the other Hedera SDKs (version 1), which supports both Ed25519 and ECDSA keys.
+/// - Ethereum's [Web3 Secret Storage](https://ethereum.org/en/developers/docs/data-structures-and-encoding/web3-secret-storage/)
+///   format (version 3), which only supports ECDSA keys.
+///
+/// # Examples
+///
+/// ```
+/// # fn main() -> hedera::Result<()> {
+/// use hedera::{
+///     Keystore,
+///     Pbes2Kdf,
+///     PrivateKey,
+/// };
+///
+/// let key = PrivateKey::generate_ecdsa();
+///
+/// // cheap parameters to keep the example fast, use `Keystore::new_web3` in your applications.
+/// let kdf = Pbes2Kdf::Scrypt { log_n: 10, r: 8, p: 1 };
+/// let json = Keystore::new_web3_with_kdf(&key, "password", kdf)?.to_json();
+///
+/// let keystore = Keystore::from_json(&json)?;
+/// assert_eq!(keystore.decrypt("password")?.to_bytes(), key.to_bytes());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Keystore(KeystoreData);
+
+impl Keystore {
+    /// Encrypts `private_key` with `password` in the keystore format of the other Hedera SDKs.
+    ///
+    /// The key is encrypted with AES-128-CTR, using a key derived from `password` with PBKDF2-HMAC-SHA256.
+    #[must_use]
+    pub fn new(private_key: &PrivateKey, password: impl AsRef<[u8]>) -> Self {
+        Self::new_hedera(private_key, password.as_ref(), HEDERA_ITERATIONS)
+    }
+
+    fn new_hedera(private_key: &PrivateKey, password: &[u8], iterations: u32) -> Self {
+        let salt: [u8; 32] = random_bytes();
+        let iv: [u8; 16] = random_bytes();
+
+        let key = pbkdf2_sha256(password, &salt, iterations);
+
+        // like `PrivateKey.toBytes()` in the Java SDK, which the other Hedera SDKs encrypt:
+        // raw Ed25519 keys and DER encoded ECDSA keys, which `PrivateKey::from_bytes` can tell apart again.
+        let mut ciphertext = private_key.to_bytes();
+        aes_128_ctr(&key, &iv, &mut ciphertext);
+
+        let mac = hedera_mac(&key, &ciphertext).finalize().into_bytes();
+
+        Self(KeystoreData::Hedera(HederaKeystore {
+            version: 1,
+            crypto: HederaCrypto {
+                ciphertext: hex::encode(ciphertext),
+                cipherparams: CipherParams { iv: hex::encode(iv) },
+                cipher: "AES-128-CTR".to_owned(),
+                kdf: "pbkdf2".to_owned(),
+                kdfparams: HederaKdfParams {
+                    dk_len: 32,
+                    salt: hex::encode(salt),
+                    c: iterations,
+                    prf: "hmac-sha256".to_owned(),
+                },
+                mac: hex::encode(mac),
+            },
+        }))
+    }
+
+    /// Encrypts the ECDSA `private_key` with `password` in the Web3 Secret Storage format,
+    /// using the scrypt parameters most Web3 wallets use (`n = 2^18`, `r = 8`, `p = 1`).
+    ///
+    /// # Errors
+    /// - [`Error::WrongKeyType`] if `private_key` is an Ed25519 key.
+    pub fn new_web3(private_key: &PrivateKey, password: impl AsRef<[u8]>) -> crate::Result<Self> {
+        Self::new_web3_with_kdf(private_key, password, WEB3_KDF)
+    }
+
+    /// Encrypts the ECDSA `private_key` with `password` in the Web3 Secret Storage format,
+    /// using `kdf` to derive the AES-128-CTR key from `password`.
+    ///
+    /// # Errors
+    /// - [`Error::WrongKeyType`] if `private_key` is an Ed25519 key.
+    /// - [`Error::KeyEncrypt`] if the parameters of `kdf` are invalid.
+    pub fn new_web3_with_kdf(
+        private_key: &PrivateKey,
+        password: impl AsRef<[u8]>,
+        kdf: Pbes2Kdf,
+    ) -> crate::Result<Self> {
+        if !private_key.is_ecdsa() {
+            return Err(Error::WrongKeyType {
+                task: "create a Web3 keystore",
+                key_enum: "PrivateKey",
+                key_variant: "Ed25519",
+            });
+        }
+
+        let salt = hex::encode(random_bytes::<32>());
+        let iv: [u8; 16] = random_bytes();
+
+        let kdfparams = match kdf {
+            Pbes2Kdf::Pbkdf2 { iterations } => Web3KdfParams::Pbkdf2 {
+                c: iterations,
+                dklen: 32,
+                prf: "hmac-sha256".to_owned(),
+                salt,
+            },
+            Pbes2Kdf::Scrypt { log_n, r, p } => Web3KdfParams::Scrypt {
+                dklen: 32,
+                n: 1_u64
+                    .checked_shl(u32::from(log_n))
+                    .ok_or_else(|| Error::key_encrypt("scrypt `log_n` is too large"))?,
+                p,
+                r,
+                salt,
+            },
+        };
+
+        let key = kdfparams.derive_key(password.as_ref()).map_err(Error::key_encrypt)?;
+
+        let mut ciphertext = private_key.to_bytes_raw();
+        aes_128_ctr(&key, &iv, &mut ciphertext);
+
+        let mac = web3_mac(&key, &ciphertext);
+
+        let address = private_key.public_key().ethereum_address().map(|it| hex::encode(it.0));
+
+        Ok(Self(KeystoreData::Web3(Web3Keystore {
+            crypto: Web3Crypto {
+                cipher: "aes-128-ctr".to_owned(),
+                cipherparams: CipherParams { iv: hex::encode(iv) },
+                ciphertext: hex::encode(ciphertext),
+                kdf: kdfparams.name().to_owned(),
+                kdfparams,
+                mac: hex::encode(mac),
+            },
+            id: random_uuid(),
+            version: 3,
+            address,
+        })))
+    }
+
+    /// Parse a `Keystore` from JSON, in either of the supported formats.
+    ///
+    /// # Errors
+    /// - [`Error::KeyParse`] if `json` is not a keystore of a supported version.
+    pub fn from_json(json: &str) -> crate::Result<Self> {
+        let value: serde_json::Value = serde_json::from_str(json).map_err(Error::key_parse)?;
+
+        let data = match value.get("version").and_then(serde_json::Value::as_u64) {
+            Some(1) => {
+                KeystoreData::Hedera(serde_json::from_value(value).map_err(Error::key_parse)?)
+            }
+            Some(3) => KeystoreData::Web3(serde_json::from_value(value).map_err(Error::key_parse)?),
+            Some(version) => {
+                return Err(Error::key_parse(format!("unsupported keystore version `{version}`")))
+            }
+            None => return Err(Error::key_parse("keystore has no `version`")),
+        };
+
+        Ok(Self(data))
+    }
+
+    /// Serialize this `Keystore` as JSON.
+    // panic should be impossible (`unreachable`)
+    #[allow(clippy::missing_panics_doc)]
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        match &self.0 {
+            KeystoreData::Hedera(it) => serde_json::to_string(it),
+            KeystoreData::Web3(it) => serde_json::to_string(it),
+        }
+        .unwrap()
+    }
+
+    /// Decrypts the [`PrivateKey`] in this keystore with `password`.
+    ///
+    /// # Errors
+    /// - [`Error::KeyParse`] if `password` is incorrect.
+    /// - [`Error::KeyParse`] if the keystore uses an unsupported cipher or key derivation function, or is otherwise malformed.
+    /// - [`Error::KeyParse`] if the key derivation parameters are unreasonably expensive
+    ///   (more than 4,194,304 PBKDF2 iterations, or scrypt using more than 1 GiB of memory or more than 16 lanes).
+    pub fn decrypt(&self, password: impl AsRef<[u8]>) -> crate::Result<PrivateKey> {
+        match &self.0 {
+            KeystoreData::Hedera(it) => it.decrypt(password.as_ref()),
+            KeystoreData::Web3(it) => it.decrypt(password.as_ref()),
+        }
+    }
+}
+
+impl FromStr for Keystore {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_json(s)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum KeystoreData {
+    Hedera(HederaKeystore),
+    Web3(Web3Keystore),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct CipherParams {
+    iv: String,
+}
+
+impl CipherParams {
+    fn iv(&self) -> crate::Result<[u8; 16]> {
+        decode_hex(&self.iv)?.try_into().map_err(|_| Error::key_parse("IV must be 16 bytes"))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct HederaKeystore {
+    version: u8,
+    crypto: HederaCrypto,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct HederaCrypto {
+    ciphertext: String,
+    cipherparams: CipherParams,
+    cipher: String,
+    kdf: String,
+    kdfparams: HederaKdfParams,
+    mac: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct HederaKdfParams {
+    dk_len: usize,
+    salt: String,
+    c: u32,
+    prf: String,
+}
+
+impl HederaKeystore {
+    fn decrypt(&self, password: &[u8]) -> crate::Result<PrivateKey> {
+        let crypto = &self.crypto;
+        let params = &crypto.kdfparams;
+
+        // the other SDKs write the cipher in upper case.
+        if !crypto.cipher.eq_ignore_ascii_case("aes-128-ctr") {
+            return Err(unsupported("cipher", &crypto.cipher));
+        }
+
+        if crypto.kdf != "pbkdf2" {
+            return Err(unsupported("kdf", &crypto.kdf));
+        }
+
+        if params.prf != "hmac-sha256" {
+            return Err(unsupported("prf", &params.prf));
+        }
+
+        if params.dk_len != 32 || params.c == 0 {
+            return Err(Error::key_parse("invalid pbkdf2 parameters"));
+        }
+
+        if params.c > MAX_PBKDF2_ITERATIONS {
+            return Err(Error::key_parse(format!("too many pbkdf2 iterations `{}`", params.c)));
+        }
+
+        let key = pbkdf2_sha256(password, &decode_hex(&params.salt)?, params.c);
+
+        let mut data = decode_hex(&crypto.ciphertext)?;
+
+        hedera_mac(&key, &data)
+            .verify_slice(&decode_hex(&crypto.mac)?)
+            .map_err(|_| mac_mismatch())?;
+
+        aes_128_ctr(&key, &crypto.cipherparams.iv()?, &mut data);
+
+        PrivateKey::from_bytes(&data)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct Web3Keystore {
+    // some older wallets capitalize this.
+    #[serde(alias = "Crypto")]
+    crypto: Web3Crypto,
+    #[serde(default)]
+    id: String,
+    version: u8,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    address: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct Web3Crypto {
+    cipher: String,
+    cipherparams: CipherParams,
+    ciphertext: String,
+    kdf: String,
+    kdfparams: Web3KdfParams,
+    mac: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+enum Web3KdfParams {
+    Scrypt { dklen: usize, n: u64, p: u32, r: u32, salt: String },
+    Pbkdf2 { c: u32, dklen: usize, prf: String, salt: String },
+}
+
+impl Web3KdfParams {
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Scrypt { .. } => "scrypt",
+            Self::Pbkdf2 { .. } => "pbkdf2",
+        }
+    }
+
+    fn derive_key(&self, password: &[u8]) -> Result<[u8; 32], String> {
+        match self {
+            Self::Scrypt { dklen: 32, n, p, r, salt } => {
+                if *n < 2 || !n.is_power_of_two() || *r == 0 || *p == 0 {
+                    return Err(format!("invalid scrypt parameters `n = {n}, r = {r}, p = {p}`"));
+                }
+
+                let memory = 128_u64
+                    .saturating_mul(u64::from(*r))
+                    .saturating_mul(n.saturating_add(u64::from(*p)));
+
+                if memory > MAX_SCRYPT_MEMORY || *p > MAX_SCRYPT_P {
+                    return Err(format!(
+                        "scrypt parameters `n = {n}, r = {r}, p = {p}` are too expensive"
+                    ));
+                }
+
+                let salt = hex::decode(salt).map_err(|e| e.to_string())?;
+
+                // all three fit in a `usize` now that they're bounded.
+                let [n, r, p] = [*n, u64::from(*r), u64::from(*p)]
+                    .map(|it| usize::try_from(it).map_err(|e| e.to_string()));
+
+                Ok(scrypt(password, &salt, n?, r?, p?))
+            }
+
+            Self::Pbkdf2 { c, .. } if *c > MAX_PBKDF2_ITERATIONS => {
+                Err(format!("too many pbkdf2 iterations `{c}`"))
+            }
+
+            Self::Pbkdf2 { c, dklen: 32, prf, salt } if prf == "hmac-sha256" && *c > 0 => {
+                let salt = hex::decode(salt).map_err(|e| e.to_string())?;
+
+                Ok(pbkdf2_sha256(password, &salt, *c))
+            }
+
+            _ => Err(format!("unsupported {} parameters", self.name())),
+        }
+    }
+}
+
+impl Web3Keystore {
+    fn decrypt(&self, password: &[u8]) -> crate::Result<PrivateKey> {
+        let crypto = &self.crypto;
+
+        if crypto.cipher != "aes-128-ctr" {
+            return Err(unsupported("cipher", &crypto.cipher));
+        }
+
+        if crypto.kdf != crypto.kdfparams.name() {
+            return Err(unsupported("kdf", &crypto.kdf));
+        }
+
+        let key = crypto.kdfparams.derive_key(password).map_err(Error::key_parse)?;
+
+        let mut data = decode_hex(&crypto.ciphertext)?;
+
+        if !bool::from(web3_mac(&key, &data)[..].ct_eq(&decode_hex(&crypto.mac)?)) {
+            return Err(mac_mismatch());
+        }
+
+        aes_128_ctr(&key, &crypto.cipherparams.iv()?, &mut data);
+
+        PrivateKey::from_bytes_ecdsa(&data)
+    }
+}
+
+fn random_bytes<const N: usize>() -> [u8; N] {
+    let mut bytes = [0; N];
+    thread_rng().fill_bytes(&mut bytes);
+    bytes
+}
+
+/// A random (version 4) UUID.
+fn random_uuid() -> String {
+    let mut bytes: [u8; 16] = random_bytes();
+    bytes[6] = (bytes[6] & 0x0f) | 0x40;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+
+    let bytes = hex::encode(bytes);
+
+    format!(
+        "{}-{}-{}-{}-{}",
+        &bytes[..8],
+        &bytes[8..12],
+        &bytes[12..16],
+        &bytes[16..20],
+        &bytes[20..]
+    )
+}
+
+fn pbkdf2_sha256(password: &[u8], salt: &[u8], iterations: u32) -> [u8; 32] {
+    let mut key = [0; 32];
+    pbkdf2::pbkdf2::<Hmac<Sha256>>(password, salt, iterations, &mut key);
+    key
+}
+
+/// `scrypt` with a 32 byte output.
+///
+/// The `scrypt` crate enforces RFC 7914's `n < 2^(16 * r)`, which Web3 wallets don't
+/// (the official test vector uses `n = 2^18, r = 1`), so this only borrows its Salsa20/8 core.
+fn scrypt(password: &[u8], salt: &[u8], n: usize, r: usize, p: usize) -> [u8; 32] {
+    let block_len = 128 * r;
+
+    let mut blocks = vec![0; block_len * p];
+    pbkdf2::pbkdf2::<Hmac<Sha256>>(password, salt, 1, &mut blocks);
+
+    let mut table = vec![0; block_len * n];
+    let mut scratch = vec![0; block_len];
+
+    for block in blocks.chunks_exact_mut(block_len) {
+        scrypt_ro_mix(block, &mut table, &mut scratch, n);
+    }
+
+    pbkdf2_sha256(password, &blocks, 1)
+}
+
+/// `scryptROMix` from RFC 7914 on `block`, using `table` (`n` blocks) and `scratch` (one block).
+fn scrypt_ro_mix(block: &mut [u8], table: &mut [u8], scratch: &mut [u8], n: usize) {
+    let len = block.len();
+
+    for entry in table.chunks_exact_mut(len) {
+        entry.copy_from_slice(block);
+        scrypt_block_mix(entry, block);
+    }
+
+    for _ in 0..n {
+        let last = &block[len - 64..];
+        let index = u32::from_le_bytes([last[0], last[1], last[2], last[3]]) as usize & (n - 1);
+
+        let entry = &table[index * len..(index + 1) * len];
+
+        for ((out, lhs), rhs) in scratch.iter_mut().zip(&*block).zip(entry) {
+            *out = lhs ^ rhs;
+        }
+
+        scrypt_block_mix(scratch, block);
+    }
+}
+
+/// `scryptBlockMix` from RFC 7914.
+fn scrypt_block_mix(input: &[u8], output: &mut [u8]) {
+    let half = input.len() / 2;
+
+    let mut x = [0; 64];
+    x.copy_from_slice(&input[input.len() - 64..]);
+
+    for (i, chunk) in input.chunks_exact(64).enumerate() {
+        let mut state = [0; 16];
+
+        for ((word, x), chunk) in state.iter_mut().zip(x.chunks_exact(4)).zip(chunk.chunks_exact(4))
+        {
+            *word = u32::from_le_bytes([
+                x[0] ^ chunk[0],
+                x[1] ^ chunk[1],
+                x[2] ^ chunk[2],
+                x[3] ^ chunk[3],
+            ]);
+        }
+
+        SalsaCore::<U4>::from_raw_state(state)
+            .write_keystream_block(GenericArray::from_mut_slice(&mut x));
+
+        let pos = (i / 2) * 64 + if i % 2 == 0 { 0 } else { half };
+        output[pos..pos + 64].copy_from_slice(&x);
+    }
+}
+
+/// Applies AES-128-CTR keyed with the first half of `key`.
+fn aes_128_ctr(key: &[u8; 32], iv: &[u8; 16], data: &mut [u8]) {
+    Aes128Ctr::new(GenericArray::from_slice(&key[..16]), GenericArray::from_slice(iv))
+        .apply_keystream(data);
+}
+
+/// `HMAC-SHA384` keyed with the second half of `key` over `ciphertext`.
+fn hedera_mac(key: &[u8; 32], ciphertext: &[u8]) -> Hmac<Sha384> {
+    Hmac::<Sha384>::new_from_slice(&key[16..])
+        .expect("HMAC can take keys of any size")
+        .chain_update(ciphertext)
+}
+
+/// `keccak256` of the second half of `key` followed by `ciphertext`.
+fn web3_mac(key: &[u8; 32], ciphertext: &[u8]) -> [u8; 32] {
+    Keccak256::new().chain_update(&key[16..]).chain_update(ciphertext).finalize().into()
+}
+
+fn decode_hex(s: &str) -> crate::Result<Vec<u8>> {
+    hex::decode(s.strip_prefix("0x").unwrap_or(s)).map_err(Error::key_parse)
+}
+
+fn unsupported(field: &str, value: &str) -> Error {
+    Error::key_parse(format!("unsupported keystore {field} `{value}`"))
+}
+
+fn mac_mismatch() -> Error {
+    Error::key_parse("keystore MAC mismatch, the password is probably incorrect")
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+    use hex_literal::hex;
+
+    use super::{
+        aes_128_ctr,
+        pbkdf2_sha256,
+        Keystore,
+        KeystoreData,
+    };
+    use crate::{
+        Error,
+        Pbes2Kdf,
+        PrivateKey,
+    };
+
+    const PASSWORD: &str = "testpassword";
+
+    const ECDSA_KEY: [u8; 32] =
+        hex!("7a28b5ba57c53603b0b07b56bba752f7784bf506fa95edc395f5cf6c7514fe9d");
+
+    // the test vectors from the Web3 Secret Storage definition, unmodified.
+    const WEB3_SCRYPT: &str = r#"{
+        "crypto" : {
+            "cipher" : "aes-128-ctr",
+            "cipherparams" : {
+                "iv" : "83dbcc02d8ccb40e466191a123791e0e"
+            },
+            "ciphertext" : "d172bf743a674da9cdad04534d56926ef8358534d458fffccd4e6ad2fbde479c",
+            "kdf" : "scrypt",
+            "kdfparams" : {
+                "dklen" : 32,
+                "n" : 262144,
+                "p" : 8,
+                "r" : 1,
+                "salt" : "ab0c7876052600dd703518d6fc3fe8984592145b591fc8fb5c6d43190334ba19"
+            },
+            "mac" : "2103ac29920d71da29f15d75b4a16dbe95cfd7ff8faea1056c33131d846e3097"
+        },
+        "id" : "3198bc9c-6672-5ab3-d995-4942343ae5b6",
+        "version" : 3
+    }"#;
+
+    const WEB3_PBKDF2: &str = r#"{
+        "crypto" : {
+            "cipher" : "aes-128-ctr",
+            "cipherparams" : {
+                "iv" : "6087dab2f9fdbbfaddc31a909735c1e6"
+            },
+            "ciphertext" : "5318b4d5bcd28de64ee5559e671353e16f075ecae9f99c7a79a38af5f869aa46",
+            "kdf" : "pbkdf2",
+            "kdfparams" : {
+                "c" : 262144,
+                "dklen" : 32,
+                "prf" : "hmac-sha256",
+                "salt" : "ae3cd4e7013836a3df6bd7241b12db061dbe2c6785853cce422d148a624ce0bd"
+            },
+            "mac" : "517ead924a9d0dc3124507e3393d175ce3ff7c1e96529c6c555ce9e51205e9b2"
+        },
+        "id" : "3198bc9c-6672-5ab3-d995-4942343ae5b6",
+        "version" : 3
+    }"#;
+
+    // encrypted by this SDK, with 1024 iterations instead of 262,144 to keep the tests fast.
+    const HEDERA_ED25519: &str = r#"{
+        "version": 1,
+        "crypto": {
+            "ciphertext": "834ec3dd57389c87fb8da481f0111526e846a2903d1ddf09dd7246bfc6f9159f",
+            "cipherparams": { "iv": "6087dab2f9fdbbfaddc31a909735c1e6" },
+            "cipher": "AES-128-CTR",
+            "kdf": "pbkdf2",
+            "kdfparams": {
+                "dkLen": 32,
+                "salt": "ae3cd4e7013836a3df6bd7241b12db061dbe2c6785853cce422d148a624ce0bd",
+                "c": 1024,
+                "prf": "hmac-sha256"
+            },
+            "mac": "8034e65512db8ec8719ea7298f632e098153a82d295d5d5e5d5f539187d3f12a123f6a92513477bdbf0d07cde1e5672a"
+        }
+    }"#;
+
+    const HEDERA_ECDSA: &str = r#"{
+        "version": 1,
+        "crypto": {
+            "ciphertext": "68368a5ed96c29590f8ac64550f22b0f60a85f17402ad7611c5abef6e8a99f2d87d182d1bc55698cd5c8ee26488f19026a7b",
+            "cipherparams": { "iv": "6087dab2f9fdbbfaddc31a909735c1e6" },
+            "cipher": "AES-128-CTR",
+            "kdf": "pbkdf2",
+            "kdfparams": {
+                "dkLen": 32,
+                "salt": "ae3cd4e7013836a3df6bd7241b12db061dbe2c6785853cce422d148a624ce0bd",
+                "c": 1024,
+                "prf": "hmac-sha256"
+            },
+            "mac": "ac8354cbcd32ead2c27b24e4a9cc2aa3c1ce1ab7f809e4b5c073227f22470d652800340b5166ebf002cfe1044d60b3e8"
+        }
+    }"#;
+
+    #[test]
+    fn decrypt_web3_scrypt() {
+        let key = Keystore::from_json(WEB3_SCRYPT).unwrap().decrypt(PASSWORD).unwrap();
+
+        assert!(key.is_ecdsa());
+        assert_eq!(key.to_bytes_raw(), ECDSA_KEY);
+    }
+
+    #[test]
+    fn decrypt_web3_pbkdf2() {
+        let key = Keystore::from_json(WEB3_PBKDF2).unwrap().decrypt(PASSWORD).unwrap();
+
+        assert_eq!(key.to_bytes_raw(), ECDSA_KEY);
+    }
+
+    #[test]
+    fn decrypt_hedera_ed25519() {
+        let key = Keystore::from_json(HEDERA_ED25519).unwrap().decrypt(PASSWORD).unwrap();
+
+        assert!(key.is_ed25519());
+        assert_eq!(
+            key.to_bytes_raw(),
+            hex!("db484b828e64b2d8f12ce3c0a0e93a0b8cce7af1bb8f39c97732394482538e10")
+        );
+    }
+
+    #[test]
+    fn decrypt_hedera_ecdsa() {
+        let key = Keystore::from_json(HEDERA_ECDSA).unwrap().decrypt(PASSWORD).unwrap();
+
+        assert!(key.is_ecdsa());
+        assert_eq!(
+            key.to_bytes_raw(),
+            hex!("8776c6b831a1b61ac10dac0304a2843de4716f54b1919bb91a2685d0fe3f3048")
+        );
+    }
+
+    /// Decrypts the key bytes stored in the Hedera keystore `json`, without parsing them.
+    fn hedera_plaintext(json: &str) -> Vec<u8> {
+        let keystore = Keystore::from_json(json).unwrap();
+        let crypto = &assert_matches!(&keystore.0, KeystoreData::Hedera(it) => it).crypto;
+
+        let salt = hex::decode(&crypto.kdfparams.salt).unwrap();
+        let key = pbkdf2_sha256(PASSWORD.as_bytes(), &salt, crypto.kdfparams.c);
+
+        let mut data = hex::decode(&crypto.ciphertext).unwrap();
+        aes_128_ctr(&key, &crypto.cipherparams.iv().unwrap(), &mut data);
+
+        data
+    }
+
+    #[test]
+    fn hedera_key_encoding() {
+        let ed25519 = Keystore::from_json(HEDERA_ED25519).unwrap().decrypt(PASSWORD).unwrap();
+        let ecdsa = Keystore::from_json(HEDERA_ECDSA).unwrap().decrypt(PASSWORD).unwrap();
+
+        assert_eq!(hedera_plaintext(HEDERA_ED25519), ed25519.to_bytes_raw());
+        assert_eq!(hedera_plaintext(HEDERA_ECDSA), ecdsa.to_bytes_der());
+
+        for key in [PrivateKey::generate_ed25519(), PrivateKey::generate_ecdsa()] {
+            let json = Keystore::new_hedera(&key, PASSWORD.as_bytes(), 1024).to_json();
+
+            let expected = if key.is_ecdsa() { key.to_bytes_der() } else { key.to_bytes_raw() };
+
+            assert_eq!(hedera_plaintext(&json), expected);
+        }
+    }
+
+    #[test]
+    fn decrypt_wrong_password() {
+        // `web3_round_trip` covers scrypt with cheaper parameters than `WEB3_SCRYPT`.
+        for json in [WEB3_PBKDF2, HEDERA_ED25519, HEDERA_ECDSA] {
+            let keystore = Keystore::from_json(json).unwrap();
+
+            assert_matches!(keystore.decrypt("wrongpassword"), Err(Error::KeyParse(_)));
+        }
+    }
+
+    #[test]
+    fn hedera_round_trip() {
+        for key in [PrivateKey::generate_ed25519(), PrivateKey::generate_ecdsa()] {
+            let json = Keystore::new_hedera(&key, PASSWORD.as_bytes(), 1024).to_json();
+
+            let decrypted = json.parse::<Keystore>().unwrap().decrypt(PASSWORD).unwrap();
+
+            assert_eq!(decrypted.to_bytes(), key.to_bytes());
+        }
+    }
+
+    #[test]
+    fn web3_round_trip() {
+        let key = PrivateKey::from_bytes_ecdsa(&ECDSA_KEY).unwrap();
+
+        for kdf in
+            [Pbes2Kdf::Scrypt { log_n: 10, r: 8, p: 1 }, Pbes2Kdf::Pbkdf2 { iterations: 1024 }]
+        {
+            let json = Keystore::new_web3_with_kdf(&key, PASSWORD, kdf).unwrap().to_json();
+
+            let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+            assert_eq!(value["address"], "008aeeda4d805471df9b2a5b0f38a0c3bcba786b");
+
+            let keystore = Keystore::from_json(&json).unwrap();
+
+            assert_eq!(keystore.decrypt(PASSWORD).unwrap().to_bytes_raw(), ECDSA_KEY);
+            assert_matches!(keystore.decrypt("wrongpassword"), Err(Error::KeyParse(_)));
+        }
+    }
+
+    #[test]
+    fn web3_ed25519_fails() {
+        let key = PrivateKey::generate_ed25519();
+
+        assert_matches!(Keystore::new_web3(&key, PASSWORD), Err(Error::WrongKeyType { .. }));
+    }
+
+    #[test]
+    fn web3_invalid_kdf() {
+        let key = PrivateKey::generate_ecdsa();
+        let kdf = Pbes2Kdf::Scrypt { log_n: 64, r: 8, p: 1 };
+
+        assert_matches!(
+            Keystore::new_web3_with_kdf(&key, PASSWORD, kdf),
+            Err(Error::KeyEncrypt(_))
+        );
+    }
+
+    #[test]
+    fn scrypt_rfc_7914() {
+        assert_eq!(
+            super::scrypt(b"password", b"NaCl", 1024, 8, 16),
+            hex!("fdbabe1c9d3472007856e7190d01e9fe7c6ad7cbc8237830e77376634b373162")
+        );
+    }
+
+    #[test]
+    fn decrypt_too_expensive() {
+        let scrypt = WEB3_SCRYPT.replace("262144", "4294967296");
+        let web3_pbkdf2 = WEB3_PBKDF2.replace("262144", "4294967295");
+        let hedera = HEDERA_ED25519.replace("1024", "4294967295");
+        let lanes = WEB3_SCRYPT.replace(r#""p" : 8"#, r#""p" : 4294967295"#);
+        // a tiny table, but 16 lanes of 512 MiB blocks.
+        let blocks = WEB3_SCRYPT
+            .replace("262144", "2")
+            .replace(r#""r" : 1"#, r#""r" : 4194304"#)
+            .replace(r#""p" : 8"#, r#""p" : 16"#);
+
+        for json in [scrypt, web3_pbkdf2, hedera, lanes, blocks] {
+            let keystore = Keystore::from_json(&json).unwrap();
+
+            assert_matches!(keystore.decrypt(PASSWORD), Err(Error::KeyParse(_)));
+        }
+    }
+
+    #[test]
+    fn from_json_unsupported_version() {
+        assert_matches!(Keystore::from_json(r#"{"version": 2}"#), Err(Error::KeyParse(_)));
+        assert_matches!(Keystore::from_json("{}"), Err(Error::KeyParse(_)));
+    }
+}
@@ -18,16 +18,25 @@
  * ‍
  */
 
+mod bip32;
+mod extended_public_key;
 #[allow(clippy::module_inception)]
 mod key;
 mod key_list;
+mod keystore;
+mod pbes2_kdf;
 mod private_key;
 mod public_key;
+mod signature_report;
 
+pub use extended_public_key::ExtendedPublicKey;
 pub use key::Key;
 pub use key_list::KeyList;
+pub use keystore::Keystore;
+pub use pbes2_kdf::Pbes2Kdf;
 pub use private_key::PrivateKey;
 pub use public_key::PublicKey;
+pub use signature_report::KeySignatureReport;
 
 #[derive(Copy, Clone)]
 pub(crate) enum KeyKind {
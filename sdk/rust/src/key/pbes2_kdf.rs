@@ -0,0 +1,99 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use pkcs8::der::Encode;
+use pkcs8::pkcs5::pbes2;
+use rand::{
+    thread_rng,
+    RngCore,
+};
+use scrypt::Params;
+
+use crate::Error;
+
+/// The key derivation function used to turn a password into an AES-256-CBC key
+/// when encrypting a [`PrivateKey`](crate::PrivateKey) with PKCS#5 PBES2.
+///
+/// See [`PrivateKey::to_pem_with_password_and_kdf`](crate::PrivateKey::to_pem_with_password_and_kdf).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Pbes2Kdf {
+    /// PBKDF2 with HMAC-SHA256.
+    Pbkdf2 {
+        /// The number of iterations, more iterations are slower to brute force.
+        iterations: u32,
+    },
+
+    /// scrypt.
+    Scrypt {
+        /// The base 2 logarithm of the CPU/memory cost.
+        log_n: u8,
+        /// The block size.
+        r: u32,
+        /// The parallelization.
+        p: u32,
+    },
+}
+
+impl Default for Pbes2Kdf {
+    /// scrypt with `log_n = 15`, `r = 8` and `p = 1`.
+    fn default() -> Self {
+        Self::Scrypt { log_n: 15, r: 8, p: 1 }
+    }
+}
+
+impl Pbes2Kdf {
+    /// Encrypts the der encoded `PrivateKeyInfo` in `der` with `password`,
+    /// returning a der encoded `EncryptedPrivateKeyInfo`.
+    pub(super) fn encrypt(self, password: &[u8], der: &[u8]) -> crate::Result<Vec<u8>> {
+        let mut salt = [0; 16];
+        let mut iv = [0; 16];
+
+        let mut rng = thread_rng();
+        rng.fill_bytes(&mut salt);
+        rng.fill_bytes(&mut iv);
+
+        let params = match self {
+            Self::Pbkdf2 { iterations } => {
+                pbes2::Parameters::pbkdf2_sha256_aes256cbc(iterations, &salt, &iv)
+            }
+            Self::Scrypt { log_n, r, p } => {
+                let params =
+                    Params::new(log_n, r, p).map_err(|e| Error::key_encrypt(e.to_string()))?;
+
+                pbes2::Parameters::scrypt_aes256cbc(params, &salt, &iv)
+            }
+        }
+        .map_err(|e| Error::key_encrypt(e.to_string()))?;
+
+        let encrypted_data =
+            params.encrypt(password, der).map_err(|e| Error::key_encrypt(e.to_string()))?;
+
+        let info = pkcs8::EncryptedPrivateKeyInfo {
+            encryption_algorithm: params.into(),
+            encrypted_data: &encrypted_data,
+        };
+
+        let mut buf = Vec::with_capacity(encrypted_data.len() + 128);
+        info.encode_to_vec(&mut buf).unwrap();
+
+        Ok(buf)
+    }
+}
@@ -43,6 +43,7 @@ use hmac::{
 };
 use k256::ecdsa::signature::DigestSigner;
 use k256::pkcs8::der::Encode;
+use pem_rfc7468::LineEnding;
 use pkcs8::der::Decode;
 use pkcs8::{
     AssociatedOid,
@@ -51,12 +52,17 @@ use pkcs8::{
 use sha2::Sha512;
 use sha3::Digest;
 
+use super::bip32;
+use crate::ethereum::signed_message;
 use crate::signer::AnySigner;
 use crate::{
     AccountId,
     Error,
+    ExtendedPublicKey,
+    Pbes2Kdf,
     PublicKey,
     Transaction,
+    TypedData,
 };
 
 pub(super) const ED25519_OID: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.101.112");
@@ -367,6 +373,44 @@ impl PrivateKey {
         buf
     }
 
+    /// Return this `PrivateKey`, serialized as unencrypted PKCS#8 [PEM](https://www.rfc-editor.org/rfc/rfc7468#section-10) (`BEGIN PRIVATE KEY`).
+    // panic should be impossible (`unreachable`)
+    #[allow(clippy::missing_panics_doc)]
+    #[must_use]
+    pub fn to_pem(&self) -> String {
+        pem_rfc7468::encode_string("PRIVATE KEY", LineEnding::LF, &self.to_bytes_der()).unwrap()
+    }
+
+    /// Return this `PrivateKey`, serialized as encrypted PKCS#8 [PEM](https://www.rfc-editor.org/rfc/rfc7468#section-11) (`BEGIN ENCRYPTED PRIVATE KEY`).
+    ///
+    /// The key is encrypted with PBES2, using the [default](Pbes2Kdf::default) key derivation function and AES-256-CBC,
+    /// and can be read back with [`from_pem_with_password`](Self::from_pem_with_password).
+    ///
+    /// # Errors
+    /// - [`Error::KeyEncrypt`] if encrypting the private key fails.
+    pub fn to_pem_with_password(&self, password: impl AsRef<[u8]>) -> crate::Result<String> {
+        self.to_pem_with_password_and_kdf(password, Pbes2Kdf::default())
+    }
+
+    /// Return this `PrivateKey`, serialized as encrypted PKCS#8 [PEM](https://www.rfc-editor.org/rfc/rfc7468#section-11) (`BEGIN ENCRYPTED PRIVATE KEY`).
+    ///
+    /// The key is encrypted with PBES2, using `kdf` to derive an AES-256-CBC key from `password`.
+    ///
+    /// # Errors
+    /// - [`Error::KeyEncrypt`] if the parameters of `kdf` are invalid.
+    /// - [`Error::KeyEncrypt`] if encrypting the private key fails.
+    // panic should be impossible (`unreachable`)
+    #[allow(clippy::missing_panics_doc)]
+    pub fn to_pem_with_password_and_kdf(
+        &self,
+        password: impl AsRef<[u8]>,
+        kdf: Pbes2Kdf,
+    ) -> crate::Result<String> {
+        let der = kdf.encrypt(password.as_ref(), &self.to_bytes_der())?;
+
+        Ok(pem_rfc7468::encode_string("ENCRYPTED PRIVATE KEY", LineEnding::LF, &der).unwrap())
+    }
+
     /// Return this `PrivateKey`, serialized as bytes.
     ///
     /// If this is an ed25519 private key, this is equivalent to [`to_bytes_raw`](Self::to_bytes_raw)
@@ -486,6 +530,63 @@ impl PrivateKey {
         }
     }
 
+    /// Signs the keccak-256 hash of `message` with this ECDSA key,
+    /// returning the signature (`r || s`) and its recovery ID.
+    pub(crate) fn sign_ecdsa_recoverable(&self, message: &[u8]) -> crate::Result<([u8; 64], u8)> {
+        match &self.0.data {
+            PrivateKeyData::Ecdsa(key) => {
+                let signature: k256::ecdsa::recoverable::Signature =
+                    key.sign_digest(sha3::Keccak256::new_with_prefix(message));
+
+                let bytes: &[u8] = signature.as_ref();
+
+                let mut r_s = [0; 64];
+                r_s.copy_from_slice(&bytes[..64]);
+
+                Ok((r_s, bytes[64]))
+            }
+            PrivateKeyData::Ed25519(_) => Err(Error::WrongKeyType {
+                task: "create a recoverable signature",
+                key_enum: "PrivateKey",
+                key_variant: "Ed25519",
+            }),
+        }
+    }
+
+    /// Signs the keccak-256 hash of `message` with this ECDSA key, in a way that the public key can be recovered from.
+    ///
+    /// The result is a 65 byte `r || s || v` signature, with `v` being `27` or `28`,
+    /// see [`PublicKey::recover`] to recover the key (and its EVM address) from it.
+    ///
+    /// # Errors
+    /// - [`Error::WrongKeyType`] if this is an Ed25519 key.
+    pub fn sign_recoverable(&self, message: &[u8]) -> crate::Result<Vec<u8>> {
+        let (r_s, recovery_id) = self.sign_ecdsa_recoverable(message)?;
+
+        Ok(signed_message::to_rsv(r_s, recovery_id))
+    }
+
+    /// Signs `message` the way Ethereum's `personal_sign` (EIP-191) does, with this ECDSA key.
+    ///
+    /// The result is the 65 byte `r || s || v` signature EVM wallets produce, with `v` being `27` or `28`.
+    ///
+    /// # Errors
+    /// - [`Error::WrongKeyType`] if this is an Ed25519 key.
+    pub fn sign_personal_message(&self, message: &[u8]) -> crate::Result<Vec<u8>> {
+        self.sign_recoverable(&signed_message::personal_message(message))
+    }
+
+    /// Signs EIP-712 typed `data` the way Ethereum's `eth_signTypedData_v4` does, with this ECDSA key.
+    ///
+    /// The result is the 65 byte `r || s || v` signature EVM wallets produce, with `v` being `27` or `28`.
+    ///
+    /// # Errors
+    /// - [`Error::AbiEncode`] if the domain or message of `data` don't match their types.
+    /// - [`Error::WrongKeyType`] if this is an Ed25519 key.
+    pub fn sign_typed_data(&self, data: &TypedData) -> crate::Result<Vec<u8>> {
+        self.sign_recoverable(&data.encode()?)
+    }
+
     // I question the reason for this function existing.
     /// Signs the given transaction.
     pub fn sign_transaction<D: crate::transaction::TransactionExecute>(
@@ -502,14 +603,54 @@ impl PrivateKey {
     /// Returns true if calling [`derive`](Self::derive) on `self` would succeed.
     #[must_use]
     pub fn is_derivable(&self) -> bool {
-        self.is_ed25519() && self.0.chain_code.is_some()
+        self.0.chain_code.is_some()
+    }
+
+    /// Returns the chain code of this key, if it has one.
+    #[must_use]
+    pub fn chain_code(&self) -> Option<[u8; 32]> {
+        self.0.chain_code
+    }
+
+    /// Returns a copy of this key using `chain_code`, making it derivable.
+    ///
+    /// # Panics
+    /// Never, the bytes of an Ed25519 secret key are always a valid secret key.
+    #[must_use]
+    pub fn with_chain_code(&self, chain_code: [u8; 32]) -> Self {
+        let data = match &self.0.data {
+            PrivateKeyData::Ed25519(key) => {
+                // `Keypair` isn't `Clone`.
+                let secret = ed25519_dalek::SecretKey::from_bytes(key.secret.as_bytes()).unwrap();
+                PrivateKeyData::Ed25519(Keypair { public: key.public, secret })
+            }
+            PrivateKeyData::Ecdsa(key) => PrivateKeyData::Ecdsa(key.clone()),
+        };
+
+        Self(Arc::new(PrivateKeyDataWrapper::new_derivable(data, chain_code)))
+    }
+
+    /// Returns the public key of this key along with its chain code,
+    /// which can derive the same (non-hardened) child public keys as [`derive`](Self::derive).
+    ///
+    /// # Errors
+    /// - [`Error::WrongKeyType`] if this is an Ed25519 key (Ed25519 only supports hardened derivation)
+    /// - [`Error::KeyDerive`] if this key has no `chain_code` (key is not derivable)
+    pub fn to_extended_public_key(&self) -> crate::Result<ExtendedPublicKey> {
+        match self.0.chain_code {
+            Some(chain_code) => ExtendedPublicKey::new(self.public_key(), chain_code),
+            None => Err(Error::key_derive("key is underivable")),
+        }
     }
 
     /// Derives a child key based on `index`.
     ///
+    /// Ed25519 keys always derive hardened children (SLIP-10),
+    /// Ecdsa keys follow BIP-32, where a negative `index` (the top bit set) derives a hardened child.
+    ///
     /// # Errors
-    /// - [`Error::KeyDerive`] if this is an Ecdsa key (unsupported operation)
     /// - [`Error::KeyDerive`] if this key has no `chain_code` (key is not derivable)
+    /// - [`Error::KeyDerive`] if the derived Ecdsa key is invalid (astronomically unlikely), derive the next index instead.
     // this is specifically for the two `try_into`s which depend on `split_array_ref`.
     // Any panic would indicate a bug in this crate or a dependency of it, not in user code.
     #[allow(clippy::missing_panics_doc)]
@@ -548,8 +689,11 @@ impl PrivateKey {
 
                 Ok(Self(Arc::new(PrivateKeyDataWrapper::new_derivable(data, chain_code))))
             }
-            PrivateKeyData::Ecdsa(_) => {
-                Err(Error::key_derive("Ecdsa private keys don't currently support derivation"))
+            PrivateKeyData::Ecdsa(key) => {
+                let (data, chain_code) = bip32::derive_private(key, chain_code, index)?;
+                let data = PrivateKeyData::Ecdsa(data);
+
+                Ok(Self(Arc::new(PrivateKeyDataWrapper::new_derivable(data, chain_code))))
             }
         }
     }
@@ -597,15 +741,49 @@ impl PrivateKey {
         }
     }
 
+    /// Derives a child key along `path` (such as `m/44'/3030'/0'/0'/0'`) relative to `self`.
+    ///
+    /// Hardened indexes are marked with `'` (or `h`), and Ed25519 keys (SLIP-10) only support hardened indexes.
+    ///
+    /// # Errors
+    /// - [`Error::KeyDerive`] if `path` is not a valid derivation path.
+    /// - [`Error::KeyDerive`] if this is an Ed25519 key and `path` contains a non-hardened index.
+    /// - [`Error::KeyDerive`] if [`derive`](Self::derive) fails for any index in `path`.
+    pub fn derive_path(&self, path: &str) -> crate::Result<Self> {
+        let indexes = bip32::parse_path(path)?;
+
+        if self.is_ed25519() && indexes.iter().any(|index| index & bip32::HARDENED == 0) {
+            return Err(Error::key_derive(format!(
+                "Ed25519 keys only support hardened derivation, but `{path}` has non-hardened indexes"
+            )));
+        }
+
+        let mut key = self.clone();
+
+        for index in indexes {
+            key = key.derive(index as i32)?;
+        }
+
+        Ok(key)
+    }
+
     /// Recover a `PrivateKey` from a mnemonic phrase and a passphrase.
-    // this is specifically for the two `try_into`s which depend on `split_array_ref`.
-    // There *is* a 3rd unwrap for a "key is not derivable" error, but we construct a key that _is_ derivable.
+    // There is an unwrap for a "key is not derivable" error, but we construct a key that _is_ derivable.
     // Any panic would indicate a bug in this crate or a dependency of it, not in user code.
     #[allow(clippy::missing_panics_doc)]
     #[must_use]
     pub fn from_mnemonic(mnemonic: &crate::Mnemonic, passphrase: &str) -> PrivateKey {
-        let seed = mnemonic.to_seed(passphrase);
+        let mut key = Self::from_seed_ed25519(&mnemonic.to_seed(passphrase));
 
+        for index in [44, 3030, 0, 0] {
+            key = key.derive(index).expect("BUG: we set the chain code earlier in this function");
+        }
+
+        key
+    }
+
+    /// Computes the SLIP-10 Ed25519 master key from a BIP-39 `seed`.
+    pub(crate) fn from_seed_ed25519(seed: &[u8]) -> Self {
         let output: [u8; 64] = Hmac::<Sha512>::new_from_slice(b"ed25519 seed")
             .expect("hmac can take a seed of any size")
             .chain_update(seed)
@@ -625,13 +803,15 @@ impl PrivateKey {
         let data = ed25519_dalek::Keypair { public: (&data).into(), secret: data };
         let data = PrivateKeyData::Ed25519(data);
 
-        let mut key = Self(Arc::new(PrivateKeyDataWrapper::new_derivable(data, right)));
+        Self(Arc::new(PrivateKeyDataWrapper::new_derivable(data, right)))
+    }
 
-        for index in [44, 3030, 0, 0] {
-            key = key.derive(index).expect("BUG: we set the chain code earlier in this function");
-        }
+    /// Computes the BIP-32 ECDSA(secp256k1) master key from a BIP-39 `seed`.
+    pub(crate) fn from_seed_ecdsa(seed: &[u8]) -> crate::Result<Self> {
+        let (data, chain_code) = bip32::master(seed)?;
+        let data = PrivateKeyData::Ecdsa(data);
 
-        key
+        Ok(Self(Arc::new(PrivateKeyDataWrapper::new_derivable(data, chain_code))))
     }
 
     #[must_use]
@@ -34,6 +34,8 @@ use ed25519_dalek::ed25519::signature::DigestVerifier;
 use ed25519_dalek::Verifier;
 use hedera_proto::services;
 use k256::ecdsa;
+use k256::elliptic_curve::sec1::ToEncodedPoint;
+use pem_rfc7468::LineEnding;
 use pkcs8::der::{
     Decode,
     Encode,
@@ -41,6 +43,7 @@ use pkcs8::der::{
 use prost::Message;
 use sha2::Digest;
 
+use crate::ethereum::signed_message;
 use crate::key::private_key::ED25519_OID;
 use crate::protobuf::ToProtobuf;
 use crate::signer::AnySigner;
@@ -48,8 +51,10 @@ use crate::transaction::TransactionSources;
 use crate::{
     AccountId,
     Error,
+    EvmAddress,
     FromProtobuf,
     Transaction,
+    TypedData,
 };
 
 #[cfg(test)]
@@ -97,6 +102,13 @@ impl PublicKey {
         Self(PublicKeyData::Ecdsa(key))
     }
 
+    pub(super) fn as_ecdsa(&self) -> Option<&k256::ecdsa::VerifyingKey> {
+        match &self.0 {
+            PublicKeyData::Ecdsa(key) => Some(key),
+            PublicKeyData::Ed25519(_) => None,
+        }
+    }
+
     /// Returns `true` if the public key is `Ed25519`.
     #[must_use]
     pub fn is_ed25519(&self) -> bool {
@@ -194,6 +206,28 @@ impl PublicKey {
         )
     }
 
+    /// Parse a `PublicKey` from [PEM](https://www.rfc-editor.org/rfc/rfc7468#section-13) encoded bytes.
+    ///
+    /// # Errors
+    /// - [`Error::KeyParse`] if `pem` is not valid PEM.
+    /// - [`Error::KeyParse`] if the type label (BEGIN XYZ) is not `PUBLIC KEY`.
+    /// - [`Error::KeyParse`] if the data contained inside the PEM is not a valid `PublicKey`.
+    pub fn from_pem(pem: impl AsRef<[u8]>) -> crate::Result<Self> {
+        fn inner(pem: &[u8]) -> crate::Result<PublicKey> {
+            let (type_label, der) = pem_rfc7468::decode_vec(pem).map_err(Error::key_parse)?;
+
+            if type_label != "PUBLIC KEY" {
+                return Err(Error::key_parse(format!(
+                    "incorrect PEM type label: expected: `PUBLIC KEY`, got: `{type_label}`"
+                )));
+            }
+
+            PublicKey::from_bytes_der(&der)
+        }
+
+        inner(pem.as_ref())
+    }
+
     /// Parse a Ed25519 `PublicKey` from a string containing the raw key material.
     ///
     /// Optionally strips a `0x` prefix.
@@ -218,6 +252,14 @@ impl PublicKey {
         )
     }
 
+    /// Return this `PublicKey`, serialized as [PEM](https://www.rfc-editor.org/rfc/rfc7468#section-13) (`BEGIN PUBLIC KEY`).
+    // panic should be impossible (`unreachable`)
+    #[allow(clippy::missing_panics_doc)]
+    #[must_use]
+    pub fn to_pem(&self) -> String {
+        pem_rfc7468::encode_string("PUBLIC KEY", LineEnding::LF, &self.to_bytes_der()).unwrap()
+    }
+
     /// Return this `PublicKey`, serialized as bytes.
     ///
     /// If this is an ed25519 public key, this is equivalent to [`to_bytes_raw`](Self::to_bytes_raw)
@@ -311,10 +353,12 @@ impl PublicKey {
     }
 
     /// Convert this public key into an evm address. The EVM address is This is the rightmost 20 bytes of the 32 byte Keccak-256 hash of the ECDSA public key.
+    ///
+    /// The hash is taken over the uncompressed public key (without its `0x04` prefix), as Ethereum does.
+    /// Earlier versions hashed the compressed key, which gave addresses no wallet or contract would recognize.
     pub fn to_evm_address(&self) -> crate::Result<String> {
         if let PublicKeyData::Ecdsa(ecdsa_key) = &self.0 {
-            let hash = sha3::Keccak256::digest(ecdsa_key.to_bytes());
-            Ok(format!("0x{}", hex::encode(hash.get(12..32).unwrap())))
+            Ok(ecdsa_evm_address(ecdsa_key).to_string())
         } else {
             Err(Error::WrongKeyType {
                 task: "convert to evm address",
@@ -324,6 +368,29 @@ impl PublicKey {
         }
     }
 
+    /// Recover the ECDSA public key that made `signature` on `message`, along with its EVM address.
+    ///
+    /// `signature` is a 65 byte `r || s || v` signature over the keccak-256 hash of `message`,
+    /// as made by [`PrivateKey::sign_recoverable`](crate::PrivateKey::sign_recoverable)
+    /// (or EVM tooling), `v` may be either `0`/`1` or `27`/`28`.
+    ///
+    /// The address is the last 20 bytes of the keccak-256 hash of the uncompressed public key,
+    /// the same address the EVM derives for the key.
+    ///
+    /// # Errors
+    /// - [`Error::SignatureVerify`] if `signature` is malformed, or no key can be recovered from it.
+    pub fn recover(message: &[u8], signature: &[u8]) -> crate::Result<(Self, EvmAddress)> {
+        let key = signed_message::recover(message, signature)?;
+        let address = ecdsa_evm_address(&key);
+
+        Ok((Self::ecdsa(key), address))
+    }
+
+    /// Returns the address the EVM derives for this key, if it is an ECDSA key.
+    pub(super) fn ethereum_address(&self) -> Option<EvmAddress> {
+        self.as_ecdsa().map(ecdsa_evm_address)
+    }
+
     /// Verify a `signature` on a `msg` with this public key.
     ///
     /// # Errors
@@ -349,6 +416,46 @@ impl PublicKey {
         }
     }
 
+    /// Verify an Ethereum `personal_sign` (EIP-191) `signature` on `message` with this public key.
+    ///
+    /// `signature` is the 65 byte `r || s || v` signature EVM wallets produce.
+    ///
+    /// # Errors
+    /// - [`Error::SignatureVerify`] if this isn't an ECDSA key.
+    /// - [`Error::SignatureVerify`] if the signature is invalid for this `PublicKey`.
+    pub fn verify_personal_message(&self, message: &[u8], signature: &[u8]) -> crate::Result<()> {
+        self.verify_ethereum(&signed_message::personal_message(message), signature)
+    }
+
+    /// Verify an EIP-712 (`eth_signTypedData_v4`) `signature` on typed `data` with this public key.
+    ///
+    /// `signature` is the 65 byte `r || s || v` signature EVM wallets produce.
+    ///
+    /// # Errors
+    /// - [`Error::AbiEncode`] if the domain or message of `data` don't match their types.
+    /// - [`Error::SignatureVerify`] if this isn't an ECDSA key.
+    /// - [`Error::SignatureVerify`] if the signature is invalid for this `PublicKey`.
+    pub fn verify_typed_data(&self, data: &TypedData, signature: &[u8]) -> crate::Result<()> {
+        self.verify_ethereum(&data.encode()?, signature)
+    }
+
+    fn verify_ethereum(&self, message: &[u8], signature: &[u8]) -> crate::Result<()> {
+        let key = match &self.0 {
+            PublicKeyData::Ecdsa(key) => key,
+            PublicKeyData::Ed25519(_) => {
+                return Err(Error::signature_verify(
+                    "Ethereum style signatures can only be verified with ECDSA keys",
+                ))
+            }
+        };
+
+        if signed_message::recover(message, signature)? != *key {
+            return Err(Error::signature_verify("signature was made by a different key"));
+        }
+
+        Ok(())
+    }
+
     pub(crate) fn verify_transaction_sources(
         &self,
         sources: &TransactionSources,
@@ -480,5 +587,18 @@ impl ToProtobuf for PublicKey {
     }
 }
 
+/// Returns the EVM address of `key`, the last 20 bytes of the keccak-256 hash of the uncompressed public key.
+pub(crate) fn ecdsa_evm_address(key: &ecdsa::VerifyingKey) -> EvmAddress {
+    let point = key.to_encoded_point(false);
+
+    // skip the `0x04` tag of the uncompressed point.
+    let hash = sha3::Keccak256::digest(&point.as_bytes()[1..]);
+
+    let mut address = [0; 20];
+    address.copy_from_slice(&hash[12..]);
+
+    EvmAddress::from(address)
+}
+
 // TODO: to_protobuf
 // TODO: verify_transaction
@@ -24,7 +24,11 @@ use assert_matches::assert_matches;
 use expect_test::expect;
 use hex_literal::hex;
 
-use crate::PublicKey;
+use crate::{
+    Error,
+    PrivateKey,
+    PublicKey,
+};
 
 #[test]
 fn ed25519_from_str() {
@@ -80,7 +84,15 @@ fn to_evm_address() {
     )
     .unwrap();
     let evm_address = key.to_evm_address().unwrap();
-    assert_eq!(evm_address, "0x83e31378423cf9c51ec36b074b89e16f31560cbb");
+    assert_eq!(evm_address, "0xbbaa6bdfe888ae1fc8e7c8cee82081fa79ba8834");
+
+    // the key from the Web3 Secret Storage test vectors, which lists its address.
+    let key = PrivateKey::from_str_ecdsa(
+        "7a28b5ba57c53603b0b07b56bba752f7784bf506fa95edc395f5cf6c7514fe9d",
+    )
+    .unwrap();
+    let evm_address = key.public_key().to_evm_address().unwrap();
+    assert_eq!(evm_address, "0x008aeeda4d805471df9b2a5b0f38a0c3bcba786b");
 }
 
 #[test]
@@ -186,3 +198,51 @@ fn ecdsa_verify_error_ed25519() {
 
     expect!["failed to verify a signature: signature error"].assert_eq(&err.to_string());
 }
+
+#[test]
+fn ecdsa_recover() {
+    let private_key = PrivateKey::from_bytes_ecdsa(&hex!(
+        "4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318"
+    ))
+    .unwrap();
+
+    let signature = private_key.sign_recoverable(b"hello world").unwrap();
+
+    let (public_key, evm_address) = PublicKey::recover(b"hello world", &signature).unwrap();
+
+    assert_eq!(public_key, private_key.public_key());
+    assert_eq!(evm_address.to_string(), "0x2c7536e3605d9c16a7a3d7b1898e529396a65c23");
+
+    // a different message recovers a different key.
+    let (other, _) = PublicKey::recover(b"hello, world", &signature).unwrap();
+    assert_ne!(other, public_key);
+}
+
+#[test]
+fn ecdsa_recover_invalid() {
+    assert_matches!(PublicKey::recover(b"hello world", &[0; 64]), Err(Error::SignatureVerify(_)));
+
+    let mut signature = PrivateKey::generate_ecdsa().sign_recoverable(b"hello world").unwrap();
+    signature[64] = 2;
+
+    assert_matches!(PublicKey::recover(b"hello world", &signature), Err(Error::SignatureVerify(_)));
+}
+
+#[test]
+fn pem_round_trip() {
+    for pk in
+        [PrivateKey::generate_ed25519().public_key(), PrivateKey::generate_ecdsa().public_key()]
+    {
+        let pem = pk.to_pem();
+
+        assert!(pem.starts_with("-----BEGIN PUBLIC KEY-----"));
+        assert_eq!(PublicKey::from_pem(&pem).unwrap(), pk);
+    }
+}
+
+#[test]
+fn from_pem_invalid_type_label() {
+    let pem = PrivateKey::generate_ed25519().to_pem();
+
+    assert_matches!(PublicKey::from_pem(pem), Err(Error::KeyParse(_)));
+}
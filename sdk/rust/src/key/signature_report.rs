@@ -0,0 +1,296 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use crate::{
+    Key,
+    PublicKey,
+};
+
+/// A breakdown of which parts of a [`Key`] have signed.
+///
+/// Contract keys can't be satisfied by signatures, so they are ignored when evaluating key lists.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeySignatureReport {
+    /// `true` if the signatures satisfy the key (including any thresholds).
+    pub satisfied: bool,
+
+    /// The public keys in the key structure that have signed.
+    pub signed: Vec<PublicKey>,
+
+    /// The public keys in the key structure that have not signed.
+    pub missing: Vec<PublicKey>,
+
+    /// The minimum number of additional signatures needed to satisfy the key.
+    ///
+    /// This is `Some(0)` when the key is already satisfied,
+    /// and `None` when no set of signatures can satisfy it
+    /// (for instance, an empty key list, a threshold of 0,
+    /// or a threshold greater than the number of public keys under it).
+    ///
+    /// Public keys that appear in more than one branch are counted once per branch.
+    pub signatures_needed: Option<usize>,
+}
+
+impl KeySignatureReport {
+    pub(crate) fn new(key: &Key, is_signed: &dyn Fn(&PublicKey) -> bool) -> Self {
+        let mut report = Self {
+            satisfied: false,
+            signed: Vec::new(),
+            missing: Vec::new(),
+            signatures_needed: None,
+        };
+
+        let signatures_needed = match report.evaluate(key, is_signed) {
+            Requirement::Needs(count) => Some(count),
+            Requirement::Ignored | Requirement::Unreachable => None,
+        };
+
+        report.satisfied = signatures_needed == Some(0);
+        report.signatures_needed = signatures_needed;
+
+        report
+    }
+
+    fn evaluate(&mut self, key: &Key, is_signed: &dyn Fn(&PublicKey) -> bool) -> Requirement {
+        match key {
+            Key::Single(key) if is_signed(key) => {
+                if !self.signed.contains(key) {
+                    self.signed.push(*key);
+                }
+
+                Requirement::Needs(0)
+            }
+
+            Key::Single(key) => {
+                if !self.missing.contains(key) {
+                    self.missing.push(*key);
+                }
+
+                Requirement::Needs(1)
+            }
+
+            Key::ContractId(_) | Key::DelegatableContractId(_) => Requirement::Ignored,
+
+            // an empty key list or a threshold of 0 would be trivially satisfied, treat them as unsigned instead.
+            Key::KeyList(list) if list.keys.is_empty() || list.threshold == Some(0) => {
+                Requirement::Unreachable
+            }
+
+            Key::KeyList(list) => {
+                let requirements: Vec<_> = list
+                    .keys
+                    .iter()
+                    .map(|key| self.evaluate(key, is_signed))
+                    .filter(|it| !matches!(it, Requirement::Ignored))
+                    .collect();
+
+                if requirements.is_empty() {
+                    return Requirement::Ignored;
+                }
+
+                let threshold = list.threshold.map_or(requirements.len(), |it| it as usize);
+
+                let mut needs: Vec<_> = requirements
+                    .into_iter()
+                    .filter_map(|it| match it {
+                        Requirement::Needs(count) => Some(count),
+                        Requirement::Ignored | Requirement::Unreachable => None,
+                    })
+                    .collect();
+
+                if needs.len() < threshold {
+                    return Requirement::Unreachable;
+                }
+
+                needs.sort_unstable();
+
+                Requirement::Needs(needs[..threshold].iter().sum())
+            }
+        }
+    }
+}
+
+enum Requirement {
+    /// The key can't be satisfied by signatures and doesn't count towards its parent.
+    Ignored,
+
+    /// The key can't be satisfied by any set of signatures.
+    Unreachable,
+
+    /// The key needs this many more signatures.
+    Needs(usize),
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::transaction::test_helpers::unsigned_transfer_transaction;
+    use crate::{
+        AnyTransaction,
+        ContractId,
+        Key,
+        KeyList,
+        PrivateKey,
+    };
+
+    fn transaction_signed_by(keys: &[&PrivateKey]) -> AnyTransaction {
+        let mut tx = unsigned_transfer_transaction();
+
+        for key in keys {
+            tx.sign((*key).clone());
+        }
+
+        // round trip through bytes, so that the signatures are read back from the signed transactions.
+        AnyTransaction::from_bytes(&tx.to_bytes().unwrap()).unwrap()
+    }
+
+    #[test]
+    fn single_key() {
+        let signer = PrivateKey::generate_ed25519();
+        let other = PrivateKey::generate_ecdsa();
+        let tx = transaction_signed_by(&[&signer]);
+
+        assert!(Key::from(signer.public_key()).is_satisfied_by(&tx));
+        assert!(!Key::from(other.public_key()).is_satisfied_by(&tx));
+    }
+
+    #[test]
+    fn nested_threshold() {
+        let keys: Vec<_> = (0..4).map(|_| PrivateKey::generate_ed25519()).collect();
+        let tx = transaction_signed_by(&[&keys[0], &keys[2]]);
+
+        // 2 of [k0, k1, all of [k2, k3]]
+        let key = Key::KeyList(KeyList {
+            keys: vec![
+                keys[0].public_key().into(),
+                keys[1].public_key().into(),
+                KeyList::from(vec![keys[2].public_key().into(), keys[3].public_key().into()])
+                    .into(),
+            ],
+            threshold: Some(2),
+        });
+
+        let report = key.signature_report(&tx);
+
+        assert!(!report.satisfied);
+        assert_eq!(report.signatures_needed, Some(1));
+        assert_eq!(report.signed, vec![keys[0].public_key(), keys[2].public_key()]);
+        assert_eq!(report.missing, vec![keys[1].public_key(), keys[3].public_key()]);
+
+        // the nested list only counts as one of the two required signatures.
+        let tx = transaction_signed_by(&[&keys[2], &keys[3]]);
+
+        let report = key.signature_report(&tx);
+
+        assert!(!report.satisfied);
+        assert_eq!(report.signatures_needed, Some(1));
+
+        let tx = transaction_signed_by(&[&keys[0], &keys[2], &keys[3]]);
+
+        assert!(key.is_satisfied_by(&tx));
+    }
+
+    #[test]
+    fn pending_signers_are_counted() {
+        let signer = PrivateKey::generate_ed25519();
+        let other = PrivateKey::generate_ed25519();
+
+        let mut tx = unsigned_transfer_transaction();
+
+        assert!(!Key::from(signer.public_key()).is_satisfied_by(&tx));
+
+        tx.sign(signer.clone());
+
+        assert!(Key::from(signer.public_key()).is_satisfied_by(&tx));
+
+        let report = Key::KeyList(KeyList::from(vec![
+            signer.public_key().into(),
+            other.public_key().into(),
+        ]))
+        .signature_report(&tx);
+
+        assert_eq!(report.signed, vec![signer.public_key()]);
+        assert_eq!(report.missing, vec![other.public_key()]);
+    }
+
+    #[test]
+    fn empty_key_list_and_zero_threshold() {
+        let signer = PrivateKey::generate_ed25519();
+        let tx = transaction_signed_by(&[&signer]);
+
+        let empty = Key::KeyList(KeyList::new());
+        let report = empty.signature_report(&tx);
+
+        assert!(!report.satisfied);
+        assert_eq!(report.signatures_needed, None);
+
+        let zero_threshold =
+            Key::KeyList(KeyList { keys: vec![signer.public_key().into()], threshold: Some(0) });
+
+        assert!(!zero_threshold.is_satisfied_by(&tx));
+    }
+
+    #[test]
+    fn contract_keys_are_ignored() {
+        let signer = PrivateKey::generate_ed25519();
+        let tx = transaction_signed_by(&[&signer]);
+
+        let key = Key::KeyList(KeyList::from(vec![
+            signer.public_key().into(),
+            ContractId::from(1001).into(),
+        ]));
+
+        assert!(key.is_satisfied_by(&tx));
+
+        let report = Key::from(ContractId::from(1001)).signature_report(&tx);
+
+        assert!(!report.satisfied);
+        assert_eq!(report.signatures_needed, None);
+    }
+
+    #[test]
+    fn unreachable_threshold() {
+        let signer = PrivateKey::generate_ed25519();
+        let tx = transaction_signed_by(&[&signer]);
+
+        let key =
+            Key::KeyList(KeyList { keys: vec![signer.public_key().into()], threshold: Some(2) });
+
+        assert_eq!(key.signature_report(&tx).signatures_needed, None);
+    }
+
+    #[test]
+    fn from_bytes_signatures() -> crate::Result<()> {
+        let signer = PrivateKey::generate_ecdsa();
+        let other = PrivateKey::generate_ed25519();
+
+        let bytes = transaction_signed_by(&[&signer]).to_bytes()?;
+        let tx = AnyTransaction::from_bytes(&bytes)?;
+
+        let key = Key::KeyList(KeyList {
+            keys: vec![signer.public_key().into(), other.public_key().into()],
+            threshold: Some(1),
+        });
+
+        assert!(key.is_satisfied_by(&tx));
+        assert!(!Key::from(other.public_key()).is_satisfied_by(&tx));
+
+        Ok(())
+    }
+}
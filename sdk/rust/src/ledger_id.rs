@@ -109,6 +109,21 @@ impl LedgerId {
         self.as_ref() == RefLedgerId::PREVIEWNET
     }
 
+    /// Returns the chain ID Ethereum transactions for this ledger must use,
+    /// or `None` if `self` isn't `mainnet`, `testnet`, or `previewnet`.
+    #[must_use]
+    pub fn ethereum_chain_id(&self) -> Option<u64> {
+        if self.is_mainnet() {
+            Some(295)
+        } else if self.is_testnet() {
+            Some(296)
+        } else if self.is_previewnet() {
+            Some(297)
+        } else {
+            None
+        }
+    }
+
     /// Returns `true` if `self` is `mainnet`, `testnet`, or `previewnet`.
     #[must_use]
     pub fn is_known_network(&self) -> bool {
@@ -44,15 +44,19 @@
     clippy::module_name_repetitions
 )]
 
+// lets `#[derive(AbiEncode, AbiDecode)]` and `abigen!` (which refer to `::hedera`) be used inside this crate.
+extern crate self as hedera;
+
 #[macro_use]
 mod protobuf;
 
+mod abi;
 mod account;
 mod client;
 mod contract;
 mod entity_id;
 mod error;
-mod ethereum_transaction;
+mod ethereum;
 mod evm_address;
 mod execute;
 mod file;
@@ -65,6 +69,7 @@ mod network_version_info_query;
 mod node_address;
 mod node_address_book;
 mod node_address_book_query;
+mod prng_transaction;
 mod query;
 mod schedule;
 mod semantic_version;
@@ -89,6 +94,24 @@ mod ffi;
 mod hbar;
 mod transfer;
 
+pub use abi::{
+    Abi,
+    AbiBytes,
+    AbiConstructor,
+    AbiDecode,
+    AbiEncode,
+    AbiError,
+    AbiEvent,
+    AbiEventLog,
+    AbiEventParam,
+    AbiFixedBytes,
+    AbiFunction,
+    AbiParam,
+    AbiType,
+    AbiValue,
+    AbiValues,
+    StateMutability,
+};
 pub use account::{
     AccountAllowanceApproveTransaction,
     AccountAllowanceDeleteTransaction,
@@ -98,6 +121,7 @@ pub use account::{
     AccountDeleteTransaction,
     AccountId,
     AccountInfo,
+    AccountInfoFlow,
     AccountInfoQuery,
     AccountRecordsQuery,
     AccountStakersQuery,
@@ -105,11 +129,16 @@ pub use account::{
     AllProxyStakers,
     ProxyStaker,
 };
-pub use client::Client;
 pub(crate) use client::Operator;
+pub use client::{
+    Client,
+    Clock,
+    SystemClock,
+};
 pub use contract::{
     ContractBytecodeQuery,
     ContractCallQuery,
+    ContractCreateFlow,
     ContractCreateTransaction,
     ContractExecuteTransaction,
     ContractFunctionParameters,
@@ -118,6 +147,7 @@ pub use contract::{
     ContractInfo,
     ContractInfoQuery,
     ContractLogInfo,
+    ContractRevert,
     ContractUpdateTransaction,
 };
 pub use entity_id::EntityId;
@@ -128,7 +158,19 @@ pub use error::{
     MnemonicParseError,
     Result,
 };
-pub use ethereum_transaction::EthereumTransaction;
+pub use ethereum::{
+    AccessListItem,
+    Eip1559EthereumData,
+    Eip2930EthereumData,
+    EthereumData,
+    EthereumFlow,
+    EthereumTransaction,
+    LegacyEthereumData,
+    TypedData,
+    UnsignedEip1559EthereumData,
+    UnsignedEip2930EthereumData,
+    UnsignedLegacyEthereumData,
+};
 pub use evm_address::EvmAddress;
 pub use file::{
     FileAppendTransaction,
@@ -146,10 +188,19 @@ pub use hbar::{
     HbarUnit,
     Tinybar,
 };
+pub use hedera_derive::{
+    abigen,
+    AbiDecode,
+    AbiEncode,
+};
 pub use hedera_proto::services::ResponseCodeEnum as Status;
 pub use key::{
+    ExtendedPublicKey,
     Key,
     KeyList,
+    KeySignatureReport,
+    Keystore,
+    Pbes2Kdf,
     PrivateKey,
     PublicKey,
 };
@@ -159,7 +210,10 @@ pub use mirror_query::{
     AnyMirrorQueryResponse,
     MirrorQuery,
 };
-pub use mnemonic::Mnemonic;
+pub use mnemonic::{
+    Mnemonic,
+    MnemonicLanguage,
+};
 pub use network_version_info::NetworkVersionInfo;
 pub use network_version_info_query::NetworkVersionInfoQuery;
 pub(crate) use network_version_info_query::NetworkVersionInfoQueryData;
@@ -167,6 +221,7 @@ pub use node_address::NodeAddress;
 pub use node_address_book::NodeAddressBook;
 pub use node_address_book_query::NodeAddressBookQuery;
 pub(crate) use node_address_book_query::NodeAddressBookQueryData;
+pub use prng_transaction::PrngTransaction;
 pub(crate) use protobuf::{
     FromProtobuf,
     ToProtobuf,
@@ -247,6 +302,18 @@ pub use transaction_response::TransactionResponse;
 pub use transfer::Transfer;
 pub use transfer_transaction::TransferTransaction;
 
+#[doc(hidden)]
+pub mod __private {
+    //! Not public API, used by the code generated by `#[derive(AbiDecode)]` and `abigen!`.
+    pub use num_bigint;
+
+    pub use crate::abi::{
+        abigen_function,
+        mismatch,
+        tuple_elements,
+    };
+}
+
 /// Boxed future for GRPC calls.
 pub(crate) type BoxGrpcFuture<'a, T> =
     futures_core::future::BoxFuture<'a, tonic::Result<tonic::Response<T>>>;
@@ -0,0 +1,154 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::cmp::Ordering;
+
+use once_cell::sync::Lazy;
+
+/// The language of a BIP-39 [`Mnemonic`](crate::Mnemonic)'s word list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum MnemonicLanguage {
+    /// The English word list, used by all legacy mnemonics.
+    English,
+
+    /// The Simplified Chinese word list.
+    ChineseSimplified,
+
+    /// The Traditional Chinese word list.
+    ChineseTraditional,
+
+    /// The Czech word list.
+    Czech,
+
+    /// The French word list.
+    French,
+
+    /// The Italian word list.
+    Italian,
+
+    /// The Japanese word list.
+    Japanese,
+
+    /// The Korean word list.
+    Korean,
+
+    /// The Portuguese word list.
+    Portuguese,
+
+    /// The Spanish word list.
+    Spanish,
+}
+
+impl MnemonicLanguage {
+    /// Every language, in the order they are tried when detecting the language of a mnemonic.
+    // Simplified and Traditional Chinese share most of their words, so a mnemonic can be valid in both.
+    pub(super) const ALL: [Self; 10] = [
+        Self::English,
+        Self::ChineseSimplified,
+        Self::ChineseTraditional,
+        Self::Czech,
+        Self::French,
+        Self::Italian,
+        Self::Japanese,
+        Self::Korean,
+        Self::Portuguese,
+        Self::Spanish,
+    ];
+
+    pub(super) fn word_list(self) -> &'static WordList {
+        match self {
+            Self::English => &ENGLISH,
+            Self::ChineseSimplified => &CHINESE_SIMPLIFIED,
+            Self::ChineseTraditional => &CHINESE_TRADITIONAL,
+            Self::Czech => &CZECH,
+            Self::French => &FRENCH,
+            Self::Italian => &ITALIAN,
+            Self::Japanese => &JAPANESE,
+            Self::Korean => &KOREAN,
+            Self::Portuguese => &PORTUGUESE,
+            Self::Spanish => &SPANISH,
+        }
+    }
+
+    /// The separator between words when displaying a mnemonic.
+    pub(super) fn separator(self) -> &'static str {
+        match self {
+            // BIP-39 specifies an ideographic space for Japanese.
+            Self::Japanese => "\u{3000}",
+            _ => " ",
+        }
+    }
+}
+
+// `is_sorted` is unstable.
+// this is just lifted from the stdlib impl.
+fn is_sorted<T: PartialOrd>(vs: &[T]) -> bool {
+    vs.windows(2).all(|w| w[0].partial_cmp(&w[1]).map_or(false, |o| o != Ordering::Greater))
+}
+
+/// A BIP-39 word list, all of which are exactly 2048 NFKD normalized words.
+pub(super) struct WordList {
+    words: Vec<&'static str>,
+    // if the word list is sorted we can use the power of `binary_search` which turns the `O(n)` search into a `O(log n)`
+    // n is a constant here, but perf is perf.
+    sorted: bool,
+}
+
+impl WordList {
+    fn new(text: &'static str) -> Self {
+        let words: Vec<_> = text.split_whitespace().collect();
+        assert_eq!(words.len(), 2048);
+
+        let sorted = is_sorted(&words);
+
+        Self { words, sorted }
+    }
+
+    /// Returns the index of `word`, which must already be NFKD normalized.
+    pub(super) fn index_of(&self, word: &str) -> Option<u16> {
+        let index = if self.sorted {
+            self.words.binary_search(&word).ok()
+        } else {
+            self.words.iter().position(|it| *it == word)
+        };
+
+        index.map(|it| it as u16)
+    }
+
+    pub(super) fn word(&self, index: u16) -> &'static str {
+        self.words[usize::from(index)]
+    }
+}
+
+// sadly can't do this with a const.
+static ENGLISH: Lazy<WordList> = Lazy::new(|| WordList::new(include_str!("bip39-english.txt")));
+static CHINESE_SIMPLIFIED: Lazy<WordList> =
+    Lazy::new(|| WordList::new(include_str!("bip39-chinese-simplified.txt")));
+static CHINESE_TRADITIONAL: Lazy<WordList> =
+    Lazy::new(|| WordList::new(include_str!("bip39-chinese-traditional.txt")));
+static CZECH: Lazy<WordList> = Lazy::new(|| WordList::new(include_str!("bip39-czech.txt")));
+static FRENCH: Lazy<WordList> = Lazy::new(|| WordList::new(include_str!("bip39-french.txt")));
+static ITALIAN: Lazy<WordList> = Lazy::new(|| WordList::new(include_str!("bip39-italian.txt")));
+static JAPANESE: Lazy<WordList> = Lazy::new(|| WordList::new(include_str!("bip39-japanese.txt")));
+static KOREAN: Lazy<WordList> = Lazy::new(|| WordList::new(include_str!("bip39-korean.txt")));
+static PORTUGUESE: Lazy<WordList> =
+    Lazy::new(|| WordList::new(include_str!("bip39-portuguese.txt")));
+static SPANISH: Lazy<WordList> = Lazy::new(|| WordList::new(include_str!("bip39-spanish.txt")));
@@ -18,7 +18,8 @@
  * ‍
  */
 
-use std::cmp::Ordering;
+mod language;
+
 use std::fmt;
 use std::str::FromStr;
 
@@ -34,7 +35,9 @@ use rand::{
     RngCore,
 };
 use sha2::Digest;
+use unicode_normalization::UnicodeNormalization;
 
+pub use self::language::MnemonicLanguage;
 use crate::error::{
     MnemonicEntropyError,
     MnemonicParseError,
@@ -44,24 +47,8 @@ use crate::{
     PrivateKey,
 };
 
-const BIP39: &str = include_str!("bip39-english.txt");
 const LEGACY: &str = include_str!("legacy-english.txt");
 
-// `is_sorted` is unstable.
-// this is just lifted from the stdlib impl.
-fn is_sorted<T: PartialOrd>(vs: &[T]) -> bool {
-    vs.windows(2).all(|w| w[0].partial_cmp(&w[1]).map_or(false, |o| o != Ordering::Greater))
-}
-
-// sadly can't do this with a const.
-static BIP39_WORD_LIST: Lazy<Vec<&'static str>> = Lazy::new(|| {
-    let it: Vec<_> = BIP39.split_whitespace().collect();
-    // if the word list is sorted we can use the power of `binary_search` which turns the `O(n)` search into a `O(log n)`
-    // n is a constant here, but perf is perf.
-    assert!(is_sorted(&it));
-    it
-});
-
 // the legacy word list isn't sorted.
 static LEGACY_WORD_LIST: Lazy<Vec<&'static str>> =
     Lazy::new(|| LEGACY.split_whitespace().collect());
@@ -95,9 +82,23 @@ impl Mnemonic {
         matches!(&self.0, MnemonicData::V1(_))
     }
 
+    /// Returns the language of this mnemonic's word list.
+    ///
+    /// Legacy mnemonics are always [`English`](MnemonicLanguage::English).
+    #[must_use]
+    pub fn language(&self) -> MnemonicLanguage {
+        match &self.0 {
+            MnemonicData::V1(_) => MnemonicLanguage::English,
+            MnemonicData::V2V3(it) => it.language,
+        }
+    }
+
     // todo(sr): Not too happy about requiring a `Vec<String>`
     /// Constructs a `Mnemonic` from a 24-word list.
     ///
+    /// The language is detected from the words themselves,
+    /// see [`MnemonicLanguage`] for the supported BIP-39 word lists.
+    ///
     /// # Errors
     /// * if the mnemonic has an invalid length.
     /// * if the mnemonic uses invalid words.
@@ -108,53 +109,114 @@ impl Mnemonic {
             Err(words) => words,
         };
 
-        let mnemonic = Self(MnemonicV2V3 { words }.into());
+        // the word lists are NFKD normalized, so the words need to be too.
+        let words: Vec<String> = words.iter().map(|word| word.nfkd().collect()).collect();
 
-        if mnemonic.words().len() != 12 && mnemonic.words().len() != 24 {
+        if words.len() != 12 && words.len() != 24 {
             return Err(Error::MnemonicParse {
-                reason: MnemonicParseError::BadLength(mnemonic.words().len()),
-                mnemonic,
+                reason: MnemonicParseError::BadLength(words.len()),
+                mnemonic: Self(MnemonicV2V3 { words, language: MnemonicLanguage::English }.into()),
             });
         }
 
-        let mut word_indecies = Vec::with_capacity(mnemonic.words().len());
-        let mut unknown_words = Vec::new();
-        for (word_index, word) in mnemonic.words().iter().enumerate() {
-            match BIP39_WORD_LIST.binary_search(&&**word) {
-                Ok(i) => {
-                    word_indecies.push(i as u16);
-                }
-                // error (word not in list)
-                Err(_) => {
-                    unknown_words.push(word_index);
+        // the first language that knows every word and has a matching checksum wins,
+        // otherwise the error is from the language that knows the most words.
+        let mut best_error = (
+            MnemonicLanguage::English,
+            MnemonicParseError::UnknownWords((0..words.len()).collect()),
+            0,
+        );
+
+        for language in MnemonicLanguage::ALL {
+            let word_list = language.word_list();
+
+            let mut word_indecies = Vec::with_capacity(words.len());
+            let mut unknown_words = Vec::new();
+            for (word_index, word) in words.iter().enumerate() {
+                match word_list.index_of(word) {
+                    Some(i) => {
+                        word_indecies.push(i);
+                    }
+                    // error (word not in list)
+                    None => {
+                        unknown_words.push(word_index);
+                    }
                 }
             }
-        }
 
-        if !unknown_words.is_empty() {
-            return Err(Error::MnemonicParse {
-                reason: MnemonicParseError::UnknownWords(unknown_words),
-                mnemonic,
-            });
-        }
+            let known_words = words.len() - unknown_words.len();
 
-        let (entropy, actual_checksum) = incecies_to_entropy_and_checksum(&word_indecies);
-        let expected_checksum = checksum(&entropy);
-        let expected_checksum =
-            if mnemonic.words().len() == 12 { expected_checksum & 0xf0 } else { expected_checksum };
+            let reason = if unknown_words.is_empty() {
+                let (entropy, actual_checksum) = incecies_to_entropy_and_checksum(&word_indecies);
+                let expected_checksum = checksum(&entropy);
+                let expected_checksum =
+                    if words.len() == 12 { expected_checksum & 0xf0 } else { expected_checksum };
 
-        if expected_checksum != actual_checksum {
-            // error: checksum mismatch.
-            return Err(Error::MnemonicParse {
-                reason: MnemonicParseError::ChecksumMismatch {
+                if expected_checksum == actual_checksum {
+                    return Ok(Self(MnemonicV2V3 { words, language }.into()));
+                }
+
+                // error: checksum mismatch.
+                MnemonicParseError::ChecksumMismatch {
                     expected: expected_checksum,
                     actual: actual_checksum,
-                },
-                mnemonic,
-            });
+                }
+            } else {
+                MnemonicParseError::UnknownWords(unknown_words)
+            };
+
+            if known_words > best_error.2 {
+                best_error = (language, reason, known_words);
+            }
+        }
+
+        let (language, reason, _) = best_error;
+
+        Err(Error::MnemonicParse {
+            reason,
+            mnemonic: Self(MnemonicV2V3 { words, language }.into()),
+        })
+    }
+
+    /// Constructs a `Mnemonic` from `entropy` using the BIP-39 standard English word list.
+    ///
+    /// 16 bytes of entropy make a 12 word mnemonic, and 32 bytes make a 24 word mnemonic.
+    ///
+    /// # Errors
+    /// - [`MnemonicEntropyError::BadEntropyLength`] if `entropy` isn't 16 or 32 bytes long.
+    pub fn from_entropy(entropy: &[u8]) -> crate::Result<Self> {
+        Self::from_entropy_with_language(entropy, MnemonicLanguage::English)
+    }
+
+    /// Constructs a `Mnemonic` from `entropy` using the BIP-39 word list for `language`.
+    ///
+    /// 16 bytes of entropy make a 12 word mnemonic, and 32 bytes make a 24 word mnemonic.
+    ///
+    /// # Errors
+    /// - [`MnemonicEntropyError::BadEntropyLength`] if `entropy` isn't 16 or 32 bytes long.
+    pub fn from_entropy_with_language(
+        entropy: &[u8],
+        language: MnemonicLanguage,
+    ) -> crate::Result<Self> {
+        if entropy.len() != 16 && entropy.len() != 32 {
+            return Err(Error::from(MnemonicEntropyError::BadEntropyLength(entropy.len())));
         }
 
-        Ok(mnemonic)
+        Ok(Self(MnemonicV2V3::from_entropy(entropy, language).into()))
+    }
+
+    /// Returns the entropy of this `Mnemonic`, the inverse of [`from_entropy`](Self::from_entropy).
+    ///
+    /// For legacy mnemonics this is the legacy entropy used by [`to_legacy_private_key`](Self::to_legacy_private_key) instead.
+    ///
+    /// # Errors
+    /// - [`MnemonicEntropyError::ChecksumMismatch`] if this is a legacy mnemonic,
+    ///   and the `Mnemonic`'s checksum doesn't match up with the computed one.
+    pub fn to_entropy(&self) -> crate::Result<Vec<u8>> {
+        match &self.0 {
+            MnemonicData::V1(it) => it.to_entropy(),
+            MnemonicData::V2V3(it) => Ok(it.to_entropy_and_checksum().0),
+        }
     }
 
     /// Generate a new 12 word `Mnemonic` from the BIP-39 standard English word list.
@@ -204,14 +266,83 @@ impl Mnemonic {
         }
     }
 
+    /// Recover an Ed25519 [`PrivateKey`] from this `Mnemonic` along the SLIP-10 derivation `path`,
+    /// such as `m/44'/3030'/0'/0'/0'`.
+    ///
+    /// [`to_private_key`](Self::to_private_key) uses the path `m/44'/3030'/0'/0'`.
+    ///
+    /// # Errors
+    /// - [`Error::KeyDerive`] if `path` is not a valid derivation path, or has non-hardened indexes.
+    pub fn to_private_key_with_path(
+        &self,
+        passphrase: &str,
+        path: &str,
+    ) -> crate::Result<PrivateKey> {
+        PrivateKey::from_seed_ed25519(&self.to_seed(passphrase)).derive_path(path)
+    }
+
+    /// Recover an ECDSA(secp256k1) [`PrivateKey`] from this `Mnemonic` along the BIP-32 derivation `path`,
+    /// such as `m/44'/60'/0'/0/0` for the first account of Ethereum wallets.
+    ///
+    /// # Errors
+    /// - [`Error::KeyDerive`] if `path` is not a valid derivation path.
+    /// - [`Error::KeyDerive`] if any derived key is invalid (astronomically unlikely).
+    pub fn to_ecdsa_private_key_with_path(
+        &self,
+        passphrase: &str,
+        path: &str,
+    ) -> crate::Result<PrivateKey> {
+        PrivateKey::from_seed_ecdsa(&self.to_seed(passphrase))?.derive_path(path)
+    }
+
+    /// Recover an Ed25519 [`PrivateKey`] from this `Mnemonic` at the derivation path `m/44'/3030'/0'/0'/{index}'`,
+    /// the same key the other Hedera SDKs' `toStandardEd25519PrivateKey` produce.
+    ///
+    /// Ed25519 derivation is always hardened, so `index` is hardened regardless of its sign.
+    // The unwrap is for a "key is not derivable" error, but the master key is always derivable.
+    #[allow(clippy::missing_panics_doc)]
+    #[must_use]
+    pub fn to_standard_ed25519_private_key(&self, passphrase: &str, index: i32) -> PrivateKey {
+        let mut key = PrivateKey::from_seed_ed25519(&self.to_seed(passphrase));
+
+        for index in [44, 3030, 0, 0, index] {
+            key = key.derive(index).expect("BUG: the master key should always be derivable");
+        }
+
+        key
+    }
+
+    /// Recover an ECDSA(secp256k1) [`PrivateKey`] from this `Mnemonic` at the derivation path `m/44'/3030'/0'/0/{index}`,
+    /// the same key the other Hedera SDKs' `toStandardECDSAsecp256k1PrivateKey` produce.
+    ///
+    /// A negative `index` (the top bit set) derives a hardened key.
+    ///
+    /// # Errors
+    /// - [`Error::KeyDerive`] if any derived key is invalid (astronomically unlikely).
+    pub fn to_standard_ecdsa_secp256k1_private_key(
+        &self,
+        passphrase: &str,
+        index: i32,
+    ) -> crate::Result<PrivateKey> {
+        const HARDENED: i32 = i32::MIN;
+
+        let mut key = PrivateKey::from_seed_ecdsa(&self.to_seed(passphrase))?;
+
+        for index in [44 | HARDENED, 3030 | HARDENED, HARDENED, 0, index] {
+            key = key.derive(index)?;
+        }
+
+        Ok(key)
+    }
+
     pub(crate) fn to_seed(&self, phrase: &str) -> [u8; 64] {
         let mut salt = String::from("mnemonic");
-        salt.push_str(phrase);
+        salt.extend(phrase.nfkd());
 
         let mut mat = [0; 64];
 
         pbkdf2::pbkdf2::<Hmac<sha2::Sha512>>(
-            self.to_string().as_bytes(),
+            self.to_string().nfkd().collect::<String>().as_bytes(),
             salt.as_bytes(),
             2048,
             &mut mat,
@@ -226,8 +357,10 @@ impl fmt::Display for Mnemonic {
         if let Some((first, rest)) = self.words().split_first() {
             f.write_str(first)?;
 
+            let separator = self.language().separator();
+
             for word in rest.iter() {
-                write!(f, " {word}")?;
+                write!(f, "{separator}{word}")?;
             }
         }
 
@@ -287,6 +420,7 @@ impl MnemonicV1 {
 
 struct MnemonicV2V3 {
     words: Vec<String>,
+    language: MnemonicLanguage,
 }
 
 impl MnemonicV2V3 {
@@ -294,7 +428,7 @@ impl MnemonicV2V3 {
         &self.words
     }
 
-    fn from_entropy(entropy: &[u8]) -> Self {
+    fn from_entropy(entropy: &[u8], language: MnemonicLanguage) -> Self {
         assert!(entropy.len() == 16 || entropy.len() == 32);
 
         let entropy = {
@@ -314,13 +448,13 @@ impl MnemonicV2V3 {
             buffer = (buffer << 8) | u32::from(byte);
             offset += 8;
             if offset >= 11 {
-                let index = (buffer >> (offset - 11) & 0x7ff) as usize;
-                words.push(BIP39_WORD_LIST[index].to_owned());
+                let index = (buffer >> (offset - 11) & 0x7ff) as u16;
+                words.push(language.word_list().word(index).to_owned());
                 offset -= 11;
             }
         }
 
-        Self { words }
+        Self { words, language }
     }
 
     fn generate_12() -> Self {
@@ -328,7 +462,7 @@ impl MnemonicV2V3 {
         let mut entropy = [0; 16];
         rng.fill_bytes(&mut entropy);
 
-        Self::from_entropy(&entropy)
+        Self::from_entropy(&entropy, MnemonicLanguage::English)
     }
 
     fn generate_24() -> Self {
@@ -336,7 +470,16 @@ impl MnemonicV2V3 {
         let mut entropy = [0; 32];
         rng.fill_bytes(&mut entropy);
 
-        Self::from_entropy(&entropy)
+        Self::from_entropy(&entropy, MnemonicLanguage::English)
+    }
+
+    fn to_entropy_and_checksum(&self) -> (Vec<u8>, u8) {
+        let word_list = self.language.word_list();
+
+        let indecies: Vec<_> =
+            self.words.iter().map(|it| word_list.index_of(it).unwrap()).collect();
+
+        incecies_to_entropy_and_checksum(&indecies)
     }
 
     fn to_legacy_entropy(&self) -> crate::Result<Vec<u8>> {
@@ -349,7 +492,7 @@ impl MnemonicV2V3 {
         }
 
         // technically, this code all works for 12 words, but I'm going to pretend it doesn't.
-        let (entropy, actual_checksum) = words_to_entropy_and_checksum(&self.words);
+        let (entropy, actual_checksum) = self.to_entropy_and_checksum();
 
         let expected_checksum = checksum(&entropy);
         let expected_checksum =
@@ -426,16 +569,6 @@ fn convert_radix<I: IntoIterator<Item = i32>>(
     out
 }
 
-fn words_to_entropy_and_checksum<T: AsRef<str>>(words: &[T]) -> (Vec<u8>, u8) {
-    let indecies: Vec<_> = words
-        .iter()
-        .map(T::as_ref)
-        .map(|it| BIP39_WORD_LIST.binary_search(&it).unwrap() as u16)
-        .collect();
-
-    incecies_to_entropy_and_checksum(&indecies)
-}
-
 fn incecies_to_entropy_and_checksum(indecies: &[u16]) -> (Vec<u8>, u8) {
     assert!(matches!(indecies.len(), 12 | 24));
 
@@ -478,9 +611,16 @@ mod tests {
     use assert_matches::assert_matches;
     use expect_test::expect;
     use hex_literal::hex;
-
-    use super::Mnemonic;
-    use crate::error::MnemonicParseError;
+    use unicode_normalization::UnicodeNormalization;
+
+    use super::{
+        Mnemonic,
+        MnemonicLanguage,
+    };
+    use crate::error::{
+        MnemonicEntropyError,
+        MnemonicParseError,
+    };
     use crate::Error;
 
     const KNOWN_GOOD_MNEMONICS: &[&str] = &[
@@ -606,9 +746,10 @@ mod tests {
         ];
 
         for (entropy, s) in ENTROPY.into_iter().zip(KNOWN_GOOD_MNEMONICS) {
-            let mnemonic = Mnemonic(super::MnemonicV2V3::from_entropy(entropy).into());
+            let mnemonic = Mnemonic::from_entropy(entropy).unwrap();
 
             assert_eq!(&mnemonic.to_string(), s);
+            assert_eq!(&mnemonic.to_entropy().unwrap(), entropy);
         }
     }
 
@@ -684,4 +825,162 @@ mod tests {
         "#]]
         .assert_debug_eq(key.debug_pretty());
     }
+
+    #[test]
+    fn to_private_key_with_path() {
+        const MNEMONIC: &str = concat!(
+            "inmate flip alley wear offer often ",
+            "piece magnet surge toddler submit right ",
+            "radio absent pear floor belt raven ",
+            "price stove replace reduce plate home"
+        );
+
+        let mnemonic = Mnemonic::from_str(MNEMONIC).unwrap();
+
+        let key = mnemonic.to_private_key_with_path("", "m/44'/3030'/0'/0'").unwrap();
+
+        assert_eq!(key.to_bytes_raw(), mnemonic.to_private_key("").unwrap().to_bytes_raw());
+
+        assert_matches!(
+            mnemonic.to_private_key_with_path("", "m/44'/3030'/0'/0/0"),
+            Err(Error::KeyDerive(_))
+        );
+    }
+
+    #[test]
+    fn to_ecdsa_private_key_with_path() {
+        const MNEMONIC: &str = "test test test test test test test test test test test junk";
+
+        let mnemonic = Mnemonic::from_str(MNEMONIC).unwrap();
+
+        let key = mnemonic.to_ecdsa_private_key_with_path("", "m/44'/60'/0'/0/0").unwrap();
+
+        assert_eq!(
+            key.to_bytes_raw(),
+            hex!("ac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80")
+        );
+    }
+
+    #[test]
+    fn to_standard_ed25519_private_key() {
+        let mnemonic = Mnemonic::from_str(KNOWN_GOOD_MNEMONICS[0]).unwrap();
+
+        let key = mnemonic.to_standard_ed25519_private_key("", 0);
+
+        assert_eq!(
+            key.to_bytes_raw(),
+            hex!("f8dcc99a1ced1cc59bc2fee161c26ca6d6af657da9aa654da724441343ecd16f")
+        );
+
+        let key = mnemonic.to_standard_ed25519_private_key("some pass", 5);
+
+        assert_eq!(
+            key.to_bytes_raw(),
+            hex!("75720dcc031cafdc49ab988e670cefbe20c6bda8f3dc79b2b3dc75e910d50c45")
+        );
+        assert_eq!(
+            key.chain_code(),
+            Some(hex!("1d3d23b908af8c6586c0cec581cb2ef90df6dffadfe8b00b4a023717bc23aef9"))
+        );
+    }
+
+    #[test]
+    fn to_standard_ecdsa_secp256k1_private_key() {
+        let mnemonic = Mnemonic::from_str(KNOWN_GOOD_MNEMONICS[0]).unwrap();
+
+        let key = mnemonic.to_standard_ecdsa_secp256k1_private_key("", 0).unwrap();
+
+        assert_eq!(
+            key.to_bytes_raw(),
+            hex!("0fde7bfd57ae6ec310bdd8b95967d98e8762a2c02da6f694b152cf9860860ab8")
+        );
+
+        let key = mnemonic.to_standard_ecdsa_secp256k1_private_key("some pass", 5).unwrap();
+
+        assert_eq!(
+            key.to_bytes_raw(),
+            hex!("77e3d796fae5da29014afd30ac8ee626bfb766bde564d2182cc6e48d9ad878d7")
+        );
+        assert_eq!(
+            key.chain_code(),
+            Some(hex!("9be36a8bf354ae26649edf671e11a053ba0a7989a6b7380aa11257a514e35ecf"))
+        );
+
+        // hardened
+        let key = mnemonic.to_standard_ecdsa_secp256k1_private_key("", 1 | i32::MIN).unwrap();
+
+        assert_eq!(
+            key.to_bytes_raw(),
+            hex!("12b31b6f8f19532f95a3e5a686ed44d2faa1972b9acb54c760a3c349141e4620")
+        );
+    }
+
+    #[test]
+    fn from_entropy_bad_length() {
+        assert_matches!(
+            Mnemonic::from_entropy(&[0; 20]),
+            Err(Error::MnemonicEntropy(MnemonicEntropyError::BadEntropyLength(20)))
+        );
+    }
+
+    #[test]
+    fn japanese() {
+        // from the BIP-39 Japanese test vectors.
+        const MNEMONIC: &str = concat!(
+            "あいこくしん　あいこくしん　あいこくしん　あいこくしん　",
+            "あいこくしん　あいこくしん　あいこくしん　あいこくしん　",
+            "あいこくしん　あいこくしん　あいこくしん　あおぞら"
+        );
+
+        let mnemonic = Mnemonic::from_str(MNEMONIC).unwrap();
+
+        assert_eq!(mnemonic.language(), MnemonicLanguage::Japanese);
+        // the words are stored NFKD normalized, which decomposes the dakuten (゛).
+        assert_eq!(mnemonic.to_string(), MNEMONIC.nfd().collect::<String>());
+        assert_eq!(mnemonic.to_entropy().unwrap(), [0; 16]);
+
+        assert_eq!(
+            mnemonic.to_seed("㍍ガバヴァぱばぐゞちぢ十人十色"),
+            hex!(
+                "a262d6fb6122ecf45be09c50492b31f92e9beb7d9a845987a02cefda57a15f9c"
+                "467a17872029a9e92299b5cbdf306e3a0ee620245cbd508959b6cb7ca637bd55"
+            )
+        );
+
+        let other = Mnemonic::from_entropy_with_language(&[0; 16], MnemonicLanguage::Japanese);
+
+        assert_eq!(other.unwrap().to_string(), MNEMONIC.nfd().collect::<String>());
+    }
+
+    #[test]
+    fn detect_language() {
+        const ENTROPY: [u8; 16] = hex!("7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f7f");
+
+        // "véloce" is NFC here, but NFKD in the word list.
+        const FRENCH: &str = concat!(
+            "implorer visage sonnette voyage ",
+            "véloce pourpre volaille tribunal ",
+            "implorer visage sonnette voyelle"
+        );
+
+        let mnemonic = Mnemonic::from_str(FRENCH).unwrap();
+
+        assert_eq!(mnemonic.language(), MnemonicLanguage::French);
+        assert_eq!(mnemonic.to_entropy().unwrap(), ENTROPY);
+
+        const SPANISH: &str =
+            "ligero vista talar yogur venta queso yacer trozo ligero vista talar zafiro";
+
+        let mnemonic = Mnemonic::from_str(SPANISH).unwrap();
+
+        assert_eq!(mnemonic.language(), MnemonicLanguage::Spanish);
+        assert_eq!(mnemonic.to_entropy().unwrap(), ENTROPY);
+
+        for language in MnemonicLanguage::ALL {
+            let mnemonic = Mnemonic::from_entropy_with_language(&ENTROPY, language).unwrap();
+            let parsed = Mnemonic::from_str(&mnemonic.to_string()).unwrap();
+
+            assert_eq!(parsed.to_entropy().unwrap(), ENTROPY);
+        }
+    }
 }
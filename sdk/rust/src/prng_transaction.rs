@@ -0,0 +1,233 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use hedera_proto::services;
+use hedera_proto::services::util_service_client::UtilServiceClient;
+use tonic::transport::Channel;
+
+use crate::protobuf::{
+    FromProtobuf,
+    ToProtobuf,
+};
+use crate::transaction::{
+    AnyTransactionData,
+    ChunkInfo,
+    ToSchedulableTransactionDataProtobuf,
+    ToTransactionDataProtobuf,
+    TransactionData,
+    TransactionExecute,
+};
+use crate::{
+    BoxGrpcFuture,
+    Error,
+    LedgerId,
+    Transaction,
+    ValidateChecksums,
+};
+
+/// Generates a pseudorandom number, using the network's entropy.
+///
+/// If a range is set, the transaction record's `prng_number` will be a pseudorandom number in `[0, range)`,
+/// otherwise the record's `prng_bytes` will be a pseudorandom 384-bit string.
+pub type PrngTransaction = Transaction<PrngTransactionData>;
+
+#[cfg_attr(feature = "ffi", serde_with::skip_serializing_none)]
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "ffi", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "ffi", serde(rename_all = "camelCase", default))]
+pub struct PrngTransactionData {
+    /// The exclusive upper bound of the number to generate, if any.
+    range: Option<i32>,
+}
+
+impl PrngTransaction {
+    /// Returns the upper bound for the random number.
+    #[must_use]
+    pub fn get_range(&self) -> Option<i32> {
+        self.data().range
+    }
+
+    /// Sets the upper bound for the random number.
+    ///
+    /// If set, the generated number will be in `[0, range)`,
+    /// if unset (or `0`), 384 pseudorandom bits will be generated instead.
+    ///
+    /// The network rejects negative ranges.
+    pub fn range(&mut self, range: i32) -> &mut Self {
+        self.data_mut().range = Some(range);
+        self
+    }
+}
+
+impl TransactionData for PrngTransactionData {
+    fn validate(&self) -> crate::Result<()> {
+        match self.range {
+            Some(range) if range < 0 => {
+                Err(Error::invalid_transaction_field("range", "must not be negative"))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl TransactionExecute for PrngTransactionData {
+    fn execute(
+        &self,
+        channel: Channel,
+        request: services::Transaction,
+    ) -> BoxGrpcFuture<'_, services::TransactionResponse> {
+        Box::pin(async { UtilServiceClient::new(channel).prng(request).await })
+    }
+}
+
+impl ValidateChecksums for PrngTransactionData {
+    fn validate_checksums(&self, _ledger_id: &LedgerId) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+impl ToTransactionDataProtobuf for PrngTransactionData {
+    fn to_transaction_data_protobuf(
+        &self,
+        chunk_info: &ChunkInfo,
+    ) -> services::transaction_body::Data {
+        let _ = chunk_info.assert_single_transaction();
+
+        services::transaction_body::Data::UtilPrng(self.to_protobuf())
+    }
+}
+
+impl ToSchedulableTransactionDataProtobuf for PrngTransactionData {
+    fn to_schedulable_transaction_data_protobuf(
+        &self,
+    ) -> services::schedulable_transaction_body::Data {
+        services::schedulable_transaction_body::Data::UtilPrng(self.to_protobuf())
+    }
+}
+
+impl From<PrngTransactionData> for AnyTransactionData {
+    fn from(transaction: PrngTransactionData) -> Self {
+        Self::Prng(transaction)
+    }
+}
+
+impl FromProtobuf<services::UtilPrngTransactionBody> for PrngTransactionData {
+    fn from_protobuf(pb: services::UtilPrngTransactionBody) -> crate::Result<Self> {
+        Ok(Self { range: (pb.range != 0).then_some(pb.range) })
+    }
+}
+
+impl ToProtobuf for PrngTransactionData {
+    type Protobuf = services::UtilPrngTransactionBody;
+
+    fn to_protobuf(&self) -> Self::Protobuf {
+        services::UtilPrngTransactionBody { range: self.range.unwrap_or_default() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+
+    use crate::protobuf::ToProtobuf;
+    use crate::transaction::test_helpers::transaction_id;
+    use crate::transaction::AnyTransactionData;
+    use crate::{
+        AnyTransaction,
+        Error,
+        PrngTransaction,
+    };
+
+    #[test]
+    fn to_from_bytes() -> crate::Result<()> {
+        let bytes = PrngTransaction::new()
+            .range(100)
+            .transaction_id(transaction_id())
+            .node_account_ids([6.into()])
+            .freeze()?
+            .to_bytes()?;
+
+        let tx = AnyTransaction::from_bytes(&bytes)?;
+
+        let data = assert_matches!(tx.data(), AnyTransactionData::Prng(it) => it);
+
+        assert_eq!(data.range, Some(100));
+
+        Ok(())
+    }
+
+    #[test]
+    fn unset_range_is_zero() {
+        let tx = PrngTransaction::new();
+
+        assert_eq!(tx.data().to_protobuf().range, 0);
+        assert_eq!(tx.get_range(), None);
+    }
+
+    #[test]
+    fn negative_range_fails_to_freeze() {
+        let mut tx = PrngTransaction::new();
+
+        tx.range(-1).node_account_ids([6.into()]);
+
+        assert_matches!(tx.freeze(), Err(Error::InvalidTransactionField { field: "range", .. }));
+    }
+
+    #[cfg(feature = "ffi")]
+    mod ffi {
+        use assert_matches::assert_matches;
+
+        use crate::transaction::{
+            AnyTransaction,
+            AnyTransactionData,
+        };
+        use crate::PrngTransaction;
+
+        // language=JSON
+        const PRNG_TRANSACTION_JSON: &str = r#"{
+  "$type": "prng",
+  "range": 100
+}"#;
+
+        #[test]
+        fn it_should_serialize() -> anyhow::Result<()> {
+            let mut transaction = PrngTransaction::new();
+
+            transaction.range(100);
+
+            let transaction_json = serde_json::to_string_pretty(&transaction)?;
+
+            assert_eq!(transaction_json, PRNG_TRANSACTION_JSON);
+
+            Ok(())
+        }
+
+        #[test]
+        fn it_should_deserialize() -> anyhow::Result<()> {
+            let transaction: AnyTransaction = serde_json::from_str(PRNG_TRANSACTION_JSON)?;
+
+            let data = assert_matches!(transaction.data(), AnyTransactionData::Prng(transaction) => transaction);
+
+            assert_eq!(data.range, Some(100));
+
+            Ok(())
+        }
+    }
+}
@@ -81,6 +81,17 @@ pub trait QueryExecute:
         <Self::Response as FromProtobuf<services::response::Response>>::from_protobuf(response)
     }
 
+    /// Create an error with more detail than the pre-check status alone, from the `response` that carried it.
+    #[allow(unused_variables)]
+    fn make_error_pre_check_response(
+        &self,
+        status: Status,
+        transaction_id: Option<TransactionId>,
+        response: services::Response,
+    ) -> Option<crate::Error> {
+        None
+    }
+
     /// Execute the prepared query request against the provided GRPC channel.
     fn execute(
         &self,
@@ -169,6 +180,17 @@ where
         }
     }
 
+    fn make_error_pre_check_response(
+        &self,
+        status: crate::Status,
+        transaction_id: Option<TransactionId>,
+        response: Self::GrpcResponse,
+    ) -> crate::Error {
+        self.data
+            .make_error_pre_check_response(status, transaction_id, response)
+            .unwrap_or_else(|| self.make_error_pre_check(status, transaction_id))
+    }
+
     fn response_pre_check_status(response: &Self::GrpcResponse) -> crate::Result<i32> {
         Ok(response_header(&response.response)?.node_transaction_precheck_code)
     }
@@ -2,6 +2,7 @@ use hedera_proto::services;
 
 use crate::protobuf::FromProtobuf;
 use crate::transaction::{
+    validate,
     AnyTransactionData,
     ToSchedulableTransactionDataProtobuf,
     TransactionData,
@@ -28,6 +29,7 @@ mod data {
         FileDeleteTransactionData as FileDelete,
         FileUpdateTransactionData as FileUpdate,
     };
+    pub(super) use crate::prng_transaction::PrngTransactionData as Prng;
     pub(super) use crate::schedule::ScheduleDeleteTransactionData as ScheduleDelete;
     pub(super) use crate::system::{
         FreezeTransactionData as Freeze,
@@ -87,6 +89,13 @@ impl FromProtobuf<services::SchedulableTransactionBody> for SchedulableTransacti
 }
 
 impl SchedulableTransactionBody {
+    /// Validate the scheduled transaction as if it were being frozen on its own.
+    pub(super) fn validate(&self) -> crate::Result<()> {
+        validate::memo("transaction_memo", &self.transaction_memo)?;
+
+        AnyTransactionData::from((*self.data).clone()).validate()
+    }
+
     pub(super) fn to_scheduled_body_protobuf(&self) -> services::SchedulableTransactionBody {
         services::SchedulableTransactionBody {
             data: Some(self.data.to_schedulable_transaction_data_protobuf()),
@@ -141,6 +150,7 @@ pub(super) enum AnySchedulableTransactionData {
     SystemUndelete(data::SystemUndelete),
     Freeze(data::Freeze),
     ScheduleDelete(data::ScheduleDelete),
+    Prng(data::Prng),
 }
 
 impl AnySchedulableTransactionData {
@@ -191,6 +201,7 @@ impl AnySchedulableTransactionData {
             AnySchedulableTransactionData::SystemUndelete(it) => it.default_max_transaction_fee(),
             AnySchedulableTransactionData::Freeze(it) => it.default_max_transaction_fee(),
             AnySchedulableTransactionData::ScheduleDelete(it) => it.default_max_transaction_fee(),
+            AnySchedulableTransactionData::Prng(it) => it.default_max_transaction_fee(),
         }
     }
 }
@@ -282,7 +293,7 @@ impl FromProtobuf<services::schedulable_transaction_body::Data> for AnySchedulab
             Data::ScheduleDelete(it) => {
                 Ok(Self::ScheduleDelete(data::ScheduleDelete::from_protobuf(it)?))
             }
-            Data::UtilPrng(_) => unimplemented!("Prng transaction not currently implemented"),
+            Data::UtilPrng(it) => Ok(Self::Prng(data::Prng::from_protobuf(it)?)),
         }
     }
 }
@@ -403,6 +414,9 @@ impl ToSchedulableTransactionDataProtobuf for AnySchedulableTransactionData {
             AnySchedulableTransactionData::ScheduleDelete(it) => {
                 it.to_schedulable_transaction_data_protobuf()
             }
+            AnySchedulableTransactionData::Prng(it) => {
+                it.to_schedulable_transaction_data_protobuf()
+            }
         }
     }
 }
@@ -451,6 +465,7 @@ impl TryFrom<AnyTransactionData> for AnySchedulableTransactionData {
             AnyTransactionData::SystemUndelete(it) => Ok(Self::SystemUndelete(it)),
             AnyTransactionData::Freeze(it) => Ok(Self::Freeze(it)),
             AnyTransactionData::ScheduleDelete(it) => Ok(Self::ScheduleDelete(it)),
+            AnyTransactionData::Prng(it) => Ok(Self::Prng(it)),
             // fixme: basic-parse isn't suitable for this.
             AnyTransactionData::ScheduleCreate(_) => {
                 Err(crate::Error::basic_parse("Cannot schedule `ScheduleCreateTransaction`"))
@@ -511,6 +526,7 @@ impl From<AnySchedulableTransactionData> for AnyTransactionData {
             AnySchedulableTransactionData::SystemUndelete(it) => Self::SystemUndelete(it),
             AnySchedulableTransactionData::Freeze(it) => Self::Freeze(it),
             AnySchedulableTransactionData::ScheduleDelete(it) => Self::ScheduleDelete(it),
+            AnySchedulableTransactionData::Prng(it) => Self::Prng(it),
         }
     }
 }
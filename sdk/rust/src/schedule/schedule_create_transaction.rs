@@ -29,6 +29,7 @@ use crate::protobuf::{
     ToProtobuf,
 };
 use crate::transaction::{
+    validate,
     AnyTransactionData,
     ChunkInfo,
     ToSchedulableTransactionDataProtobuf,
@@ -167,7 +168,24 @@ impl ScheduleCreateTransaction {
     }
 }
 
-impl TransactionData for ScheduleCreateTransactionData {}
+impl TransactionData for ScheduleCreateTransactionData {
+    fn validate(&self) -> crate::Result<()> {
+        match &self.scheduled_transaction {
+            Some(scheduled) => scheduled.validate()?,
+            None => {
+                return Err(Error::invalid_transaction_field(
+                    "scheduled_transaction",
+                    "must be set",
+                ))
+            }
+        }
+
+        validate::memo("schedule_memo", self.schedule_memo.as_deref().unwrap_or_default())?;
+        validate::optional_key("admin_key", &self.admin_key)?;
+
+        Ok(())
+    }
+}
 
 impl TransactionExecute for ScheduleCreateTransactionData {
     fn execute(
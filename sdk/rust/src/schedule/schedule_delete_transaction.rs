@@ -27,6 +27,7 @@ use crate::protobuf::{
     ToProtobuf,
 };
 use crate::transaction::{
+    validate,
     AnyTransactionData,
     ChunkInfo,
     ToSchedulableTransactionDataProtobuf,
@@ -68,7 +69,13 @@ impl ScheduleDeleteTransaction {
         self
     }
 }
-impl TransactionData for ScheduleDeleteTransactionData {}
+impl TransactionData for ScheduleDeleteTransactionData {
+    fn validate(&self) -> crate::Result<()> {
+        validate::require("schedule_id", &self.schedule_id)?;
+
+        Ok(())
+    }
+}
 
 impl TransactionExecute for ScheduleDeleteTransactionData {
     fn execute(
@@ -27,6 +27,7 @@ use crate::protobuf::{
     ToProtobuf,
 };
 use crate::transaction::{
+    validate,
     AnyTransactionData,
     ChunkInfo,
     ToTransactionDataProtobuf,
@@ -66,7 +67,13 @@ impl ScheduleSignTransaction {
     }
 }
 
-impl TransactionData for ScheduleSignTransactionData {}
+impl TransactionData for ScheduleSignTransactionData {
+    fn validate(&self) -> crate::Result<()> {
+        validate::require("schedule_id", &self.schedule_id)?;
+
+        Ok(())
+    }
+}
 
 impl TransactionExecute for ScheduleSignTransactionData {
     fn execute(
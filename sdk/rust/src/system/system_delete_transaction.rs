@@ -117,7 +117,18 @@ impl SystemDeleteTransaction {
     }
 }
 
-impl TransactionData for SystemDeleteTransactionData {}
+impl TransactionData for SystemDeleteTransactionData {
+    fn validate(&self) -> crate::Result<()> {
+        if self.file_id.is_none() && self.contract_id.is_none() {
+            return Err(Error::invalid_transaction_field(
+                "file_id",
+                "either `file_id` or `contract_id` must be set",
+            ));
+        }
+
+        Ok(())
+    }
+}
 
 impl TransactionExecute for SystemDeleteTransactionData {
     fn execute(
@@ -87,7 +87,18 @@ impl SystemUndeleteTransaction {
     }
 }
 
-impl TransactionData for SystemUndeleteTransactionData {}
+impl TransactionData for SystemUndeleteTransactionData {
+    fn validate(&self) -> crate::Result<()> {
+        if self.file_id.is_none() && self.contract_id.is_none() {
+            return Err(Error::invalid_transaction_field(
+                "file_id",
+                "either `file_id` or `contract_id` must be set",
+            ));
+        }
+
+        Ok(())
+    }
+}
 
 impl TransactionExecute for SystemUndeleteTransactionData {
     fn execute(
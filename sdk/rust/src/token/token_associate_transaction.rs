@@ -24,6 +24,7 @@ use tonic::transport::Channel;
 
 use crate::protobuf::FromProtobuf;
 use crate::transaction::{
+    validate,
     AnyTransactionData,
     ChunkInfo,
     ToSchedulableTransactionDataProtobuf,
@@ -95,7 +96,13 @@ impl TokenAssociateTransaction {
     }
 }
 
-impl TransactionData for TokenAssociateTransactionData {}
+impl TransactionData for TokenAssociateTransactionData {
+    fn validate(&self) -> crate::Result<()> {
+        validate::require("account_id", &self.account_id)?;
+
+        Ok(())
+    }
+}
 
 impl TransactionExecute for TokenAssociateTransactionData {
     fn execute(
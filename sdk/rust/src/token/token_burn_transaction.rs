@@ -27,6 +27,7 @@ use crate::protobuf::{
     ToProtobuf,
 };
 use crate::transaction::{
+    validate,
     AnyTransactionData,
     ChunkInfo,
     ToSchedulableTransactionDataProtobuf,
@@ -121,7 +122,14 @@ impl TokenBurnTransaction {
     }
 }
 
-impl TransactionData for TokenBurnTransactionData {}
+impl TransactionData for TokenBurnTransactionData {
+    fn validate(&self) -> crate::Result<()> {
+        validate::require("token_id", &self.token_id)?;
+        validate::max_len("serials", self.serials.len(), validate::MAX_NFT_BATCH_SIZE)?;
+
+        Ok(())
+    }
+}
 
 impl TransactionExecute for TokenBurnTransactionData {
     fn execute(
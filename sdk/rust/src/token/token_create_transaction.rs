@@ -34,6 +34,7 @@ use crate::token::custom_fees::AnyCustomFee;
 use crate::token::token_supply_type::TokenSupplyType;
 use crate::token::token_type::TokenType;
 use crate::transaction::{
+    validate,
     AnyTransactionData,
     ChunkInfo,
     ToSchedulableTransactionDataProtobuf,
@@ -452,7 +453,31 @@ impl TokenCreateTransaction {
     }
 }
 
-impl TransactionData for TokenCreateTransactionData {}
+impl TransactionData for TokenCreateTransactionData {
+    fn validate(&self) -> crate::Result<()> {
+        if self.name.is_empty() {
+            return Err(Error::invalid_transaction_field("name", "must not be empty"));
+        }
+        validate::max_bytes("name", &self.name, validate::MAX_TOKEN_NAME_BYTES)?;
+
+        if self.symbol.is_empty() {
+            return Err(Error::invalid_transaction_field("symbol", "must not be empty"));
+        }
+        validate::max_bytes("symbol", &self.symbol, validate::MAX_TOKEN_NAME_BYTES)?;
+        validate::require("treasury_account_id", &self.treasury_account_id)?;
+        validate::memo("token_memo", &self.token_memo)?;
+        validate::optional_key("admin_key", &self.admin_key)?;
+        validate::optional_key("kyc_key", &self.kyc_key)?;
+        validate::optional_key("freeze_key", &self.freeze_key)?;
+        validate::optional_key("wipe_key", &self.wipe_key)?;
+        validate::optional_key("supply_key", &self.supply_key)?;
+        validate::optional_key("fee_schedule_key", &self.fee_schedule_key)?;
+        validate::optional_key("pause_key", &self.pause_key)?;
+        validate::max_len("custom_fees", self.custom_fees.len(), validate::MAX_CUSTOM_FEES)?;
+
+        Ok(())
+    }
+}
 
 impl TransactionExecute for TokenCreateTransactionData {
     fn execute(
@@ -27,6 +27,7 @@ use crate::protobuf::{
     ToProtobuf,
 };
 use crate::transaction::{
+    validate,
     AnyTransactionData,
     ChunkInfo,
     ToSchedulableTransactionDataProtobuf,
@@ -77,7 +78,13 @@ impl TokenDeleteTransaction {
     }
 }
 
-impl TransactionData for TokenDeleteTransactionData {}
+impl TransactionData for TokenDeleteTransactionData {
+    fn validate(&self) -> crate::Result<()> {
+        validate::require("token_id", &self.token_id)?;
+
+        Ok(())
+    }
+}
 
 impl TransactionExecute for TokenDeleteTransactionData {
     fn execute(
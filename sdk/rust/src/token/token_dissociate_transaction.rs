@@ -27,6 +27,7 @@ use crate::protobuf::{
     ToProtobuf,
 };
 use crate::transaction::{
+    validate,
     AnyTransactionData,
     ChunkInfo,
     ToSchedulableTransactionDataProtobuf,
@@ -101,7 +102,13 @@ impl TokenDissociateTransaction {
     }
 }
 
-impl TransactionData for TokenDissociateTransactionData {}
+impl TransactionData for TokenDissociateTransactionData {
+    fn validate(&self) -> crate::Result<()> {
+        validate::require("account_id", &self.account_id)?;
+
+        Ok(())
+    }
+}
 
 impl TransactionExecute for TokenDissociateTransactionData {
     fn execute(
@@ -28,6 +28,7 @@ use crate::protobuf::{
 };
 use crate::token::custom_fees::AnyCustomFee;
 use crate::transaction::{
+    validate,
     AnyTransactionData,
     ChunkInfo,
     ToSchedulableTransactionDataProtobuf,
@@ -96,7 +97,14 @@ impl TokenFeeScheduleUpdateTransaction {
     }
 }
 
-impl TransactionData for TokenFeeScheduleUpdateTransactionData {}
+impl TransactionData for TokenFeeScheduleUpdateTransactionData {
+    fn validate(&self) -> crate::Result<()> {
+        validate::require("token_id", &self.token_id)?;
+        validate::max_len("custom_fees", self.custom_fees.len(), validate::MAX_CUSTOM_FEES)?;
+
+        Ok(())
+    }
+}
 
 impl TransactionExecute for TokenFeeScheduleUpdateTransactionData {
     fn execute(
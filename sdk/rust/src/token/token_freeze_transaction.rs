@@ -27,6 +27,7 @@ use crate::protobuf::{
     ToProtobuf,
 };
 use crate::transaction::{
+    validate,
     AnyTransactionData,
     ChunkInfo,
     ToSchedulableTransactionDataProtobuf,
@@ -96,7 +97,14 @@ impl TokenFreezeTransaction {
     }
 }
 
-impl TransactionData for TokenFreezeTransactionData {}
+impl TransactionData for TokenFreezeTransactionData {
+    fn validate(&self) -> crate::Result<()> {
+        validate::require("token_id", &self.token_id)?;
+        validate::require("account_id", &self.account_id)?;
+
+        Ok(())
+    }
+}
 
 impl TransactionExecute for TokenFreezeTransactionData {
     fn execute(
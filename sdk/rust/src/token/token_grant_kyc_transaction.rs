@@ -27,6 +27,7 @@ use crate::protobuf::{
     ToProtobuf,
 };
 use crate::transaction::{
+    validate,
     AnyTransactionData,
     ChunkInfo,
     ToSchedulableTransactionDataProtobuf,
@@ -95,7 +96,14 @@ impl TokenGrantKycTransaction {
     }
 }
 
-impl TransactionData for TokenGrantKycTransactionData {}
+impl TransactionData for TokenGrantKycTransactionData {
+    fn validate(&self) -> crate::Result<()> {
+        validate::require("token_id", &self.token_id)?;
+        validate::require("account_id", &self.account_id)?;
+
+        Ok(())
+    }
+}
 
 impl TransactionExecute for TokenGrantKycTransactionData {
     fn execute(
@@ -27,6 +27,7 @@ use crate::protobuf::{
     ToProtobuf,
 };
 use crate::transaction::{
+    validate,
     AnyTransactionData,
     ChunkInfo,
     ToSchedulableTransactionDataProtobuf,
@@ -122,7 +123,21 @@ impl TokenMintTransaction {
     }
 }
 
-impl TransactionData for TokenMintTransactionData {}
+impl TransactionData for TokenMintTransactionData {
+    fn validate(&self) -> crate::Result<()> {
+        validate::require("token_id", &self.token_id)?;
+        validate::max_len("metadata", self.metadata.len(), validate::MAX_NFT_BATCH_SIZE)?;
+
+        if self.amount != 0 && !self.metadata.is_empty() {
+            return Err(Error::invalid_transaction_field(
+                "metadata",
+                "cannot mint both a fungible `amount` and NFT `metadata`",
+            ));
+        }
+
+        Ok(())
+    }
+}
 
 impl TransactionExecute for TokenMintTransactionData {
     fn execute(
@@ -27,6 +27,7 @@ use crate::protobuf::{
     ToProtobuf,
 };
 use crate::transaction::{
+    validate,
     AnyTransactionData,
     ChunkInfo,
     ToSchedulableTransactionDataProtobuf,
@@ -78,7 +79,13 @@ impl TokenPauseTransaction {
     }
 }
 
-impl TransactionData for TokenPauseTransactionData {}
+impl TransactionData for TokenPauseTransactionData {
+    fn validate(&self) -> crate::Result<()> {
+        validate::require("token_id", &self.token_id)?;
+
+        Ok(())
+    }
+}
 
 impl TransactionExecute for TokenPauseTransactionData {
     fn execute(
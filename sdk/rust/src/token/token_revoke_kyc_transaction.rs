@@ -27,6 +27,7 @@ use crate::protobuf::{
     ToProtobuf,
 };
 use crate::transaction::{
+    validate,
     AnyTransactionData,
     ChunkInfo,
     ToSchedulableTransactionDataProtobuf,
@@ -96,7 +97,14 @@ impl TokenRevokeKycTransaction {
     }
 }
 
-impl TransactionData for TokenRevokeKycTransactionData {}
+impl TransactionData for TokenRevokeKycTransactionData {
+    fn validate(&self) -> crate::Result<()> {
+        validate::require("token_id", &self.token_id)?;
+        validate::require("account_id", &self.account_id)?;
+
+        Ok(())
+    }
+}
 
 impl TransactionExecute for TokenRevokeKycTransactionData {
     fn execute(
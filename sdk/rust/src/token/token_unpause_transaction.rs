@@ -27,6 +27,7 @@ use crate::protobuf::{
     ToProtobuf,
 };
 use crate::transaction::{
+    validate,
     AnyTransactionData,
     ChunkInfo,
     ToSchedulableTransactionDataProtobuf,
@@ -77,7 +78,13 @@ impl TokenUnpauseTransaction {
     }
 }
 
-impl TransactionData for TokenUnpauseTransactionData {}
+impl TransactionData for TokenUnpauseTransactionData {
+    fn validate(&self) -> crate::Result<()> {
+        validate::require("token_id", &self.token_id)?;
+
+        Ok(())
+    }
+}
 
 impl TransactionExecute for TokenUnpauseTransactionData {
     fn execute(
@@ -31,6 +31,7 @@ use crate::protobuf::{
     ToProtobuf,
 };
 use crate::transaction::{
+    validate,
     AnyTransactionData,
     ChunkInfo,
     ToSchedulableTransactionDataProtobuf,
@@ -348,7 +349,23 @@ impl TokenUpdateTransaction {
     }
 }
 
-impl TransactionData for TokenUpdateTransactionData {}
+impl TransactionData for TokenUpdateTransactionData {
+    fn validate(&self) -> crate::Result<()> {
+        validate::require("token_id", &self.token_id)?;
+        validate::max_bytes("token_name", &self.token_name, validate::MAX_TOKEN_NAME_BYTES)?;
+        validate::max_bytes("token_symbol", &self.token_symbol, validate::MAX_TOKEN_NAME_BYTES)?;
+        validate::memo("token_memo", &self.token_memo)?;
+        validate::optional_update_key("admin_key", &self.admin_key)?;
+        validate::optional_update_key("kyc_key", &self.kyc_key)?;
+        validate::optional_update_key("freeze_key", &self.freeze_key)?;
+        validate::optional_update_key("wipe_key", &self.wipe_key)?;
+        validate::optional_update_key("supply_key", &self.supply_key)?;
+        validate::optional_update_key("fee_schedule_key", &self.fee_schedule_key)?;
+        validate::optional_update_key("pause_key", &self.pause_key)?;
+
+        Ok(())
+    }
+}
 
 impl TransactionExecute for TokenUpdateTransactionData {
     fn execute(
@@ -27,6 +27,7 @@ use crate::protobuf::{
     ToProtobuf,
 };
 use crate::transaction::{
+    validate,
     AnyTransactionData,
     ChunkInfo,
     ToSchedulableTransactionDataProtobuf,
@@ -144,7 +145,15 @@ impl TokenWipeTransaction {
     }
 }
 
-impl TransactionData for TokenWipeTransactionData {}
+impl TransactionData for TokenWipeTransactionData {
+    fn validate(&self) -> crate::Result<()> {
+        validate::require("token_id", &self.token_id)?;
+        validate::require("account_id", &self.account_id)?;
+        validate::max_len("serials", self.serials.len(), validate::MAX_NFT_BATCH_SIZE)?;
+
+        Ok(())
+    }
+}
 
 impl TransactionExecute for TokenWipeTransactionData {
     fn execute(
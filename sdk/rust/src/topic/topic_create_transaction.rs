@@ -28,6 +28,7 @@ use crate::protobuf::{
     ToProtobuf,
 };
 use crate::transaction::{
+    validate,
     AnyTransactionData,
     ChunkInfo,
     ToSchedulableTransactionDataProtobuf,
@@ -163,7 +164,15 @@ impl TopicCreateTransaction {
     }
 }
 
-impl TransactionData for TopicCreateTransactionData {}
+impl TransactionData for TopicCreateTransactionData {
+    fn validate(&self) -> crate::Result<()> {
+        validate::memo("topic_memo", &self.topic_memo)?;
+        validate::optional_key("admin_key", &self.admin_key)?;
+        validate::optional_key("submit_key", &self.submit_key)?;
+
+        Ok(())
+    }
+}
 
 impl TransactionExecute for TopicCreateTransactionData {
     fn execute(
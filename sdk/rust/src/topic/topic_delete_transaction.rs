@@ -27,6 +27,7 @@ use crate::protobuf::{
     ToProtobuf,
 };
 use crate::transaction::{
+    validate,
     AnyTransactionData,
     ChunkInfo,
     ToSchedulableTransactionDataProtobuf,
@@ -75,7 +76,13 @@ impl TopicDeleteTransaction {
     }
 }
 
-impl TransactionData for TopicDeleteTransactionData {}
+impl TransactionData for TopicDeleteTransactionData {
+    fn validate(&self) -> crate::Result<()> {
+        validate::require("topic_id", &self.topic_id)?;
+
+        Ok(())
+    }
+}
 
 impl TransactionExecute for TopicDeleteTransactionData {
     fn execute(
@@ -30,6 +30,7 @@ use crate::protobuf::{
     ToProtobuf,
 };
 use crate::transaction::{
+    validate,
     AnyTransactionData,
     ChunkData,
     ChunkInfo,
@@ -99,6 +100,12 @@ impl TopicMessageSubmitTransaction {
 }
 
 impl TransactionData for TopicMessageSubmitTransactionData {
+    fn validate(&self) -> crate::Result<()> {
+        validate::require("topic_id", &self.topic_id)?;
+
+        Ok(())
+    }
+
     fn maybe_chunk_data(&self) -> Option<&ChunkData> {
         Some(self.chunk_data())
     }
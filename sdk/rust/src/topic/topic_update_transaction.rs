@@ -31,6 +31,7 @@ use crate::protobuf::{
     ToProtobuf,
 };
 use crate::transaction::{
+    validate,
     AnyTransactionData,
     ChunkInfo,
     ToSchedulableTransactionDataProtobuf,
@@ -182,7 +183,16 @@ impl TopicUpdateTransaction {
     }
 }
 
-impl TransactionData for TopicUpdateTransactionData {}
+impl TransactionData for TopicUpdateTransactionData {
+    fn validate(&self) -> crate::Result<()> {
+        validate::require("topic_id", &self.topic_id)?;
+        validate::memo("topic_memo", self.topic_memo.as_deref().unwrap_or_default())?;
+        validate::optional_update_key("admin_key", &self.admin_key)?;
+        validate::optional_update_key("submit_key", &self.submit_key)?;
+
+        Ok(())
+    }
+}
 
 impl TransactionExecute for TopicUpdateTransactionData {
     fn execute(
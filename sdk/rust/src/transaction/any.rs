@@ -59,13 +59,14 @@ mod data {
         ContractExecuteTransactionData as ContractExecute,
         ContractUpdateTransactionData as ContractUpdate,
     };
-    pub(super) use crate::ethereum_transaction::EthereumTransactionData as Ethereum;
+    pub(super) use crate::ethereum::EthereumTransactionData as Ethereum;
     pub(super) use crate::file::{
         FileAppendTransactionData as FileAppend,
         FileCreateTransactionData as FileCreate,
         FileDeleteTransactionData as FileDelete,
         FileUpdateTransactionData as FileUpdate,
     };
+    pub(super) use crate::prng_transaction::PrngTransactionData as Prng;
     pub(super) use crate::schedule::{
         ScheduleCreateTransactionData as ScheduleCreate,
         ScheduleDeleteTransactionData as ScheduleDelete,
@@ -149,6 +150,7 @@ pub enum AnyTransactionData {
     ScheduleSign(data::ScheduleSign),
     ScheduleDelete(data::ScheduleDelete),
     Ethereum(data::Ethereum),
+    Prng(data::Prng),
 }
 
 impl ToTransactionDataProtobuf for AnyTransactionData {
@@ -276,6 +278,7 @@ impl ToTransactionDataProtobuf for AnyTransactionData {
             }
 
             Self::Ethereum(transaction) => transaction.to_transaction_data_protobuf(chunk_info),
+            Self::Prng(transaction) => transaction.to_transaction_data_protobuf(chunk_info),
         }
     }
 }
@@ -323,6 +326,7 @@ impl TransactionData for AnyTransactionData {
             Self::ScheduleSign(transaction) => transaction.default_max_transaction_fee(),
             Self::ScheduleDelete(transaction) => transaction.default_max_transaction_fee(),
             Self::Ethereum(transaction) => transaction.default_max_transaction_fee(),
+            Self::Prng(transaction) => transaction.default_max_transaction_fee(),
         }
     }
 
@@ -368,6 +372,7 @@ impl TransactionData for AnyTransactionData {
             AnyTransactionData::ScheduleSign(it) => it.maybe_chunk_data(),
             AnyTransactionData::ScheduleDelete(it) => it.maybe_chunk_data(),
             AnyTransactionData::Ethereum(it) => it.maybe_chunk_data(),
+            AnyTransactionData::Prng(it) => it.maybe_chunk_data(),
         }
     }
 
@@ -413,6 +418,53 @@ impl TransactionData for AnyTransactionData {
             AnyTransactionData::ScheduleSign(it) => it.wait_for_receipt(),
             AnyTransactionData::ScheduleDelete(it) => it.wait_for_receipt(),
             AnyTransactionData::Ethereum(it) => it.wait_for_receipt(),
+            AnyTransactionData::Prng(it) => it.wait_for_receipt(),
+        }
+    }
+
+    fn validate(&self) -> crate::Result<()> {
+        match self {
+            AnyTransactionData::AccountCreate(it) => it.validate(),
+            AnyTransactionData::AccountUpdate(it) => it.validate(),
+            AnyTransactionData::AccountDelete(it) => it.validate(),
+            AnyTransactionData::AccountAllowanceApprove(it) => it.validate(),
+            AnyTransactionData::AccountAllowanceDelete(it) => it.validate(),
+            AnyTransactionData::ContractCreate(it) => it.validate(),
+            AnyTransactionData::ContractUpdate(it) => it.validate(),
+            AnyTransactionData::ContractDelete(it) => it.validate(),
+            AnyTransactionData::ContractExecute(it) => it.validate(),
+            AnyTransactionData::Transfer(it) => it.validate(),
+            AnyTransactionData::TopicCreate(it) => it.validate(),
+            AnyTransactionData::TopicUpdate(it) => it.validate(),
+            AnyTransactionData::TopicDelete(it) => it.validate(),
+            AnyTransactionData::TopicMessageSubmit(it) => it.validate(),
+            AnyTransactionData::FileAppend(it) => it.validate(),
+            AnyTransactionData::FileCreate(it) => it.validate(),
+            AnyTransactionData::FileUpdate(it) => it.validate(),
+            AnyTransactionData::FileDelete(it) => it.validate(),
+            AnyTransactionData::TokenAssociate(it) => it.validate(),
+            AnyTransactionData::TokenBurn(it) => it.validate(),
+            AnyTransactionData::TokenCreate(it) => it.validate(),
+            AnyTransactionData::TokenDelete(it) => it.validate(),
+            AnyTransactionData::TokenDissociate(it) => it.validate(),
+            AnyTransactionData::TokenFeeScheduleUpdate(it) => it.validate(),
+            AnyTransactionData::TokenFreeze(it) => it.validate(),
+            AnyTransactionData::TokenGrantKyc(it) => it.validate(),
+            AnyTransactionData::TokenMint(it) => it.validate(),
+            AnyTransactionData::TokenPause(it) => it.validate(),
+            AnyTransactionData::TokenRevokeKyc(it) => it.validate(),
+            AnyTransactionData::TokenUnfreeze(it) => it.validate(),
+            AnyTransactionData::TokenUnpause(it) => it.validate(),
+            AnyTransactionData::TokenUpdate(it) => it.validate(),
+            AnyTransactionData::TokenWipe(it) => it.validate(),
+            AnyTransactionData::SystemDelete(it) => it.validate(),
+            AnyTransactionData::SystemUndelete(it) => it.validate(),
+            AnyTransactionData::Freeze(it) => it.validate(),
+            AnyTransactionData::ScheduleCreate(it) => it.validate(),
+            AnyTransactionData::ScheduleSign(it) => it.validate(),
+            AnyTransactionData::ScheduleDelete(it) => it.validate(),
+            AnyTransactionData::Ethereum(it) => it.validate(),
+            AnyTransactionData::Prng(it) => it.validate(),
         }
     }
 }
@@ -464,6 +516,7 @@ impl TransactionExecute for AnyTransactionData {
             Self::ScheduleSign(transaction) => transaction.execute(channel, request),
             Self::ScheduleDelete(transaction) => transaction.execute(channel, request),
             Self::Ethereum(transaction) => transaction.execute(channel, request),
+            Self::Prng(transaction) => transaction.execute(channel, request),
         }
     }
 }
@@ -581,6 +634,7 @@ impl ValidateChecksums for AnyTransactionData {
                 transaction.validate_checksums(ledger_id)
             }
             AnyTransactionData::Ethereum(transaction) => transaction.validate_checksums(ledger_id),
+            AnyTransactionData::Prng(transaction) => transaction.validate_checksums(ledger_id),
         }
     }
 }
@@ -682,6 +736,7 @@ impl FromProtobuf<services::transaction_body::Data> for AnyTransactionData {
             Data::ContractUpdateInstance(pb) => data::ContractUpdate::from_protobuf(pb)?.into(),
             Data::ContractDeleteInstance(pb) => data::ContractDelete::from_protobuf(pb)?.into(),
             Data::EthereumTransaction(pb) => data::Ethereum::from_protobuf(pb)?.into(),
+            Data::UtilPrng(pb) => data::Prng::from_protobuf(pb)?.into(),
             Data::CryptoApproveAllowance(pb) => {
                 data::AccountAllowanceApprove::from_protobuf(pb)?.into()
             }
@@ -743,9 +798,6 @@ impl FromProtobuf<services::transaction_body::Data> for AnyTransactionData {
                     "unsupported transaction `NodeStakeUpdateTransaction`",
                 ))
             }
-            Data::UtilPrng(_) => {
-                return Err(Error::from_protobuf("unimplemented transaction `PrngTransaction`"))
-            }
         };
 
         Ok(data)
@@ -882,6 +934,9 @@ impl AnyTransactionData {
             ServicesTransactionDataList::Ethereum(v) => {
                 data::Ethereum::from_protobuf(try_into_only_element(v)?)?.into()
             }
+            ServicesTransactionDataList::Prng(v) => {
+                data::Prng::from_protobuf(try_into_only_element(v)?)?.into()
+            }
         };
 
         Ok(data)
@@ -958,6 +1013,7 @@ enum ServicesTransactionDataList {
     ScheduleSign(Vec<services::ScheduleSignTransactionBody>),
     ScheduleDelete(Vec<services::ScheduleDeleteTransactionBody>),
     Ethereum(Vec<services::EthereumTransactionBody>),
+    Prng(Vec<services::UtilPrngTransactionBody>),
 }
 
 impl FromProtobuf<Vec<services::transaction_body::Data>> for ServicesTransactionDataList {
@@ -985,6 +1041,7 @@ impl FromProtobuf<Vec<services::transaction_body::Data>> for ServicesTransaction
             Data::ContractUpdateInstance(it) => Self::ContractUpdate(make_vec(it, len)),
             Data::ContractDeleteInstance(it) => Self::ContractDelete(make_vec(it, len)),
             Data::EthereumTransaction(it) => Self::Ethereum(make_vec(it, len)),
+            Data::UtilPrng(it) => Self::Prng(make_vec(it, len)),
             Data::CryptoApproveAllowance(it) => Self::AccountAllowanceApprove(make_vec(it, len)),
             Data::CryptoDeleteAllowance(it) => Self::AccountAllowanceDelete(make_vec(it, len)),
             Data::CryptoCreateAccount(it) => Self::AccountCreate(make_vec(it, len)),
@@ -1042,9 +1099,6 @@ impl FromProtobuf<Vec<services::transaction_body::Data>> for ServicesTransaction
                     "unsupported transaction `NodeStakeUpdateTransaction`",
                 ))
             }
-            Data::UtilPrng(_) => {
-                return Err(Error::from_protobuf("unimplemented transaction `PrngTransaction`"))
-            }
         };
 
         for transaction in iter {
@@ -1097,6 +1151,7 @@ impl FromProtobuf<Vec<services::transaction_body::Data>> for ServicesTransaction
                 (Self::ScheduleSign(v), Data::ScheduleSign(element)) => v.push(element),
                 (Self::ScheduleDelete(v), Data::ScheduleDelete(element)) => v.push(element),
                 (Self::Ethereum(v), Data::EthereumTransaction(element)) => v.push(element),
+                (Self::Prng(v), Data::UtilPrng(element)) => v.push(element),
                 _ => return Err(Error::from_protobuf("mismatched transaction types")),
             }
         }
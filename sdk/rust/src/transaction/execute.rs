@@ -150,6 +150,13 @@ pub trait TransactionData: Clone + Into<AnyTransactionData> {
     fn wait_for_receipt(&self) -> bool {
         false
     }
+
+    /// Check the transaction data for mistakes that would otherwise only be caught by the network.
+    ///
+    /// This is run when the transaction is frozen.
+    fn validate(&self) -> crate::Result<()> {
+        Ok(())
+    }
 }
 
 pub trait TransactionExecute:
@@ -52,7 +52,10 @@ mod execute;
 mod protobuf;
 mod source;
 #[cfg(test)]
+pub(crate) mod test_helpers;
+#[cfg(test)]
 mod tests;
+pub(crate) mod validate;
 
 pub use any::AnyTransaction;
 #[cfg(feature = "ffi")]
@@ -78,6 +81,12 @@ pub(crate) use source::TransactionSources;
 
 const DEFAULT_TRANSACTION_VALID_DURATION: Duration = Duration::seconds(120);
 
+/// The shortest transaction valid duration the network accepts.
+const MIN_TRANSACTION_VALID_DURATION: Duration = Duration::seconds(15);
+
+/// The longest transaction valid duration the network accepts.
+const MAX_TRANSACTION_VALID_DURATION: Duration = Duration::seconds(180);
+
 /// A transaction that can be executed on the Hedera network.
 #[cfg_attr(feature = "ffi", derive(serde::Serialize))]
 #[cfg_attr(feature = "ffi", serde(rename_all = "camelCase"))]
@@ -362,8 +371,44 @@ impl<D: ChunkedTransactionData> Transaction<D> {
     }
 }
 
-impl<D: ValidateChecksums> Transaction<D> {
+impl<D: TransactionData> Transaction<D> {
+    /// Check the transaction for mistakes the network would reject it for, without contacting the network.
+    ///
+    /// This is done automatically by [`freeze_with`](Self::freeze_with).
+    ///
+    /// # Errors
+    /// - [`Error::InvalidTransactionField`] naming the offending field,
+    ///   for instance, if the transaction memo is longer than 100 bytes,
+    ///   or the transaction valid duration isn't between 15 and 180 seconds.
+    /// - [`Error::TransfersNotBalanced`], [`Error::TransferAmountOverflow`] or [`Error::NftTransferInconsistent`]
+    ///   if the transfers of a [`TransferTransaction`](crate::TransferTransaction) are invalid.
+    pub fn validate(&self) -> crate::Result<()> {
+        validate::memo("transaction_memo", &self.body.transaction_memo)?;
+
+        if let Some(duration) = self.body.transaction_valid_duration {
+            if duration < MIN_TRANSACTION_VALID_DURATION
+                || duration > MAX_TRANSACTION_VALID_DURATION
+            {
+                return Err(Error::invalid_transaction_field(
+                    "transaction_valid_duration",
+                    format!(
+                        "must be between {} and {} seconds, but is {duration}",
+                        MIN_TRANSACTION_VALID_DURATION.whole_seconds(),
+                        MAX_TRANSACTION_VALID_DURATION.whole_seconds()
+                    ),
+                ));
+            }
+        }
+
+        self.data().validate()
+    }
+}
+
+impl<D: TransactionData + ValidateChecksums> Transaction<D> {
     /// Freeze the transaction so that no further modifications can be made.
+    ///
+    /// # Errors
+    /// - If the transaction is invalid, see [`validate`](Self::validate).
     pub fn freeze(&mut self) -> crate::Result<&mut Self> {
         self.freeze_with(None)
     }
@@ -378,6 +423,8 @@ impl<D: ValidateChecksums> Transaction<D> {
         }
         let client: Option<&Client> = client.into();
 
+        self.validate()?;
+
         let node_account_ids = match &self.body.node_account_ids {
             // the clone here is the lesser of two evils.
             Some(it) => it.clone(),
@@ -401,7 +448,7 @@ impl<D: ValidateChecksums> Transaction<D> {
             }
         });
 
-        let operator = client.and_then(|it| it.operator_internal().as_deref().map(|it| it.clone()));
+        let operator = client.and_then(Client::operator);
 
         // note: yes, there's an `Some(opt.unwrap())`, this is INTENTIONAL.
         self.body.node_account_ids = Some(node_account_ids);
@@ -489,6 +536,17 @@ impl<D: TransactionExecute> Transaction<D> {
         return Ok(Cow::Owned(TransactionSources::new(self.make_transaction_list()?).unwrap()));
     }
 
+    /// Returns the signed transactions, with the signers that haven't been applied yet (including the operator) applied.
+    ///
+    /// Returns `None` if the transaction isn't frozen, or can't be signed (for instance, it has no transaction ID).
+    pub(crate) fn signed_sources(&self) -> Option<Cow<'_, TransactionSources>> {
+        if !self.is_frozen() {
+            return None;
+        }
+
+        self.make_sources().ok()
+    }
+
     /// Convert `self` to protobuf encoded bytes.
     ///
     /// # Errors
@@ -30,6 +30,7 @@ use crate::signer::AnySigner;
 use crate::{
     AccountId,
     Error,
+    PublicKey,
     TransactionHash,
     TransactionId,
 };
@@ -278,6 +279,30 @@ impl TransactionSources {
         })
     }
 
+    /// Returns `true` if there's at least one signed transaction and every one of them has a valid signature from `key`.
+    pub(crate) fn is_signed_by(&self, key: &PublicKey) -> bool {
+        let pk_bytes = key.to_bytes_raw();
+
+        !self.signed_transactions.is_empty()
+            && self.signed_transactions.iter().all(|signed_transaction| {
+                let sig_pairs = signed_transaction
+                    .sig_map
+                    .as_ref()
+                    .map_or_else(|| [].as_slice(), |it| &it.sig_pair);
+
+                sig_pairs.iter().filter(|it| pk_bytes.starts_with(&it.pub_key_prefix)).any(|it| {
+                    use services::signature_pair::Signature;
+
+                    let signature = match &it.signature {
+                        Some(Signature::Ed25519(it) | Signature::EcdsaSecp256k1(it)) => it,
+                        _ => return false,
+                    };
+
+                    key.verify(&signed_transaction.body_bytes, signature).is_ok()
+                })
+            })
+    }
+
     pub(crate) fn signed_transactions(&self) -> &[services::SignedTransaction] {
         &self.signed_transactions
     }
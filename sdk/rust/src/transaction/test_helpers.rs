@@ -0,0 +1,57 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+//! Fixtures shared by the unit tests of transactions and signatures.
+
+use time::OffsetDateTime;
+
+use crate::{
+    Hbar,
+    TransactionId,
+    TransferTransaction,
+};
+
+/// A transaction ID for `0.0.101`, valid from now.
+pub(crate) fn transaction_id() -> TransactionId {
+    TransactionId {
+        account_id: 101.into(),
+        valid_start: OffsetDateTime::now_utc(),
+        nonce: None,
+        scheduled: false,
+    }
+}
+
+/// A balanced transfer of 2 hbar from `0.0.101` to `0.0.2`.
+pub(crate) fn transfer_transaction() -> TransferTransaction {
+    let mut tx = TransferTransaction::new();
+
+    tx.hbar_transfer(2.into(), Hbar::new(2)).hbar_transfer(101.into(), Hbar::new(-2));
+
+    tx
+}
+
+/// [`transfer_transaction`] with a [`transaction_id`], frozen for node `0.0.6` without any signatures.
+pub(crate) fn unsigned_transfer_transaction() -> TransferTransaction {
+    let mut tx = transfer_transaction();
+
+    tx.transaction_id(transaction_id()).node_account_ids([6.into()]).freeze().unwrap();
+
+    tx
+}
@@ -1,16 +1,33 @@
 use std::collections::HashSet;
 
 use assert_matches::assert_matches;
+use hedera_proto::services::account_id::Account;
 use hex_literal::hex;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
 use time::OffsetDateTime;
 
+use crate::transaction::test_helpers::transfer_transaction;
 use crate::transaction::AnyTransactionData;
 use crate::{
+    AccountCreateTransaction,
+    AccountId,
     AnyTransaction,
     Client,
+    Error,
     Hbar,
+    Key,
+    KeyList,
     PrivateKey,
+    ScheduleCreateTransaction,
+    ToProtobuf,
+    TokenCreateTransaction,
+    TokenId,
+    TokenMintTransaction,
+    TokenUpdateTransaction,
+    TopicId,
     TopicMessageSubmitTransaction,
+    TopicUpdateTransaction,
     TransactionId,
     TransferTransaction,
 };
@@ -119,3 +136,269 @@ fn chunked_to_from_bytes() -> crate::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn clock_and_rng_make_bytes_deterministic() -> crate::Result<()> {
+    fn make_bytes() -> crate::Result<Vec<u8>> {
+        let client = Client::for_testnet();
+        client.set_operator(
+            101.into(),
+            PrivateKey::from_bytes(&hex!("302e020100300506032b657004220420e40d4241d093b22910c78135e0501b137cd9205bbb9c0153c5adf2c65e7dc95a")).unwrap(),
+        );
+        client.set_clock(|| OffsetDateTime::from_unix_timestamp(1_640_995_200).unwrap());
+        client.set_rng(StdRng::seed_from_u64(314));
+
+        transfer_transaction().freeze_with(&client)?.to_bytes()
+    }
+
+    assert_eq!(make_bytes()?, make_bytes()?);
+
+    Ok(())
+}
+
+#[test]
+fn transfers_are_merged_and_sorted() {
+    let mut tx = TransferTransaction::new();
+
+    tx.hbar_transfer(101.into(), Hbar::from_tinybars(-1))
+        .hbar_transfer(2.into(), Hbar::from_tinybars(2))
+        .hbar_transfer(101.into(), Hbar::from_tinybars(-1))
+        .hbar_transfer(3.into(), Hbar::from_tinybars(5))
+        .hbar_transfer(3.into(), Hbar::from_tinybars(-5))
+        .token_transfer(TokenId::from(20), 7.into(), 3)
+        .token_transfer(TokenId::from(10), 7.into(), -3)
+        .token_transfer(TokenId::from(10), 6.into(), 3)
+        .token_transfer(TokenId::from(20), 6.into(), -3);
+
+    let pb = tx.data().to_protobuf();
+
+    let hbar_accounts: Vec<_> = pb
+        .transfers
+        .unwrap()
+        .account_amounts
+        .into_iter()
+        .map(|it| (it.account_id.unwrap().account.unwrap(), it.amount))
+        .collect();
+
+    assert_eq!(hbar_accounts, [(Account::AccountNum(2), 2), (Account::AccountNum(101), -2),]);
+
+    let token_ids: Vec<_> =
+        pb.token_transfers.iter().map(|it| it.token.as_ref().unwrap().token_num).collect();
+    assert_eq!(token_ids, [10, 20]);
+
+    let token_accounts: Vec<_> = pb.token_transfers[0]
+        .transfers
+        .iter()
+        .map(|it| it.account_id.clone().unwrap().account.unwrap())
+        .collect();
+
+    assert_eq!(token_accounts, [Account::AccountNum(6), Account::AccountNum(7),]);
+}
+
+#[test]
+fn unbalanced_transfers_fail_validation() {
+    let mut tx = TransferTransaction::new();
+
+    tx.hbar_transfer(2.into(), Hbar::from_tinybars(2))
+        .hbar_transfer(101.into(), Hbar::from_tinybars(-1))
+        .node_account_ids([6.into()]);
+
+    assert_matches!(tx.validate(), Err(Error::TransfersNotBalanced { token_id: None, sum: 1 }));
+
+    let mut tx = TransferTransaction::new();
+
+    tx.token_transfer(TokenId::from(10), 2.into(), 5)
+        .token_transfer(TokenId::from(10), 101.into(), -4)
+        .node_account_ids([6.into()]);
+
+    assert_matches!(
+        tx.validate(),
+        Err(Error::TransfersNotBalanced { token_id: Some(token_id), sum: 1 }) if token_id == TokenId::from(10)
+    );
+}
+
+#[test]
+fn inconsistent_nft_transfers_fail_validation() {
+    let nft_id = TokenId::from(10).nft(1);
+    let alice = AccountId::from(1001);
+    let bob = AccountId::from(1002);
+
+    let mut tx = TransferTransaction::new();
+
+    tx.nft_transfer(nft_id, alice, bob)
+        .nft_transfer(nft_id, bob, alice)
+        .node_account_ids([6.into()]);
+
+    assert_matches!(tx.validate(), Err(Error::NftTransferInconsistent { .. }));
+
+    let mut tx = TransferTransaction::new();
+
+    tx.nft_transfer(nft_id, alice, alice).node_account_ids([6.into()]);
+
+    assert_matches!(tx.validate(), Err(Error::NftTransferInconsistent { .. }));
+}
+
+#[test]
+fn approved_and_unapproved_transfers_are_not_merged() {
+    let mut tx = TransferTransaction::new();
+
+    tx.hbar_transfer(101.into(), Hbar::from_tinybars(-1))
+        .approved_hbar_transfer(101.into(), Hbar::from_tinybars(-1))
+        .hbar_transfer(2.into(), Hbar::from_tinybars(2));
+
+    let approvals: Vec<_> = tx
+        .data()
+        .to_protobuf()
+        .transfers
+        .unwrap()
+        .account_amounts
+        .into_iter()
+        .map(|it| (it.account_id.unwrap().account.unwrap(), it.amount, it.is_approval))
+        .collect();
+
+    assert_eq!(
+        approvals,
+        [
+            (Account::AccountNum(2), 2, false),
+            (Account::AccountNum(101), -1, false),
+            (Account::AccountNum(101), -1, true),
+        ]
+    );
+}
+
+#[test]
+fn overflowing_transfers_fail_validation() {
+    let mut tx = TransferTransaction::new();
+
+    tx.hbar_transfer(2.into(), Hbar::from_tinybars(i64::MAX))
+        .hbar_transfer(2.into(), Hbar::from_tinybars(i64::MAX));
+
+    assert_matches!(
+        tx.validate(),
+        Err(Error::TransferAmountOverflow { token_id: None, account_id }) if account_id == AccountId::from(2)
+    );
+}
+
+#[test]
+fn freeze_validates_transfers() {
+    let mut tx = TransferTransaction::new();
+
+    tx.hbar_transfer(2.into(), Hbar::from_tinybars(-1))
+        .hbar_transfer(101.into(), Hbar::from_tinybars(2))
+        .node_account_ids([6.into()])
+        .transaction_id(TransactionId::generate(2.into()));
+
+    assert_matches!(tx.freeze(), Err(Error::TransfersNotBalanced { token_id: None, sum: 1 }));
+    assert!(!tx.is_frozen());
+}
+
+#[test]
+fn invalid_transaction_fields_fail_to_freeze() {
+    let mut tx = TransferTransaction::new();
+
+    tx.transaction_memo("a".repeat(101)).node_account_ids([6.into()]);
+
+    assert_matches!(
+        tx.freeze(),
+        Err(Error::InvalidTransactionField { field: "transaction_memo", .. })
+    );
+
+    let mut tx = TransferTransaction::new();
+
+    tx.transaction_valid_duration(time::Duration::seconds(181)).node_account_ids([6.into()]);
+
+    assert_matches!(
+        tx.freeze(),
+        Err(Error::InvalidTransactionField { field: "transaction_valid_duration", .. })
+    );
+
+    let mut tx = TransferTransaction::new();
+
+    tx.transaction_valid_duration(time::Duration::seconds(14)).node_account_ids([6.into()]);
+
+    assert_matches!(
+        tx.freeze(),
+        Err(Error::InvalidTransactionField { field: "transaction_valid_duration", .. })
+    );
+}
+
+#[test]
+fn invalid_transaction_data_fails_to_freeze() {
+    let mut tx = TokenMintTransaction::new();
+
+    tx.metadata([b"nft".to_vec()]).node_account_ids([6.into()]);
+
+    assert_matches!(tx.freeze(), Err(Error::InvalidTransactionField { field: "token_id", .. }));
+
+    let mut tx = TokenMintTransaction::new();
+
+    tx.token_id(TokenId::from(10)).metadata(vec![b"nft".to_vec(); 11]).node_account_ids([6.into()]);
+
+    assert_matches!(tx.freeze(), Err(Error::InvalidTransactionField { field: "metadata", .. }));
+
+    let mut tx = TokenCreateTransaction::new();
+
+    tx.name("ffff")
+        .symbol("F".repeat(101))
+        .treasury_account_id(AccountId::from(101))
+        .node_account_ids([6.into()]);
+
+    assert_matches!(tx.freeze(), Err(Error::InvalidTransactionField { field: "symbol", .. }));
+
+    let mut tx = TokenCreateTransaction::new();
+
+    tx.name("ffff")
+        .symbol("F")
+        .treasury_account_id(AccountId::from(101))
+        .admin_key(Key::KeyList(KeyList::new()))
+        .node_account_ids([6.into()]);
+
+    assert_matches!(tx.freeze(), Err(Error::InvalidTransactionField { field: "admin_key", .. }));
+}
+
+#[test]
+fn update_transactions_can_clear_keys() -> crate::Result<()> {
+    TokenUpdateTransaction::new()
+        .token_id(TokenId::from(10))
+        .kyc_key(KeyList::new())
+        .node_account_ids([6.into()])
+        .freeze()?;
+
+    TopicUpdateTransaction::new()
+        .topic_id(TopicId::from(10))
+        .admin_key(KeyList::new())
+        .submit_key(KeyList::new())
+        .node_account_ids([6.into()])
+        .freeze()?;
+
+    Ok(())
+}
+
+#[test]
+fn account_create_with_alias_needs_no_key() -> crate::Result<()> {
+    AccountCreateTransaction::new()
+        .alias(PrivateKey::generate_ecdsa().public_key())
+        .node_account_ids([6.into()])
+        .freeze()?;
+
+    let mut tx = AccountCreateTransaction::new();
+
+    tx.node_account_ids([6.into()]);
+
+    assert_matches!(tx.freeze(), Err(Error::InvalidTransactionField { field: "key", .. }));
+
+    Ok(())
+}
+
+#[test]
+fn schedule_create_validates_the_scheduled_transaction() {
+    let mut scheduled = TokenMintTransaction::new();
+
+    scheduled.token_id(TokenId::from(10)).metadata(vec![b"nft".to_vec(); 11]);
+
+    let mut tx = ScheduleCreateTransaction::new();
+
+    tx.scheduled_transaction(scheduled).node_account_ids([6.into()]);
+
+    assert_matches!(tx.freeze(), Err(Error::InvalidTransactionField { field: "metadata", .. }));
+}
@@ -0,0 +1,112 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+//! Helpers for validating transaction fields locally, before a transaction is frozen.
+
+use crate::{
+    Error,
+    Key,
+};
+
+/// The maximum length (in bytes) the network accepts for any memo.
+pub(crate) const MAX_MEMO_BYTES: usize = 100;
+
+/// The maximum length (in bytes) the network accepts for a token's name or symbol.
+pub(crate) const MAX_TOKEN_NAME_BYTES: usize = 100;
+
+/// The maximum number of entries the network accepts in a single mint, burn, or wipe.
+pub(crate) const MAX_NFT_BATCH_SIZE: usize = 10;
+
+/// The maximum number of custom fees a token may have.
+pub(crate) const MAX_CUSTOM_FEES: usize = 10;
+
+/// Fails if `value` is [`None`].
+pub(crate) fn require<T>(field: &'static str, value: &Option<T>) -> crate::Result<()> {
+    match value {
+        Some(_) => Ok(()),
+        None => Err(Error::invalid_transaction_field(field, "must be set")),
+    }
+}
+
+/// Fails if `memo` is longer than [`MAX_MEMO_BYTES`].
+pub(crate) fn memo(field: &'static str, memo: &str) -> crate::Result<()> {
+    max_bytes(field, memo, MAX_MEMO_BYTES)
+}
+
+/// Fails if `value` is longer than `max` bytes.
+pub(crate) fn max_bytes(field: &'static str, value: &str, max: usize) -> crate::Result<()> {
+    if value.len() > max {
+        return Err(Error::invalid_transaction_field(
+            field,
+            format!("must be at most {max} bytes, but is {} bytes", value.len()),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Fails if `len` is greater than `max`.
+pub(crate) fn max_len(field: &'static str, len: usize, max: usize) -> crate::Result<()> {
+    if len > max {
+        return Err(Error::invalid_transaction_field(
+            field,
+            format!("must have at most {max} entries, but has {len}"),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Fails if `key` (or any key nested in it) is a key list that could never be satisfied.
+pub(crate) fn key(field: &'static str, key: &Key) -> crate::Result<()> {
+    let list = match key {
+        Key::KeyList(list) => list,
+        _ => return Ok(()),
+    };
+
+    if list.keys.is_empty() {
+        return Err(Error::invalid_transaction_field(field, "key lists must not be empty"));
+    }
+
+    if let Some(threshold) = list.threshold {
+        if threshold == 0 || threshold as usize > list.keys.len() {
+            return Err(Error::invalid_transaction_field(
+                field,
+                format!("threshold `{threshold}` must be between 1 and {}", list.keys.len()),
+            ));
+        }
+    }
+
+    list.keys.iter().try_for_each(|it| self::key(field, it))
+}
+
+/// Like [`key`], but a missing key is fine.
+pub(crate) fn optional_key(field: &'static str, key: &Option<Key>) -> crate::Result<()> {
+    key.as_ref().map_or(Ok(()), |it| self::key(field, it))
+}
+
+/// Like [`optional_key`], but the key itself may be an empty key list,
+/// which update transactions use to clear (or lock) a key.
+pub(crate) fn optional_update_key(field: &'static str, key: &Option<Key>) -> crate::Result<()> {
+    match key {
+        Some(Key::KeyList(list)) if list.keys.is_empty() => Ok(()),
+        key => optional_key(field, key),
+    }
+}
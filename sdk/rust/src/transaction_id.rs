@@ -27,15 +27,13 @@ use std::fmt::{
 use std::str::FromStr;
 
 use hedera_proto::services;
-use rand::{
-    thread_rng,
-    Rng,
-};
+use rand::Rng;
 use time::{
     Duration,
     OffsetDateTime,
 };
 
+use crate::client::ClockAndRng;
 use crate::{
     AccountId,
     Error,
@@ -75,8 +73,13 @@ impl TransactionId {
     /// Generates a new transaction ID for the given account ID.
     #[must_use]
     pub fn generate(account_id: AccountId) -> Self {
-        let valid_start = OffsetDateTime::now_utc()
-            - Duration::nanoseconds(thread_rng().gen_range(5_000_000_000..8_000_000_000));
+        Self::generate_with(account_id, &ClockAndRng::default())
+    }
+
+    /// Generates a new transaction ID for the given account ID using the given clock and RNG.
+    pub(crate) fn generate_with(account_id: AccountId, clock_and_rng: &ClockAndRng) -> Self {
+        let jitter = clock_and_rng.rng(|rng| rng.gen_range(5_000_000_000..8_000_000_000));
+        let valid_start = clock_and_rng.now_utc() - Duration::nanoseconds(jitter);
 
         Self { account_id, valid_start, scheduled: false, nonce: None }
     }
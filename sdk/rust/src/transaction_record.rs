@@ -25,6 +25,8 @@ use time::OffsetDateTime;
 
 use crate::evm_address::EvmAddress;
 use crate::{
+    Abi,
+    AbiEventLog,
     AccountId,
     AssessedCustomFee,
     ContractFunctionResult,
@@ -120,24 +122,46 @@ pub struct TransactionRecord {
     /// The keccak256 hash of the ethereumData. This field will only be populated for
     /// `EthereumTransaction`.
     pub ethereum_hash: Vec<u8>,
-    // /// In the record of a PRNG transaction with no output range, a pseudorandom 384-bit string.
-    // TODO: pub prng_bytes: Vec<u8>,
-    //
-    // /// In the record of a PRNG transaction with an output range, the output of a PRNG
-    // /// whose input was a 384-bit string.
-    // TODO: pub prng_number: i32,
+
+    /// In the record of a [`PrngTransaction`](crate::PrngTransaction) with no output range, a pseudorandom 384-bit string.
+    #[cfg_attr(
+        feature = "ffi",
+        serde(with = "serde_with::As::<Option<serde_with::base64::Base64>>")
+    )]
+    pub prng_bytes: Option<Vec<u8>>,
+
+    /// In the record of a [`PrngTransaction`](crate::PrngTransaction) with an output range, the output of a PRNG
+    /// whose input was a 384-bit string.
+    pub prng_number: Option<i32>,
+
     /// The last 20 bytes of the keccak-256 hash of a ECDSA_SECP256K1 primitive key.
     pub evm_address: Option<EvmAddress>,
 }
 // TODO: paid_staking_rewards
 
 impl TransactionRecord {
+    /// Decode the contract logs in this record that match an event in `abi`.
+    ///
+    /// Returns an empty list if this record has no contract function result.
+    ///
+    /// # Errors
+    /// - [`Error::AbiDecode`](crate::Error::AbiDecode) if a log matches an event, but isn't a valid encoding of it.
+    pub fn decode_logs(&self, abi: &Abi) -> crate::Result<Vec<AbiEventLog>> {
+        match &self.contract_function_result {
+            Some(result) => result.decode_logs(abi),
+            None => Ok(Vec::new()),
+        }
+    }
+
     fn from_protobuf(
         record: services::TransactionRecord,
         duplicates: Vec<Self>,
         children: Vec<Self>,
     ) -> crate::Result<Self> {
-        use services::transaction_record::Body;
+        use services::transaction_record::{
+            Body,
+            Entropy,
+        };
         let receipt = pb_getf!(record, receipt)?;
         let receipt = TransactionReceipt::from_protobuf(receipt)?;
 
@@ -193,6 +217,12 @@ impl TransactionRecord {
             (token_transfers, token_nft_transfers)
         };
 
+        let (prng_bytes, prng_number) = match record.entropy {
+            Some(Entropy::PrngBytes(it)) => (Some(it), None),
+            Some(Entropy::PrngNumber(it)) => (None, Some(it)),
+            None => (None, None),
+        };
+
         let evm_address = if record.evm_address.is_empty() {
             None
         } else {
@@ -212,6 +242,8 @@ impl TransactionRecord {
             parent_consensus_timestamp,
             duplicates,
             ethereum_hash: record.ethereum_hash,
+            prng_bytes,
+            prng_number,
             children,
             alias_key,
             transfers,
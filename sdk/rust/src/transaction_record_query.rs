@@ -158,6 +158,21 @@ impl QueryExecute for TransactionRecordQueryData {
         let record = TransactionRecord::from_protobuf(response)?;
 
         if self.validate_status && record.receipt.status != Status::Success {
+            let revert = match record.receipt.status {
+                Status::ContractRevertExecuted => {
+                    record.contract_function_result.as_ref().and_then(|it| it.revert.clone())
+                }
+                _ => None,
+            };
+
+            if let Some(revert) = revert {
+                return Err(Error::ContractRevert {
+                    status: record.receipt.status,
+                    transaction_id: self.transaction_id,
+                    revert: Box::new(revert),
+                });
+            }
+
             return Err(Error::ReceiptStatus {
                 transaction_id: self.transaction_id,
                 status: record.receipt.status,
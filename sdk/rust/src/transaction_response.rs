@@ -124,7 +124,8 @@ impl TransactionResponse {
     ///
     /// # Errors
     /// - if [`validate_status`](Self.validate_status) is `true`:
-    ///   [`Error::ReceiptStatus`](crate::Error::ReceiptStatus) for a failing receipt in the record.
+    ///   [`Error::ContractRevert`](crate::Error::ContractRevert) for a reverted contract call in the record.
+    ///   [`Error::ReceiptStatus`](crate::Error::ReceiptStatus) for any other failing receipt in the record.
     pub async fn get_record(&self, client: &Client) -> crate::Result<TransactionRecord> {
         self.get_record_query().execute(client).await
     }
@@ -134,7 +135,8 @@ impl TransactionResponse {
     ///
     /// # Errors
     /// - if [`validate_status`](Self.validate_status) is `true`:
-    ///   [`Error::ReceiptStatus`](crate::Error::ReceiptStatus) for a failing receipt in the record.
+    ///   [`Error::ContractRevert`](crate::Error::ContractRevert) for a reverted contract call in the record.
+    ///   [`Error::ReceiptStatus`](crate::Error::ReceiptStatus) for any other failing receipt in the record.
     pub async fn get_record_with_timeout(
         &self,
         client: &Client,
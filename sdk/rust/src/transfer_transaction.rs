@@ -18,6 +18,7 @@
  * ‍
  */
 
+use std::collections::HashSet;
 use std::ops::Not;
 
 use hedera_proto::services;
@@ -53,6 +54,9 @@ use crate::{
 /// from the corresponding account (a sender), and each positive one is added to the corresponding
 /// account (a receiver). The amounts list must sum to zero.
 ///
+/// Transfers to or from the same account are merged, and all transfers are sorted when serialized,
+/// so that the same set of transfers always produces the same bytes.
+///
 pub type TransferTransaction = Transaction<TransferTransactionData>;
 
 #[derive(Debug, Clone, Default)]
@@ -124,7 +128,7 @@ impl TransferTransaction {
 
         if let Some(tt) = data.token_transfers.iter_mut().find(|tt| tt.token_id == token_id) {
             tt.expected_decimals = expected_decimals;
-            tt.transfers.push(transfer);
+            Transfer::merge_into(&mut tt.transfers, transfer);
         } else {
             data.token_transfers.push(TokenTransfer {
                 token_id,
@@ -227,11 +231,9 @@ impl TransferTransaction {
     }
 
     fn _hbar_transfer(&mut self, account_id: AccountId, amount: Hbar, approved: bool) -> &mut Self {
-        self.data_mut().transfers.push(Transfer {
-            account_id,
-            amount: amount.to_tinybars(),
-            is_approval: approved,
-        });
+        let transfer = Transfer { account_id, amount: amount.to_tinybars(), is_approval: approved };
+
+        Transfer::merge_into(&mut self.data_mut().transfers, transfer);
 
         self
     }
@@ -258,7 +260,51 @@ impl TransactionExecute for TransferTransactionData {
     }
 }
 
-impl TransactionData for TransferTransactionData {}
+impl TransactionData for TransferTransactionData {
+    fn validate(&self) -> crate::Result<()> {
+        Transfer::check_merged(None, &self.transfers)?;
+
+        let sum: i128 = self.transfers.iter().map(|it| i128::from(it.amount)).sum();
+
+        if sum != 0 {
+            return Err(Error::TransfersNotBalanced { token_id: None, sum });
+        }
+
+        for token_transfer in &self.token_transfers {
+            let token_id = token_transfer.token_id;
+
+            Transfer::check_merged(Some(token_id), &token_transfer.transfers)?;
+
+            let sum: i128 = token_transfer.transfers.iter().map(|it| i128::from(it.amount)).sum();
+
+            if sum != 0 {
+                return Err(Error::TransfersNotBalanced { token_id: Some(token_id), sum });
+            }
+
+            let mut serials = HashSet::with_capacity(token_transfer.nft_transfers.len());
+
+            for transfer in &token_transfer.nft_transfers {
+                let nft_id = NftId { token_id, serial: transfer.serial };
+
+                if transfer.sender_account_id == transfer.receiver_account_id {
+                    return Err(Error::NftTransferInconsistent {
+                        nft_id,
+                        reason: "sender and receiver are the same account",
+                    });
+                }
+
+                if !serials.insert(transfer.serial) {
+                    return Err(Error::NftTransferInconsistent {
+                        nft_id,
+                        reason: "the NFT is transferred more than once",
+                    });
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
 
 impl ValidateChecksums for TransferTransactionData {
     fn validate_checksums(&self, ledger_id: &LedgerId) -> Result<(), Error> {
@@ -279,6 +325,57 @@ impl ValidateChecksums for TransferTransactionData {
     }
 }
 
+impl Transfer {
+    /// Adds `transfer` to `transfers`, merging it with an existing transfer for the same account
+    /// that is also (not) approved.
+    ///
+    /// Merged transfers are removed entirely if they cancel out.
+    /// If merging would overflow, the transfer is added on its own, which [`Self::check_merged`] reports.
+    fn merge_into(transfers: &mut Vec<Self>, transfer: Self) {
+        let index = match transfers.iter().position(|it| it.merges_with(&transfer)) {
+            Some(index) => index,
+            None => return transfers.push(transfer),
+        };
+
+        match transfers[index].amount.checked_add(transfer.amount) {
+            Some(0) => {
+                transfers.remove(index);
+            }
+            Some(amount) => transfers[index].amount = amount,
+            None => transfers.push(transfer),
+        }
+    }
+
+    fn merges_with(&self, other: &Self) -> bool {
+        self.account_id == other.account_id && self.is_approval == other.is_approval
+    }
+
+    /// Returns an error if any transfers in `transfers` couldn't be merged because their sum overflows.
+    fn check_merged(token_id: Option<TokenId>, transfers: &[Self]) -> crate::Result<()> {
+        for (index, transfer) in transfers.iter().enumerate() {
+            if transfers[..index].iter().any(|it| it.merges_with(transfer)) {
+                return Err(Error::TransferAmountOverflow {
+                    token_id,
+                    account_id: transfer.account_id,
+                });
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The key used to sort account IDs in serialized transfer lists.
+fn account_sort_key(account_id: &AccountId) -> (u64, u64, u64, Option<Vec<u8>>, Option<[u8; 20]>) {
+    (
+        account_id.shard,
+        account_id.realm,
+        account_id.num,
+        account_id.alias.map(|it| it.to_bytes_raw()),
+        account_id.evm_address.map(|it| it.0),
+    )
+}
+
 impl FromProtobuf<services::AccountAmount> for Transfer {
     fn from_protobuf(pb: services::AccountAmount) -> crate::Result<Self> {
         Ok(Self {
@@ -316,8 +413,20 @@ impl ToProtobuf for TokenTransfer {
     type Protobuf = services::TokenTransferList;
 
     fn to_protobuf(&self) -> Self::Protobuf {
-        let transfers = self.transfers.to_protobuf();
-        let nft_transfers = self.nft_transfers.to_protobuf();
+        let mut transfers = self.transfers.clone();
+        transfers.sort_by_cached_key(|it| (account_sort_key(&it.account_id), it.is_approval));
+
+        let mut nft_transfers = self.nft_transfers.clone();
+        nft_transfers.sort_by_cached_key(|it| {
+            (
+                account_sort_key(&it.sender_account_id),
+                account_sort_key(&it.receiver_account_id),
+                it.serial,
+            )
+        });
+
+        let transfers = transfers.to_protobuf();
+        let nft_transfers = nft_transfers.to_protobuf();
 
         services::TokenTransferList {
             token: Some(self.token_id.to_protobuf()),
@@ -390,13 +499,18 @@ impl ToProtobuf for TransferTransactionData {
     type Protobuf = services::CryptoTransferTransactionBody;
 
     fn to_protobuf(&self) -> Self::Protobuf {
-        let transfers = self
-            .transfers
+        let mut transfers = self.transfers.clone();
+        transfers.sort_by_cached_key(|it| (account_sort_key(&it.account_id), it.is_approval));
+
+        let transfers = transfers
             .is_empty()
             .not()
-            .then(|| services::TransferList { account_amounts: self.transfers.to_protobuf() });
+            .then(|| services::TransferList { account_amounts: transfers.to_protobuf() });
+
+        let mut token_transfers: Vec<_> = self.token_transfers.iter().collect();
+        token_transfers.sort_by_key(|it| (it.token_id.shard, it.token_id.realm, it.token_id.num));
 
-        let token_transfers = self.token_transfers.to_protobuf();
+        let token_transfers = token_transfers.into_iter().map(TokenTransfer::to_protobuf).collect();
 
         services::CryptoTransferTransactionBody { transfers, token_transfers }
     }
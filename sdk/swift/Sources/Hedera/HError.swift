@@ -60,6 +60,16 @@ public struct HError: Error, CustomStringConvertible {
         case cannotPerformTaskWithoutLedgerId
         case wrongKeyType
         case freezeUnsetNodeAccountIds
+        case transfersNotBalanced
+        case nftTransferInconsistent
+        case transferAmountOverflow
+        case invalidTransactionField
+        case abiParse
+        case abiEncode
+        case abiDecode
+        case abiItemNotFound
+        case contractRevert
+        case keyEncrypt
     }
 
     public let description: String
@@ -151,6 +161,36 @@ public struct HError: Error, CustomStringConvertible {
         case HEDERA_ERROR_FREEZE_UNSET_NODE_ACCOUNT_IDS:
             kind = .freezeUnsetNodeAccountIds
 
+        case HEDERA_ERROR_TRANSFERS_NOT_BALANCED:
+            kind = .transfersNotBalanced
+
+        case HEDERA_ERROR_NFT_TRANSFER_INCONSISTENT:
+            kind = .nftTransferInconsistent
+
+        case HEDERA_ERROR_TRANSFER_AMOUNT_OVERFLOW:
+            kind = .transferAmountOverflow
+
+        case HEDERA_ERROR_INVALID_TRANSACTION_FIELD:
+            kind = .invalidTransactionField
+
+        case HEDERA_ERROR_ABI_PARSE:
+            kind = .abiParse
+
+        case HEDERA_ERROR_ABI_ENCODE:
+            kind = .abiEncode
+
+        case HEDERA_ERROR_ABI_DECODE:
+            kind = .abiDecode
+
+        case HEDERA_ERROR_ABI_ITEM_NOT_FOUND:
+            kind = .abiItemNotFound
+
+        case HEDERA_ERROR_CONTRACT_REVERT:
+            kind = .contractRevert
+
+        case HEDERA_ERROR_KEY_ENCRYPT:
+            kind = .keyEncrypt
+
         case HEDERA_ERROR_OK:
             return nil
 